@@ -0,0 +1,75 @@
+//! Equipment Commands
+//!
+//! CRUD operations on the equipment catalog in the local database.
+
+use crate::database::{
+    delete_equipment_record, get_equipment_by_id, has_equipment_records, insert_equipment,
+    list_equipment_records, update_equipment_record, DatabaseError, DatabaseState, Equipment,
+};
+use crate::drawings::EquipmentCategory;
+use rusqlite::Connection;
+use tauri::State;
+
+/// Run a catalog operation against the shared local database connection
+fn with_catalog<T>(
+    database: &DatabaseState,
+    operation: impl FnOnce(&Connection) -> Result<T, DatabaseError>,
+) -> Result<T, DatabaseError> {
+    database.with_connection(|conn| operation(conn))
+}
+
+/// Whether the local database is open and its catalog holds any equipment
+pub(super) fn catalog_loaded(database: &DatabaseState) -> bool {
+    database
+        .with_open_connection(has_equipment_records)
+        .unwrap_or(false)
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to add equipment to the catalog, returning it with its ID
+#[tauri::command]
+pub fn create_equipment(
+    database: State<'_, DatabaseState>,
+    equipment: Equipment,
+) -> Result<Equipment, DatabaseError> {
+    with_catalog(&database, |conn| insert_equipment(conn, &equipment))
+}
+
+/// Tauri command to get a catalog equipment record by ID
+#[tauri::command]
+pub fn get_equipment(
+    database: State<'_, DatabaseState>,
+    id: String,
+) -> Result<Equipment, DatabaseError> {
+    with_catalog(&database, |conn| get_equipment_by_id(conn, &id))
+}
+
+/// Tauri command to list catalog equipment, optionally in one category
+#[tauri::command]
+pub fn list_equipment(
+    database: State<'_, DatabaseState>,
+    category: Option<EquipmentCategory>,
+) -> Result<Vec<Equipment>, DatabaseError> {
+    with_catalog(&database, |conn| list_equipment_records(conn, category))
+}
+
+/// Tauri command to replace a catalog equipment record
+#[tauri::command]
+pub fn update_equipment(
+    database: State<'_, DatabaseState>,
+    equipment: Equipment,
+) -> Result<Equipment, DatabaseError> {
+    with_catalog(&database, |conn| update_equipment_record(conn, &equipment))
+}
+
+/// Tauri command to remove a catalog equipment record
+#[tauri::command]
+pub fn delete_equipment(
+    database: State<'_, DatabaseState>,
+    id: String,
+) -> Result<(), DatabaseError> {
+    with_catalog(&database, |conn| delete_equipment_record(conn, &id))
+}
@@ -7,16 +7,39 @@
 //! - Drawings: Drawing generation and export
 //! - Standards: Standards validation operations
 
+mod equipment;
+mod standards;
+
+pub use equipment::{
+    create_equipment, delete_equipment, get_equipment, list_equipment, update_equipment,
+};
+pub use standards::validate_standards;
+
+use crate::database::{ConnectionStatus, DatabaseState};
 use serde::{Deserialize, Serialize};
+use tauri::State;
 
-/// Application information returned by the greet command
+/// Application information returned by the get_app_info command
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppInfo {
     pub name: String,
     pub version: String,
 }
 
-/// A sample greeting command to verify IPC is working
+/// Application and database state returned by the app_health command
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppHealth {
+    pub name: String,
+    pub version: String,
+    /// Status of the shared database connection
+    pub database: ConnectionStatus,
+    /// Whether the connected catalog holds any equipment
+    pub catalog_loaded: bool,
+}
+
+/// A sample greeting command to verify IPC is working, in debug builds only
+#[cfg(debug_assertions)]
 #[tauri::command]
 pub fn greet(name: &str) -> String {
     format!("Hello, {}! Welcome to AV Designer.", name)
@@ -31,10 +54,36 @@ pub fn get_app_info() -> AppInfo {
     }
 }
 
+/// Collects the app version, database connection status and catalog state
+///
+/// The catalog is only checked once the database is open, so polling health
+/// never creates the database file.
+pub fn health_report(database: &DatabaseState) -> AppHealth {
+    let info = get_app_info();
+    let status = database.status();
+    let catalog_loaded =
+        matches!(status, ConnectionStatus::Connected) && equipment::catalog_loaded(database);
+
+    AppHealth {
+        name: info.name,
+        version: info.version,
+        database: status,
+        catalog_loaded,
+    }
+}
+
+/// Report the app version, database connection status and catalog state
+#[tauri::command]
+pub fn app_health(database: State<'_, DatabaseState>) -> AppHealth {
+    health_report(&database)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::database::{DatabaseConfig, DATABASE_FILE_NAME};
 
+    #[cfg(debug_assertions)]
     #[test]
     fn test_greet() {
         let result = greet("World");
@@ -47,4 +96,29 @@ mod tests {
         assert_eq!(info.name, "AV Designer");
         assert!(!info.version.is_empty());
     }
+
+    #[test]
+    fn test_app_health_disconnected_before_db_connect() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = DatabaseState::new(DatabaseConfig::in_dir(dir.path()));
+
+        let health = health_report(&database);
+
+        assert_eq!(health.name, "AV Designer");
+        assert!(matches!(health.database, ConnectionStatus::Disconnected));
+        assert!(!health.catalog_loaded);
+        assert!(!dir.path().join(DATABASE_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_app_health_after_db_connect() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = DatabaseState::new(DatabaseConfig::in_dir(dir.path()));
+        database.connect();
+
+        let health = health_report(&database);
+
+        assert!(matches!(health.database, ConnectionStatus::Connected));
+        assert!(!health.catalog_loaded);
+    }
 }
@@ -0,0 +1,19 @@
+//! Standards Commands
+//!
+//! Checks room designs against the AV design rules in the standards module.
+
+use crate::drawings::{EquipmentInput, RoomInput};
+use crate::standards::{validate_room, StandardViolation};
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list the design rules a room breaks
+#[tauri::command]
+pub fn validate_standards(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<Vec<StandardViolation>, String> {
+    Ok(validate_room(&room, &equipment_catalog))
+}
@@ -0,0 +1,399 @@
+//! Equipment Catalog
+//!
+//! Stores equipment records in the local database. All queries bind their
+//! values as parameters rather than formatting them into SQL.
+
+use super::error::DatabaseError;
+use crate::drawings::{EquipmentCategory, EquipmentInput, EquipmentSpecs};
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+
+/// Columns read for an equipment record, in [`equipment_from_row`] order
+const EQUIPMENT_COLUMNS: &str = "id, manufacturer, model, sku, category, subcategory, \
+     description, cost, msrp, weight, height, width, depth, wattage, voltage, image_url, \
+     certifications";
+
+/// An equipment record in the catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Equipment {
+    /// Record ID, generated on create when empty
+    #[serde(default)]
+    pub id: String,
+    pub manufacturer: String,
+    pub model: String,
+    #[serde(default)]
+    pub sku: String,
+    pub category: EquipmentCategory,
+    pub subcategory: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Dealer cost, if known
+    #[serde(default)]
+    pub cost: Option<f64>,
+    /// Manufacturer's suggested retail price, if known
+    #[serde(default)]
+    pub msrp: Option<f64>,
+    /// Weight in pounds, if known
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// Platform certifications, e.g. "Microsoft Teams" or "Zoom Rooms"
+    #[serde(default)]
+    pub certifications: Vec<String>,
+    /// Dimensions and electrical specs
+    #[serde(default)]
+    pub specs: EquipmentSpecs,
+}
+
+impl From<&Equipment> for EquipmentInput {
+    fn from(equipment: &Equipment) -> Self {
+        EquipmentInput {
+            id: equipment.id.clone(),
+            manufacturer: equipment.manufacturer.clone(),
+            model: equipment.model.clone(),
+            category: equipment.category,
+            subcategory: equipment.subcategory.clone(),
+            weight: equipment.weight,
+            cost: equipment.cost,
+            certifications: equipment.certifications.clone(),
+            specs: equipment.specs.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Row Conversion (internal)
+// ============================================================================
+
+/// Stored name of a category, matching its serialized form
+fn category_name(category: EquipmentCategory) -> &'static str {
+    match category {
+        EquipmentCategory::Video => "video",
+        EquipmentCategory::Audio => "audio",
+        EquipmentCategory::Control => "control",
+        EquipmentCategory::Infrastructure => "infrastructure",
+    }
+}
+
+/// Wrap a conversion failure in column `index` as a rusqlite error
+fn conversion_error(
+    index: usize,
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(error))
+}
+
+/// Build an equipment record from a row selected with [`EQUIPMENT_COLUMNS`]
+fn equipment_from_row(row: &Row) -> rusqlite::Result<Equipment> {
+    let category: String = row.get(4)?;
+    let certifications: String = row.get(16)?;
+
+    Ok(Equipment {
+        id: row.get(0)?,
+        manufacturer: row.get(1)?,
+        model: row.get(2)?,
+        sku: row.get(3)?,
+        category: serde_json::from_value(serde_json::Value::String(category))
+            .map_err(|e| conversion_error(4, e))?,
+        subcategory: row.get(5)?,
+        description: row.get(6)?,
+        cost: row.get(7)?,
+        msrp: row.get(8)?,
+        weight: row.get(9)?,
+        certifications: serde_json::from_str(&certifications)
+            .map_err(|e| conversion_error(16, e))?,
+        specs: EquipmentSpecs {
+            height: row.get(10)?,
+            width: row.get(11)?,
+            depth: row.get(12)?,
+            wattage: row.get(13)?,
+            voltage: row.get(14)?,
+            image_url: row.get(15)?,
+        },
+    })
+}
+
+/// Check the fields every record needs
+fn validate_equipment(equipment: &Equipment) -> Result<(), DatabaseError> {
+    if equipment.manufacturer.trim().is_empty() {
+        return Err(DatabaseError::InvalidRecord(
+            "Manufacturer cannot be empty".to_string(),
+        ));
+    }
+    if equipment.model.trim().is_empty() {
+        return Err(DatabaseError::InvalidRecord(
+            "Model cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Catalog Operations
+// ============================================================================
+
+/// Add an equipment record, generating an ID when it has none
+pub fn insert_equipment(
+    conn: &Connection,
+    equipment: &Equipment,
+) -> Result<Equipment, DatabaseError> {
+    validate_equipment(equipment)?;
+
+    let mut created = equipment.clone();
+    if created.id.is_empty() {
+        created.id = conn.query_row("SELECT lower(hex(randomblob(16)))", [], |row| row.get(0))?;
+    }
+
+    conn.execute(
+        "INSERT INTO equipment (id, manufacturer, model, sku, category, subcategory, \
+         description, cost, msrp, weight, height, width, depth, wattage, voltage, image_url, \
+         certifications, updated_at) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+        params![
+            created.id,
+            created.manufacturer,
+            created.model,
+            created.sku,
+            category_name(created.category),
+            created.subcategory,
+            created.description,
+            created.cost,
+            created.msrp,
+            created.weight,
+            created.specs.height,
+            created.specs.width,
+            created.specs.depth,
+            created.specs.wattage,
+            created.specs.voltage,
+            created.specs.image_url,
+            serde_json::to_string(&created.certifications).unwrap_or_default(),
+            chrono::Utc::now().to_rfc3339(),
+        ],
+    )?;
+
+    Ok(created)
+}
+
+/// Get an equipment record by ID
+pub fn get_equipment_by_id(conn: &Connection, id: &str) -> Result<Equipment, DatabaseError> {
+    conn.query_row(
+        &format!("SELECT {} FROM equipment WHERE id = ?1", EQUIPMENT_COLUMNS),
+        [id],
+        equipment_from_row,
+    )
+    .optional()?
+    .ok_or_else(|| DatabaseError::RecordNotFound(id.to_string()))
+}
+
+/// List equipment records by manufacturer and model, optionally in one category
+pub fn list_equipment_records(
+    conn: &Connection,
+    category: Option<EquipmentCategory>,
+) -> Result<Vec<Equipment>, DatabaseError> {
+    let mut statement = conn.prepare(&format!(
+        "SELECT {} FROM equipment WHERE ?1 IS NULL OR category = ?1 \
+         ORDER BY manufacturer, model",
+        EQUIPMENT_COLUMNS
+    ))?;
+    let records = statement
+        .query_map([category.map(category_name)], equipment_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(records)
+}
+
+/// Whether the catalog holds any equipment records
+pub fn has_equipment_records(conn: &Connection) -> Result<bool, DatabaseError> {
+    let exists = conn.query_row("SELECT EXISTS(SELECT 1 FROM equipment)", [], |row| {
+        row.get(0)
+    })?;
+    Ok(exists)
+}
+
+/// Replace an existing equipment record
+pub fn update_equipment_record(
+    conn: &Connection,
+    equipment: &Equipment,
+) -> Result<Equipment, DatabaseError> {
+    validate_equipment(equipment)?;
+
+    let changed = conn.execute(
+        "UPDATE equipment SET manufacturer = ?2, model = ?3, sku = ?4, category = ?5, \
+         subcategory = ?6, description = ?7, cost = ?8, msrp = ?9, weight = ?10, height = ?11, \
+         width = ?12, depth = ?13, wattage = ?14, voltage = ?15, image_url = ?16, \
+         certifications = ?17, updated_at = ?18 \
+         WHERE id = ?1",
+        params![
+            equipment.id,
+            equipment.manufacturer,
+            equipment.model,
+            equipment.sku,
+            category_name(equipment.category),
+            equipment.subcategory,
+            equipment.description,
+            equipment.cost,
+            equipment.msrp,
+            equipment.weight,
+            equipment.specs.height,
+            equipment.specs.width,
+            equipment.specs.depth,
+            equipment.specs.wattage,
+            equipment.specs.voltage,
+            equipment.specs.image_url,
+            serde_json::to_string(&equipment.certifications).unwrap_or_default(),
+            chrono::Utc::now().to_rfc3339(),
+        ],
+    )?;
+
+    if changed == 0 {
+        return Err(DatabaseError::RecordNotFound(equipment.id.clone()));
+    }
+    Ok(equipment.clone())
+}
+
+/// Remove an equipment record by ID
+pub fn delete_equipment_record(conn: &Connection, id: &str) -> Result<(), DatabaseError> {
+    let changed = conn.execute("DELETE FROM equipment WHERE id = ?1", [id])?;
+    if changed == 0 {
+        return Err(DatabaseError::RecordNotFound(id.to_string()));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::schema::run_migrations;
+
+    fn create_test_connection() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        run_migrations(&mut conn).unwrap();
+        conn
+    }
+
+    fn create_test_equipment(model: &str, category: EquipmentCategory) -> Equipment {
+        Equipment {
+            id: String::new(),
+            manufacturer: "Shure".to_string(),
+            model: model.to_string(),
+            sku: format!("{}-SKU", model),
+            category,
+            subcategory: "microphones".to_string(),
+            description: Some("Ceiling array microphone".to_string()),
+            cost: Some(4500.0),
+            msrp: Some(5999.0),
+            weight: Some(12.5),
+            certifications: vec!["Microsoft Teams".to_string()],
+            specs: EquipmentSpecs {
+                height: Some(2.0),
+                width: Some(24.0),
+                depth: Some(24.0),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_insert_and_get_round_trip() {
+        let conn = create_test_connection();
+
+        let created = insert_equipment(
+            &conn,
+            &create_test_equipment("MXA920", EquipmentCategory::Audio),
+        )
+        .unwrap();
+
+        assert!(!created.id.is_empty());
+        assert_eq!(get_equipment_by_id(&conn, &created.id).unwrap(), created);
+    }
+
+    #[test]
+    fn test_list_filters_by_category() {
+        let conn = create_test_connection();
+        for (model, category) in [
+            ("MXA920", EquipmentCategory::Audio),
+            ("MXA310", EquipmentCategory::Audio),
+            ("QM55B", EquipmentCategory::Video),
+        ] {
+            insert_equipment(&conn, &create_test_equipment(model, category)).unwrap();
+        }
+
+        let audio = list_equipment_records(&conn, Some(EquipmentCategory::Audio)).unwrap();
+        let all = list_equipment_records(&conn, None).unwrap();
+
+        let models: Vec<&str> = audio.iter().map(|e| e.model.as_str()).collect();
+        assert_eq!(models, vec!["MXA310", "MXA920"]);
+        assert_eq!(all.len(), 3);
+    }
+
+    #[test]
+    fn test_has_equipment_records() {
+        let conn = create_test_connection();
+        assert!(!has_equipment_records(&conn).unwrap());
+
+        insert_equipment(
+            &conn,
+            &create_test_equipment("MXA920", EquipmentCategory::Audio),
+        )
+        .unwrap();
+
+        assert!(has_equipment_records(&conn).unwrap());
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let conn = create_test_connection();
+        let mut equipment = insert_equipment(
+            &conn,
+            &create_test_equipment("MXA920", EquipmentCategory::Audio),
+        )
+        .unwrap();
+
+        equipment.cost = Some(4250.0);
+        update_equipment_record(&conn, &equipment).unwrap();
+        assert_eq!(
+            get_equipment_by_id(&conn, &equipment.id).unwrap().cost,
+            Some(4250.0)
+        );
+
+        delete_equipment_record(&conn, &equipment.id).unwrap();
+        assert!(matches!(
+            get_equipment_by_id(&conn, &equipment.id),
+            Err(DatabaseError::RecordNotFound(_))
+        ));
+        assert!(matches!(
+            delete_equipment_record(&conn, &equipment.id),
+            Err(DatabaseError::RecordNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_values_are_bound_not_interpolated() {
+        let conn = create_test_connection();
+        let mut equipment = create_test_equipment("MXA920", EquipmentCategory::Audio);
+        equipment.model = "X'); DROP TABLE equipment; --".to_string();
+
+        let created = insert_equipment(&conn, &equipment).unwrap();
+
+        assert_eq!(
+            get_equipment_by_id(&conn, &created.id).unwrap().model,
+            equipment.model
+        );
+    }
+
+    #[test]
+    fn test_missing_manufacturer_rejected() {
+        let conn = create_test_connection();
+        let mut equipment = create_test_equipment("MXA920", EquipmentCategory::Audio);
+        equipment.manufacturer = " ".to_string();
+
+        assert!(matches!(
+            insert_equipment(&conn, &equipment),
+            Err(DatabaseError::InvalidRecord(_))
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+//! Database Errors
+//!
+//! Error type shared by local database operations.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can occur during database operations
+#[derive(Debug, Error, Serialize, Deserialize)]
+pub enum DatabaseError {
+    #[error("Path cannot be empty")]
+    EmptyPath,
+
+    #[error("File not found: {0}")]
+    FileNotFound(String),
+
+    #[error("File already exists: {0}")]
+    FileExists(String),
+
+    #[error("Database is not connected")]
+    NotConnected,
+
+    #[error("Record not found: {0}")]
+    RecordNotFound(String),
+
+    #[error("Invalid record: {0}")]
+    InvalidRecord(String),
+
+    #[error("Invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    #[error("Database error: {0}")]
+    Sqlite(String),
+}
+
+impl From<rusqlite::Error> for DatabaseError {
+    fn from(error: rusqlite::Error) -> Self {
+        DatabaseError::Sqlite(error.to_string())
+    }
+}
@@ -1,9 +1,33 @@
 //! Database Module
 //!
 //! This module handles local SQLite database operations for offline caching
-//! and sync with the Supabase cloud database.
+//! and sync with the Supabase cloud database, plus standalone snapshot
+//! files for backup and transfer.
 
+mod catalog;
+mod error;
+mod schema;
+mod snapshot;
+
+pub use catalog::{
+    delete_equipment_record, get_equipment_by_id, has_equipment_records, insert_equipment,
+    list_equipment_records, update_equipment_record, Equipment,
+};
+pub use error::DatabaseError;
+pub use schema::SCHEMA_VERSION;
+pub use snapshot::{
+    export_db_snapshot, export_snapshot, import_db_snapshot, import_snapshot, validate_snapshot,
+    SnapshotResult,
+};
+
+use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use tauri::State;
+
+/// File name of the local database inside the app data directory
+pub const DATABASE_FILE_NAME: &str = "av_designer.db";
 
 /// Connection status for the local database
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,35 +44,30 @@ pub struct DatabaseConfig {
     pub path: String,
 }
 
-impl Default for DatabaseConfig {
-    fn default() -> Self {
+impl DatabaseConfig {
+    /// Configuration for the database file inside `dir`, normally the app
+    /// data directory
+    pub fn in_dir(dir: &Path) -> Self {
         Self {
-            path: "av_designer.db".to_string(),
+            path: dir.join(DATABASE_FILE_NAME).to_string_lossy().into_owned(),
         }
     }
 }
 
-/// Placeholder for database manager
-/// Will be expanded to handle SQLite operations
+/// Manages the connection to the local SQLite database
 pub struct DatabaseManager {
     config: DatabaseConfig,
     status: ConnectionStatus,
+    connection: Option<Connection>,
 }
 
 impl DatabaseManager {
-    /// Create a new database manager with default configuration
-    pub fn new() -> Self {
-        Self {
-            config: DatabaseConfig::default(),
-            status: ConnectionStatus::Disconnected,
-        }
-    }
-
     /// Create a new database manager with custom configuration
     pub fn with_config(config: DatabaseConfig) -> Self {
         Self {
             config,
             status: ConnectionStatus::Disconnected,
+            connection: None,
         }
     }
 
@@ -61,27 +80,305 @@ impl DatabaseManager {
     pub fn path(&self) -> &str {
         &self.config.path
     }
+
+    /// Get the open connection, if connected
+    pub fn connection(&self) -> Option<&Connection> {
+        self.connection.as_ref()
+    }
+
+    /// Get the open connection mutably, e.g. to start a transaction
+    pub fn connection_mut(&mut self) -> Option<&mut Connection> {
+        self.connection.as_mut()
+    }
+
+    /// Open the database file, creating it and its schema if absent
+    ///
+    /// The connection is kept only once its schema is migrated, so a
+    /// database that fails to migrate, such as one written by a newer
+    /// version, is never used. The status becomes `Connected` on success and
+    /// `Error` on failure.
+    pub fn connect(&mut self) -> Result<(), DatabaseError> {
+        self.connection = None;
+        if self.config.path.is_empty() {
+            self.status = ConnectionStatus::Error(DatabaseError::EmptyPath.to_string());
+            return Err(DatabaseError::EmptyPath);
+        }
+
+        let opened = Connection::open(&self.config.path)
+            .map_err(DatabaseError::from)
+            .and_then(|mut conn| {
+                schema::run_migrations(&mut conn)?;
+                Ok(conn)
+            });
+        match opened {
+            Ok(conn) => {
+                self.connection = Some(conn);
+                self.status = ConnectionStatus::Connected;
+                Ok(())
+            }
+            Err(error) => {
+                self.status = ConnectionStatus::Error(error.to_string());
+                Err(error)
+            }
+        }
+    }
+
+    /// Apply pending schema migrations, returning the resulting schema version
+    ///
+    /// The connection is closed if a migration fails.
+    pub fn run_migrations(&mut self) -> Result<u32, DatabaseError> {
+        let conn = self
+            .connection
+            .as_mut()
+            .ok_or(DatabaseError::NotConnected)?;
+        schema::run_migrations(conn).inspect_err(|e| {
+            self.connection = None;
+            self.status = ConnectionStatus::Error(e.to_string());
+        })
+    }
+
+    /// Check that the connection still answers queries and update the status
+    ///
+    /// A failing connection is closed so the next use reopens it. An `Error`
+    /// status is kept until the database is opened again.
+    pub fn health_check(&mut self) -> ConnectionStatus {
+        if matches!(self.status, ConnectionStatus::Error(_)) {
+            return self.status.clone();
+        }
+        if let Some(conn) = &self.connection {
+            if let Err(e) = conn.query_row("SELECT 1", [], |row| row.get::<_, i32>(0)) {
+                self.connection = None;
+                self.status = ConnectionStatus::Error(DatabaseError::from(e).to_string());
+            }
+        }
+        self.status.clone()
+    }
 }
 
-impl Default for DatabaseManager {
-    fn default() -> Self {
-        Self::new()
+// ============================================================================
+// Shared Connection
+// ============================================================================
+
+/// The app's single database connection, shared by commands through tauri
+/// state
+pub struct DatabaseState {
+    manager: Mutex<DatabaseManager>,
+}
+
+impl DatabaseState {
+    /// Create the shared state; the database is opened on first use
+    pub fn new(config: DatabaseConfig) -> Self {
+        Self {
+            manager: Mutex::new(DatabaseManager::with_config(config)),
+        }
+    }
+
+    /// Lock the manager, recovering it if a command panicked while holding it
+    fn lock(&self) -> MutexGuard<'_, DatabaseManager> {
+        self.manager.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Status of the shared connection, `Disconnected` until first opened
+    pub fn status(&self) -> ConnectionStatus {
+        self.lock().status().clone()
+    }
+
+    /// Open the database unless already open, then check the connection
+    ///
+    /// Failures are reported through the returned status.
+    pub fn connect(&self) -> ConnectionStatus {
+        let mut manager = self.lock();
+        if manager.connection().is_none() {
+            let _ = manager.connect();
+        }
+        manager.health_check()
+    }
+
+    /// Run an operation on the shared connection, opening the database first
+    /// if needed
+    pub fn with_connection<T>(
+        &self,
+        operation: impl FnOnce(&mut Connection) -> Result<T, DatabaseError>,
+    ) -> Result<T, DatabaseError> {
+        let mut manager = self.lock();
+        if manager.connection().is_none() {
+            manager.connect()?;
+        }
+        let conn = manager
+            .connection_mut()
+            .ok_or(DatabaseError::NotConnected)?;
+        operation(conn)
+    }
+
+    /// Run an operation on the shared connection only if it is already open,
+    /// so the database file is never created
+    pub fn with_open_connection<T>(
+        &self,
+        operation: impl FnOnce(&Connection) -> Result<T, DatabaseError>,
+    ) -> Result<T, DatabaseError> {
+        let manager = self.lock();
+        let conn = manager.connection().ok_or(DatabaseError::NotConnected)?;
+        operation(conn)
     }
 }
 
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to open the local database and report its connection status
+#[tauri::command]
+pub fn db_connect(database: State<'_, DatabaseState>) -> ConnectionStatus {
+    database.connect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_database_manager_new() {
-        let manager = DatabaseManager::new();
+        let dir = tempfile::tempdir().unwrap();
+        let manager = create_test_manager(&dir);
         assert!(matches!(manager.status(), ConnectionStatus::Disconnected));
     }
 
     #[test]
-    fn test_database_config_default() {
-        let config = DatabaseConfig::default();
-        assert_eq!(config.path, "av_designer.db");
+    fn test_database_config_in_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DatabaseConfig::in_dir(dir.path());
+        assert_eq!(
+            Path::new(&config.path),
+            dir.path().join("av_designer.db").as_path()
+        );
+    }
+
+    fn create_test_manager(dir: &tempfile::TempDir) -> DatabaseManager {
+        DatabaseManager::with_config(DatabaseConfig::in_dir(dir.path()))
+    }
+
+    #[test]
+    fn test_connect_creates_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = create_test_manager(&dir);
+
+        manager.connect().unwrap();
+
+        assert!(matches!(manager.status(), ConnectionStatus::Connected));
+        let conn = manager.connection().unwrap();
+        assert_eq!(schema::schema_version(conn).unwrap(), SCHEMA_VERSION);
+        let tables: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master \
+                 WHERE name IN ('cached_records', 'pending_changes')",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(tables, 2);
+    }
+
+    #[test]
+    fn test_reconnect_keeps_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = create_test_manager(&dir);
+        manager.connect().unwrap();
+        manager
+            .connection()
+            .unwrap()
+            .execute(
+                "INSERT INTO cached_records VALUES ('equipment', 'eq-1', '{}', '2024-01-01')",
+                [],
+            )
+            .unwrap();
+
+        let mut manager = create_test_manager(&dir);
+        manager.connect().unwrap();
+
+        assert_eq!(manager.run_migrations().unwrap(), SCHEMA_VERSION);
+        let count: i64 = manager
+            .connection()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM cached_records", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_connect_failure_sets_error_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = DatabaseManager::with_config(DatabaseConfig {
+            path: dir
+                .path()
+                .join("missing/local.db")
+                .to_string_lossy()
+                .into_owned(),
+        });
+
+        assert!(manager.connect().is_err());
+        assert!(matches!(manager.status(), ConnectionStatus::Error(_)));
+    }
+
+    #[test]
+    fn test_newer_schema_is_never_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DatabaseConfig::in_dir(dir.path());
+        Connection::open(&config.path)
+            .unwrap()
+            .pragma_update(None, "user_version", SCHEMA_VERSION + 1)
+            .unwrap();
+        let database = DatabaseState::new(config);
+
+        assert!(matches!(database.connect(), ConnectionStatus::Error(_)));
+        assert!(database
+            .with_connection(|conn| list_equipment_records(conn, None))
+            .is_err());
+        assert!(matches!(
+            database.with_open_connection(has_equipment_records),
+            Err(DatabaseError::NotConnected)
+        ));
+        assert!(matches!(database.connect(), ConnectionStatus::Error(_)));
+    }
+
+    #[test]
+    fn test_health_check_without_connection() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = create_test_manager(&dir);
+        assert!(matches!(
+            manager.health_check(),
+            ConnectionStatus::Disconnected
+        ));
+        assert!(matches!(
+            manager.run_migrations(),
+            Err(DatabaseError::NotConnected)
+        ));
+    }
+
+    #[test]
+    fn test_state_shares_one_connection() {
+        let dir = tempfile::tempdir().unwrap();
+        let database = DatabaseState::new(DatabaseConfig::in_dir(dir.path()));
+        assert!(matches!(
+            database.with_open_connection(|_| Ok(())),
+            Err(DatabaseError::NotConnected)
+        ));
+        assert!(!dir.path().join(DATABASE_FILE_NAME).exists());
+
+        database
+            .with_connection(|conn| {
+                conn.execute("CREATE TEMP TABLE session (id INTEGER)", [])?;
+                Ok(())
+            })
+            .unwrap();
+
+        // A temp table is only visible on the connection that created it
+        assert!(matches!(database.connect(), ConnectionStatus::Connected));
+        let count: i64 = database
+            .with_open_connection(|conn| {
+                Ok(conn.query_row("SELECT COUNT(*) FROM session", [], |row| row.get(0))?)
+            })
+            .unwrap();
+        assert_eq!(count, 0);
+        assert!(matches!(database.status(), ConnectionStatus::Connected));
     }
 }
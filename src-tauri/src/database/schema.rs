@@ -0,0 +1,81 @@
+//! Database Schema
+//!
+//! Versioned migrations for the local SQLite database. The applied version
+//! is tracked in SQLite's `user_version` pragma.
+
+use super::error::DatabaseError;
+use rusqlite::Connection;
+
+/// Schema migrations in order; migration `n` brings the database to version `n + 1`
+const MIGRATIONS: &[&str] = &[
+    // 1: offline record cache and queued changes awaiting sync
+    "CREATE TABLE cached_records (
+        table_name TEXT NOT NULL,
+        id TEXT NOT NULL,
+        data TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (table_name, id)
+    );
+    CREATE TABLE pending_changes (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        table_name TEXT NOT NULL,
+        record_id TEXT NOT NULL,
+        operation TEXT NOT NULL,
+        data TEXT,
+        created_at TEXT NOT NULL
+    );",
+    // 2: equipment catalog
+    "CREATE TABLE equipment (
+        id TEXT PRIMARY KEY,
+        manufacturer TEXT NOT NULL,
+        model TEXT NOT NULL,
+        sku TEXT NOT NULL DEFAULT '',
+        category TEXT NOT NULL,
+        subcategory TEXT NOT NULL,
+        description TEXT,
+        cost REAL,
+        msrp REAL,
+        weight REAL,
+        height REAL,
+        width REAL,
+        depth REAL,
+        wattage REAL,
+        voltage TEXT,
+        image_url TEXT,
+        certifications TEXT NOT NULL DEFAULT '[]',
+        updated_at TEXT NOT NULL
+    );
+    CREATE INDEX equipment_sku ON equipment (sku);
+    CREATE INDEX equipment_category ON equipment (category);",
+];
+
+/// Latest schema version
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Get the schema version a database is at
+pub fn schema_version(conn: &Connection) -> Result<u32, DatabaseError> {
+    Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+}
+
+/// Apply pending migrations, returning the resulting schema version
+///
+/// Each migration runs in its own transaction together with the version
+/// bump, so a failed migration leaves the database at the previous version.
+pub fn run_migrations(conn: &mut Connection) -> Result<u32, DatabaseError> {
+    let current = schema_version(conn)?;
+    if current > SCHEMA_VERSION {
+        return Err(DatabaseError::Sqlite(format!(
+            "Database schema version {} is newer than supported version {}",
+            current, SCHEMA_VERSION
+        )));
+    }
+
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(current as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", index as u32 + 1)?;
+        tx.commit()?;
+    }
+
+    Ok(SCHEMA_VERSION)
+}
@@ -0,0 +1,258 @@
+//! Database Snapshots
+//!
+//! Copies the local database to a standalone file for backup or transfer
+//! to another machine, and restores such a snapshot after checking it.
+
+use super::error::DatabaseError;
+use super::schema::{self, SCHEMA_VERSION};
+use super::DatabaseState;
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, DatabaseName, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use tauri::State;
+
+/// Pages copied per step when restoring a snapshot
+const RESTORE_PAGES_PER_STEP: i32 = 256;
+
+/// Result of writing a database snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotResult {
+    pub file_path: String,
+    pub file_size_bytes: u64,
+    pub generated_at: String,
+}
+
+/// Writes a compacted copy of the database to `output_path`
+///
+/// Uses `VACUUM INTO`, which requires that the output file does not exist.
+pub fn export_snapshot(
+    conn: &Connection,
+    output_path: &str,
+) -> Result<SnapshotResult, DatabaseError> {
+    if output_path.is_empty() {
+        return Err(DatabaseError::EmptyPath);
+    }
+    if Path::new(output_path).exists() {
+        return Err(DatabaseError::FileExists(output_path.to_string()));
+    }
+
+    conn.execute("VACUUM INTO ?1", [output_path])?;
+
+    let file_size_bytes = std::fs::metadata(output_path).map(|m| m.len()).unwrap_or(0);
+
+    Ok(SnapshotResult {
+        file_path: output_path.to_string(),
+        file_size_bytes,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Checks that `path` is a readable SQLite database that passes an integrity
+/// check and holds an equipment catalog no newer than this version supports
+pub fn validate_snapshot(path: &str) -> Result<(), DatabaseError> {
+    if path.is_empty() {
+        return Err(DatabaseError::EmptyPath);
+    }
+    if !Path::new(path).is_file() {
+        return Err(DatabaseError::FileNotFound(path.to_string()));
+    }
+
+    let invalid = |e: rusqlite::Error| DatabaseError::InvalidSnapshot(e.to_string());
+    let snapshot =
+        Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(invalid)?;
+    let status: String = snapshot
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(invalid)?;
+
+    if status != "ok" {
+        return Err(DatabaseError::InvalidSnapshot(status));
+    }
+
+    let version = schema::schema_version(&snapshot)?;
+    if version > SCHEMA_VERSION {
+        return Err(DatabaseError::InvalidSnapshot(format!(
+            "schema version {} is newer than supported version {}",
+            version, SCHEMA_VERSION
+        )));
+    }
+
+    let has_equipment: bool = snapshot
+        .query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'equipment')",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(invalid)?;
+    if !has_equipment {
+        return Err(DatabaseError::InvalidSnapshot(
+            "no equipment table".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Replaces the contents of the database with a validated snapshot
+///
+/// The snapshot is migrated to the current schema in memory first, so the
+/// database is left untouched if it cannot be brought up to date.
+pub fn import_snapshot(conn: &mut Connection, path: &str) -> Result<(), DatabaseError> {
+    validate_snapshot(path)?;
+
+    let mut staged = Connection::open_in_memory()?;
+    staged.restore(
+        DatabaseName::Main,
+        path,
+        None::<fn(rusqlite::backup::Progress)>,
+    )?;
+    schema::run_migrations(&mut staged)?;
+
+    Backup::new(&staged, conn)?.run_to_completion(RESTORE_PAGES_PER_STEP, Duration::ZERO, None)?;
+    Ok(())
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to save the local database to a standalone snapshot file
+#[tauri::command]
+pub fn export_db_snapshot(
+    database: State<'_, DatabaseState>,
+    output_path: String,
+) -> Result<SnapshotResult, DatabaseError> {
+    database.with_connection(|conn| export_snapshot(conn, &output_path))
+}
+
+/// Tauri command to restore the local database from a snapshot file
+#[tauri::command]
+pub fn import_db_snapshot(
+    database: State<'_, DatabaseState>,
+    path: String,
+) -> Result<(), DatabaseError> {
+    database.with_connection(|conn| import_snapshot(conn, &path))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::insert_equipment;
+    use crate::drawings::test_support::create_test_catalog_equipment;
+    use crate::drawings::EquipmentCategory;
+
+    fn create_test_database() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        schema::run_migrations(&mut conn).unwrap();
+        for id in ["eq-1", "eq-2", "eq-3"] {
+            let equipment = create_test_catalog_equipment(id, EquipmentCategory::Video, "codecs");
+            insert_equipment(&conn, &equipment).unwrap();
+        }
+        conn.execute(
+            "INSERT INTO cached_records VALUES ('equipment', 'eq-1', '{}', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    /// Writes a standalone database built by `sql` and returns its path
+    fn write_test_snapshot(dir: &tempfile::TempDir, sql: &str) -> String {
+        let path = dir
+            .path()
+            .join("snapshot.db")
+            .to_string_lossy()
+            .into_owned();
+        Connection::open(&path).unwrap().execute_batch(sql).unwrap();
+        path
+    }
+
+    fn row_count(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+            row.get(0)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("snapshot.db")
+            .to_string_lossy()
+            .into_owned();
+        let source = create_test_database();
+
+        let result = export_snapshot(&source, &path).unwrap();
+        assert!(result.file_size_bytes > 0);
+
+        let mut restored = Connection::open_in_memory().unwrap();
+        import_snapshot(&mut restored, &path).unwrap();
+
+        for table in ["equipment", "cached_records"] {
+            assert_eq!(row_count(&restored, table), row_count(&source, table));
+        }
+        assert_eq!(schema::schema_version(&restored).unwrap(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_newer_schema_snapshot_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_test_snapshot(
+            &dir,
+            &format!(
+                "CREATE TABLE equipment (id TEXT PRIMARY KEY); PRAGMA user_version = {};",
+                SCHEMA_VERSION + 1
+            ),
+        );
+
+        let mut conn = create_test_database();
+        let result = import_snapshot(&mut conn, &path);
+
+        assert!(matches!(result, Err(DatabaseError::InvalidSnapshot(_))));
+        assert_eq!(row_count(&conn, "equipment"), 3);
+    }
+
+    #[test]
+    fn test_snapshot_without_catalog_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_test_snapshot(&dir, "CREATE TABLE notes (id INTEGER PRIMARY KEY);");
+
+        let mut conn = create_test_database();
+        let result = import_snapshot(&mut conn, &path);
+
+        assert!(matches!(result, Err(DatabaseError::InvalidSnapshot(_))));
+        assert_eq!(row_count(&conn, "equipment"), 3);
+    }
+
+    #[test]
+    fn test_existing_output_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.db");
+        std::fs::write(&path, b"keep me").unwrap();
+
+        let result = export_snapshot(&create_test_database(), path.to_str().unwrap());
+
+        assert!(matches!(result, Err(DatabaseError::FileExists(_))));
+        assert_eq!(std::fs::read(&path).unwrap(), b"keep me");
+    }
+
+    #[test]
+    fn test_non_database_snapshot_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.db");
+        std::fs::write(&path, b"this is not a sqlite database at all").unwrap();
+
+        let mut conn = create_test_database();
+        let result = import_snapshot(&mut conn, path.to_str().unwrap());
+
+        assert!(matches!(result, Err(DatabaseError::InvalidSnapshot(_))));
+        assert_eq!(row_count(&conn, "equipment"), 3);
+    }
+}
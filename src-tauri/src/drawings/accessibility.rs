@@ -0,0 +1,163 @@
+//! Accessibility (ADA) Mounting Check
+//!
+//! Flags user-operable devices (touch panels and keypads) whose operable
+//! parts sit outside the ADA unobstructed reach range.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, RoomInput};
+use serde::{Deserialize, Serialize};
+
+/// Lowest compliant height of operable parts above finished floor, in inches
+pub const ADA_MIN_REACH_HEIGHT: f64 = 15.0;
+
+/// Highest compliant height of operable parts above finished floor, in inches
+pub const ADA_MAX_REACH_HEIGHT: f64 = 48.0;
+
+/// Control subcategories that room users operate by hand
+const USER_OPERABLE_SUBCATEGORIES: [&str; 2] = ["touch-panels", "keypads"];
+
+// ============================================================================
+// ADA Issue - a user-operable device mounted out of reach range
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaIssue {
+    pub placed_equipment_id: String,
+    pub equipment_id: String,
+    pub mount_height: f64,
+    pub min_height: f64,
+    pub max_height: f64,
+}
+
+// ============================================================================
+// ADA Check
+// ============================================================================
+
+/// Returns true if the equipment is operated directly by room users
+///
+/// Only touch panels and keypads count; processors and interfaces in the
+/// Control category live in racks and closets out of users' reach.
+fn is_user_operable(equipment: &EquipmentInput) -> bool {
+    equipment.category == EquipmentCategory::Control
+        && USER_OPERABLE_SUBCATEGORIES.contains(&equipment.subcategory.as_str())
+}
+
+/// Flags user-operable devices mounted outside the ADA reach range
+///
+/// Devices without a mount height, or without a catalog entry, are skipped
+/// since their compliance cannot be determined.
+pub fn ada_check(room: &RoomInput, equipment_catalog: &[EquipmentInput]) -> Vec<AdaIssue> {
+    room.placed_equipment
+        .iter()
+        .filter(|placed| {
+            equipment_catalog
+                .iter()
+                .find(|e| e.id == placed.equipment_id)
+                .is_some_and(is_user_operable)
+        })
+        .filter_map(|placed| {
+            let height = placed.mount_height?;
+            if (ADA_MIN_REACH_HEIGHT..=ADA_MAX_REACH_HEIGHT).contains(&height) {
+                return None;
+            }
+            Some(AdaIssue {
+                placed_equipment_id: placed.id.clone(),
+                equipment_id: placed.equipment_id.clone(),
+                mount_height: height,
+                min_height: ADA_MIN_REACH_HEIGHT,
+                max_height: ADA_MAX_REACH_HEIGHT,
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to flag user-operable devices mounted outside ADA reach range
+#[tauri::command]
+pub fn check_ada_mounting(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<Vec<AdaIssue>, String> {
+    Ok(ada_check(&room, &equipment_catalog))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{MountType, PlacedEquipmentInput};
+    use crate::drawings::test_support::{
+        create_test_equipment, create_test_placed_equipment, create_test_room,
+    };
+
+    fn create_test_mounted(
+        id: &str,
+        equipment_id: &str,
+        mount_height: Option<f64>,
+    ) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            mount_type: MountType::Wall,
+            mount_height,
+            ..create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    fn test_catalog() -> Vec<EquipmentInput> {
+        vec![
+            create_test_equipment("panel-1", EquipmentCategory::Control, "touch-panels"),
+            create_test_equipment("keypad-1", EquipmentCategory::Control, "keypads"),
+            create_test_equipment("processor-1", EquipmentCategory::Control, "processors"),
+            create_test_equipment("display-1", EquipmentCategory::Video, "displays"),
+        ]
+    }
+
+    #[test]
+    fn test_touch_panel_too_high_flagged() {
+        let room = create_test_room(vec![create_test_mounted("p-panel", "panel-1", Some(60.0))]);
+
+        let issues = ada_check(&room, &test_catalog());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].placed_equipment_id, "p-panel");
+        assert_eq!(issues[0].mount_height, 60.0);
+    }
+
+    #[test]
+    fn test_touch_panel_in_range_passes() {
+        let room = create_test_room(vec![create_test_mounted("p-panel", "panel-1", Some(42.0))]);
+
+        assert!(ada_check(&room, &test_catalog()).is_empty());
+    }
+
+    #[test]
+    fn test_non_operable_device_ignored() {
+        let room = create_test_room(vec![
+            create_test_mounted("p-display", "display-1", Some(72.0)),
+            create_test_mounted("p-panel", "panel-1", None),
+        ]);
+
+        assert!(ada_check(&room, &test_catalog()).is_empty());
+    }
+
+    #[test]
+    fn test_rack_mounted_processor_ignored() {
+        let room = create_test_room(vec![
+            PlacedEquipmentInput {
+                mount_type: MountType::Rack,
+                ..create_test_mounted("p-processor", "processor-1", Some(70.0))
+            },
+            create_test_mounted("p-keypad", "keypad-1", Some(70.0)),
+        ]);
+
+        let issues = ada_check(&room, &test_catalog());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].placed_equipment_id, "p-keypad");
+    }
+}
@@ -0,0 +1,112 @@
+//! Equipment Alternatives
+//!
+//! Suggests lower-cost or comparable substitutes for a device to help
+//! value-engineer a design.
+
+use super::electrical::EquipmentInput;
+
+/// Suggests catalog items that can substitute for a device
+///
+/// Alternatives share the device's category and subcategory and have a
+/// cost within `max_price_delta` of it. Results are sorted by cost, lowest
+/// first, and exclude the device itself and items without a cost.
+pub fn suggest_alternatives(
+    equipment_id: &str,
+    equipment_catalog: &[EquipmentInput],
+    max_price_delta: f64,
+) -> Result<Vec<EquipmentInput>, String> {
+    let original = equipment_catalog
+        .iter()
+        .find(|e| e.id == equipment_id)
+        .ok_or_else(|| format!("Equipment not found: {}", equipment_id))?;
+
+    let original_cost = original
+        .cost
+        .ok_or_else(|| format!("Equipment {} has no cost to compare against", equipment_id))?;
+
+    let mut alternatives: Vec<EquipmentInput> = equipment_catalog
+        .iter()
+        .filter(|e| {
+            e.id != original.id
+                && e.category == original.category
+                && e.subcategory == original.subcategory
+                && e.cost
+                    .map(|cost| (cost - original_cost).abs() <= max_price_delta)
+                    .unwrap_or(false)
+        })
+        .cloned()
+        .collect();
+
+    alternatives.sort_by(|a, b| {
+        a.cost
+            .unwrap_or_default()
+            .total_cmp(&b.cost.unwrap_or_default())
+    });
+
+    Ok(alternatives)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to suggest substitute equipment within a price band
+#[tauri::command]
+pub fn suggest_equipment_alternatives(
+    equipment_id: String,
+    equipment_catalog: Vec<EquipmentInput>,
+    max_price_delta: f64,
+) -> Result<Vec<EquipmentInput>, String> {
+    suggest_alternatives(&equipment_id, &equipment_catalog, max_price_delta)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::EquipmentCategory;
+    use crate::drawings::test_support;
+
+    fn create_test_equipment(id: &str, subcategory: &str, cost: Option<f64>) -> EquipmentInput {
+        EquipmentInput {
+            cost,
+            ..test_support::create_test_equipment(id, EquipmentCategory::Video, subcategory)
+        }
+    }
+
+    fn create_test_catalog() -> Vec<EquipmentInput> {
+        vec![
+            create_test_equipment("display-65", "displays", Some(2000.0)),
+            create_test_equipment("display-55", "displays", Some(1500.0)),
+            create_test_equipment("display-50", "displays", Some(1200.0)),
+            create_test_equipment("display-98", "displays", Some(9000.0)),
+            create_test_equipment("display-tbd", "displays", None),
+            create_test_equipment("camera-1", "cameras", Some(1800.0)),
+        ]
+    }
+
+    #[test]
+    fn test_cheaper_same_subcategory_alternatives() {
+        let alternatives =
+            suggest_alternatives("display-65", &create_test_catalog(), 1000.0).unwrap();
+
+        let ids: Vec<&str> = alternatives.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["display-50", "display-55"]);
+    }
+
+    #[test]
+    fn test_out_of_band_alternatives_excluded() {
+        let alternatives =
+            suggest_alternatives("display-65", &create_test_catalog(), 400.0).unwrap();
+
+        assert!(alternatives.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_equipment_errors() {
+        assert!(suggest_alternatives("missing", &create_test_catalog(), 1000.0).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+//! Amplifier Sizing
+//!
+//! Sums the rated power of the speakers on each amplifier zone and
+//! recommends an amplifier size with headroom.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, RoomInput};
+use serde::{Deserialize, Serialize};
+
+/// Amplifier power recommended per watt of continuous speaker load
+pub const AMP_HEADROOM: f64 = 1.5;
+
+/// Zone name used for speakers not assigned to an amplifier zone
+const UNASSIGNED_ZONE: &str = "unassigned";
+
+// ============================================================================
+// Amp Recommendation - sizing for one amplifier zone
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmpRecommendation {
+    pub zone: String,
+    pub speaker_ids: Vec<String>,
+    /// Sum of the speakers' continuous power ratings, in watts
+    pub total_load_watts: f64,
+    /// Minimum amplifier output for the zone, in watts
+    pub recommended_watts: f64,
+    /// Placed speakers with no power rating, not counted in the load
+    pub unrated_speaker_ids: Vec<String>,
+}
+
+// ============================================================================
+// Amplifier Sizing
+// ============================================================================
+
+/// Recommends an amplifier size for each zone of speakers in the room
+///
+/// Speaker load comes from the catalog wattage rating. Speakers without an
+/// amplifier zone are sized together under "unassigned". Zones are listed
+/// in the order their first speaker appears in the room.
+pub fn amplifier_sizing(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+) -> Vec<AmpRecommendation> {
+    let mut recommendations: Vec<AmpRecommendation> = Vec::new();
+
+    for placed in &room.placed_equipment {
+        let Some(speaker) = equipment_catalog.iter().find(|e| {
+            e.id == placed.equipment_id
+                && e.category == EquipmentCategory::Audio
+                && e.subcategory == "speakers"
+        }) else {
+            continue;
+        };
+
+        let zone = placed.amp_zone.as_deref().unwrap_or(UNASSIGNED_ZONE);
+        let index = match recommendations.iter().position(|r| r.zone == zone) {
+            Some(index) => index,
+            None => {
+                recommendations.push(AmpRecommendation {
+                    zone: zone.to_string(),
+                    speaker_ids: Vec::new(),
+                    total_load_watts: 0.0,
+                    recommended_watts: 0.0,
+                    unrated_speaker_ids: Vec::new(),
+                });
+                recommendations.len() - 1
+            }
+        };
+
+        let recommendation = &mut recommendations[index];
+        recommendation.speaker_ids.push(placed.id.clone());
+        match speaker.specs.wattage {
+            Some(watts) => recommendation.total_load_watts += watts,
+            None => recommendation.unrated_speaker_ids.push(placed.id.clone()),
+        }
+    }
+
+    for recommendation in &mut recommendations {
+        recommendation.recommended_watts = recommendation.total_load_watts * AMP_HEADROOM;
+    }
+
+    recommendations
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to recommend amplifier power per speaker zone
+#[tauri::command]
+pub fn compute_amplifier_sizing(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<Vec<AmpRecommendation>, String> {
+    Ok(amplifier_sizing(&room, &equipment_catalog))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentSpecs, MountType, PlacedEquipmentInput};
+    use crate::drawings::test_support::{
+        create_test_equipment, create_test_placed_equipment, create_test_room,
+    };
+
+    fn create_test_speaker(id: &str, wattage: Option<f64>) -> EquipmentInput {
+        EquipmentInput {
+            specs: EquipmentSpecs {
+                wattage,
+                ..Default::default()
+            },
+            ..create_test_equipment(id, EquipmentCategory::Audio, "speakers")
+        }
+    }
+
+    fn create_test_placed_speaker(
+        id: &str,
+        equipment_id: &str,
+        amp_zone: Option<&str>,
+    ) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            mount_type: MountType::Ceiling,
+            amp_zone: amp_zone.map(str::to_string),
+            ..create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    #[test]
+    fn test_four_30w_speakers_need_180w() {
+        let catalog = vec![create_test_speaker("spk-30", Some(30.0))];
+        let room = create_test_room(
+            (1..=4)
+                .map(|i| create_test_placed_speaker(&format!("s{}", i), "spk-30", Some("Zone A")))
+                .collect(),
+        );
+
+        let recommendations = amplifier_sizing(&room, &catalog);
+
+        assert_eq!(recommendations.len(), 1);
+        assert_eq!(recommendations[0].zone, "Zone A");
+        assert_eq!(recommendations[0].total_load_watts, 120.0);
+        assert!(recommendations[0].recommended_watts >= 180.0);
+    }
+
+    #[test]
+    fn test_zones_sized_separately() {
+        let catalog = vec![
+            create_test_speaker("spk-30", Some(30.0)),
+            create_test_speaker("spk-60", Some(60.0)),
+        ];
+        let room = create_test_room(vec![
+            create_test_placed_speaker("s1", "spk-30", Some("Zone A")),
+            create_test_placed_speaker("s2", "spk-60", Some("Zone B")),
+            create_test_placed_speaker("s3", "spk-60", None),
+        ]);
+
+        let recommendations = amplifier_sizing(&room, &catalog);
+
+        let zones: Vec<(&str, f64)> = recommendations
+            .iter()
+            .map(|r| (r.zone.as_str(), r.recommended_watts))
+            .collect();
+        assert_eq!(
+            zones,
+            vec![("Zone A", 45.0), ("Zone B", 90.0), ("unassigned", 90.0)]
+        );
+    }
+
+    #[test]
+    fn test_unrated_speakers_reported() {
+        let catalog = vec![create_test_speaker("spk-unknown", None)];
+        let room = create_test_room(vec![create_test_placed_speaker(
+            "s1",
+            "spk-unknown",
+            Some("Zone A"),
+        )]);
+
+        let recommendations = amplifier_sizing(&room, &catalog);
+
+        assert_eq!(recommendations[0].unrated_speaker_ids, vec!["s1"]);
+        assert_eq!(recommendations[0].recommended_watts, 0.0);
+    }
+}
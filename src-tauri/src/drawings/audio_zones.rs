@@ -0,0 +1,158 @@
+//! Audio Zone Grouping
+//!
+//! Clusters ceiling and wall speakers into DSP zones by proximity.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, MountType, RoomInput};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Audio Zone - output speaker grouping
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioZone {
+    pub id: String,
+    pub speaker_ids: Vec<String>,
+    pub centroid_x: f64,
+    pub centroid_y: f64,
+}
+
+// ============================================================================
+// Audio Zone Clustering
+// ============================================================================
+
+/// Groups ceiling and wall speakers into zones by proximity
+///
+/// Each speaker joins the first zone whose centroid lies within
+/// `max_zone_radius`, otherwise it starts a new zone. Zone centroids are
+/// updated as speakers are added.
+pub fn audio_zones(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+    max_zone_radius: f64,
+) -> Vec<AudioZone> {
+    let mut zones: Vec<AudioZone> = Vec::new();
+
+    let speakers = room.placed_equipment.iter().filter(|placed| {
+        matches!(placed.mount_type, MountType::Ceiling | MountType::Wall)
+            && equipment_catalog
+                .iter()
+                .find(|e| e.id == placed.equipment_id)
+                .map(|e| e.category == EquipmentCategory::Audio && e.subcategory == "speakers")
+                .unwrap_or(false)
+    });
+
+    for speaker in speakers {
+        let nearby = zones.iter_mut().find(|zone| {
+            let dx = speaker.x - zone.centroid_x;
+            let dy = speaker.y - zone.centroid_y;
+            (dx * dx + dy * dy).sqrt() <= max_zone_radius
+        });
+
+        match nearby {
+            Some(zone) => {
+                let count = zone.speaker_ids.len() as f64;
+                zone.centroid_x = (zone.centroid_x * count + speaker.x) / (count + 1.0);
+                zone.centroid_y = (zone.centroid_y * count + speaker.y) / (count + 1.0);
+                zone.speaker_ids.push(speaker.id.clone());
+            }
+            None => zones.push(AudioZone {
+                id: format!("zone-{}", zones.len() + 1),
+                speaker_ids: vec![speaker.id.clone()],
+                centroid_x: speaker.x,
+                centroid_y: speaker.y,
+            }),
+        }
+    }
+
+    zones
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to group a room's speakers into audio zones
+#[tauri::command]
+pub fn compute_audio_zones(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+    max_zone_radius: f64,
+) -> Result<Vec<AudioZone>, String> {
+    if max_zone_radius <= 0.0 {
+        return Err("Zone radius must be greater than zero".to_string());
+    }
+
+    Ok(audio_zones(&room, &equipment_catalog, max_zone_radius))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::PlacedEquipmentInput;
+    use crate::drawings::test_support::{
+        create_test_equipment, create_test_placed_equipment, create_test_room,
+    };
+
+    fn create_test_speaker(id: &str) -> EquipmentInput {
+        create_test_equipment(id, EquipmentCategory::Audio, "speakers")
+    }
+
+    fn create_test_placed_speaker(id: &str, x: f64, y: f64) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            x,
+            y,
+            mount_type: MountType::Ceiling,
+            ..create_test_placed_equipment(id, "speaker-1")
+        }
+    }
+
+    #[test]
+    fn test_two_far_apart_groups_yield_two_zones() {
+        let room = create_test_room(vec![
+            create_test_placed_speaker("s-1", 10.0, 10.0),
+            create_test_placed_speaker("s-2", 12.0, 10.0),
+            create_test_placed_speaker("s-3", 80.0, 80.0),
+            create_test_placed_speaker("s-4", 82.0, 80.0),
+        ]);
+        let catalog = vec![create_test_speaker("speaker-1")];
+
+        let zones = audio_zones(&room, &catalog, 5.0);
+
+        assert_eq!(zones.len(), 2);
+        assert_eq!(zones[0].speaker_ids, vec!["s-1", "s-2"]);
+        assert_eq!(zones[1].speaker_ids, vec!["s-3", "s-4"]);
+        assert_eq!(zones[0].centroid_x, 11.0);
+        assert_eq!(zones[1].centroid_y, 80.0);
+    }
+
+    #[test]
+    fn test_tight_group_yields_one_zone() {
+        let room = create_test_room(vec![
+            create_test_placed_speaker("s-1", 10.0, 10.0),
+            create_test_placed_speaker("s-2", 11.0, 10.0),
+            create_test_placed_speaker("s-3", 10.0, 11.0),
+        ]);
+        let catalog = vec![create_test_speaker("speaker-1")];
+
+        let zones = audio_zones(&room, &catalog, 5.0);
+
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].speaker_ids.len(), 3);
+    }
+
+    #[test]
+    fn test_floor_speakers_are_ignored() {
+        let mut placed = create_test_placed_speaker("s-1", 10.0, 10.0);
+        placed.mount_type = MountType::Floor;
+        let room = create_test_room(vec![placed]);
+        let catalog = vec![create_test_speaker("speaker-1")];
+
+        assert!(audio_zones(&room, &catalog, 5.0).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+//! Cable Runs
+//!
+//! Works with the estimated cable run lengths of a diagram and checks
+//! them against per-cable-type length limits (e.g. HDMI beyond ~50 ft
+//! needs an extender).
+
+use super::electrical::{
+    generate_electrical_diagram, ElectricalDiagram, EquipmentInput, RoomInput, RoutingRules,
+    SignalConnection,
+};
+use std::collections::HashMap;
+
+/// Maximum reliable run length in feet for common passive cable types
+const MAX_RUN_LENGTHS: [(&str, f64); 6] = [
+    ("HDMI", 50.0),
+    ("DisplayPort", 15.0),
+    ("USB", 16.0),
+    ("SDI", 300.0),
+    ("XLR", 300.0),
+    ("Cat6", 328.0),
+];
+
+// ============================================================================
+// Run Length
+// ============================================================================
+
+/// Returns the connection with the greatest estimated length
+///
+/// Connections without a length estimate are skipped.
+pub fn longest_run(diagram: &ElectricalDiagram) -> Option<SignalConnection> {
+    diagram
+        .connections
+        .iter()
+        .filter_map(|c| c.length_estimate.map(|length| (c, length)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(c, _)| c.clone())
+}
+
+// ============================================================================
+// Length Limits
+// ============================================================================
+
+/// Returns the maximum run length in feet for a cable type, if limited
+pub fn max_run_length(cable_type: &str) -> Option<f64> {
+    MAX_RUN_LENGTHS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(cable_type))
+        .map(|(_, max)| *max)
+}
+
+/// Returns the connections that exceed the maximum length for their cable type
+pub fn overlength_runs(diagram: &ElectricalDiagram) -> Vec<SignalConnection> {
+    diagram
+        .connections
+        .iter()
+        .filter(
+            |c| match (max_run_length(&c.cable_type), c.length_estimate) {
+                (Some(max), Some(length)) => length > max,
+                _ => false,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+// ============================================================================
+// Project Totals
+// ============================================================================
+
+/// Sums estimated cable length per cable type across all rooms in a project
+///
+/// Each room's diagram is generated from its placed equipment, so lengths
+/// include the room's cable slack and any ceiling drops.
+pub fn project_cable_totals(
+    rooms: &[RoomInput],
+    equipment_catalog: &[EquipmentInput],
+) -> Result<HashMap<String, f64>, String> {
+    let mut totals: HashMap<String, f64> = HashMap::new();
+
+    for room in rooms {
+        let diagram =
+            generate_electrical_diagram(room, equipment_catalog, &RoutingRules::default())?;
+        for connection in &diagram.connections {
+            if let Some(length) = connection.length_estimate {
+                *totals.entry(connection.cable_type.clone()).or_insert(0.0) += length;
+            }
+        }
+    }
+
+    Ok(totals)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to find the longest cable run in a diagram
+#[tauri::command]
+pub fn find_longest_cable_run(
+    diagram: ElectricalDiagram,
+) -> Result<Option<SignalConnection>, String> {
+    Ok(longest_run(&diagram))
+}
+
+/// Tauri command to find cable runs longer than their cable type allows
+#[tauri::command]
+pub fn find_overlength_cable_runs(
+    diagram: ElectricalDiagram,
+) -> Result<Vec<SignalConnection>, String> {
+    Ok(overlength_runs(&diagram))
+}
+
+/// Tauri command to total cable length per cable type across a project
+#[tauri::command]
+pub fn compute_project_cable_totals(
+    rooms: Vec<RoomInput>,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<HashMap<String, f64>, String> {
+    project_cable_totals(&rooms, &equipment_catalog)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentCategory, PlacedEquipmentInput, SignalType};
+    use crate::drawings::test_support::{
+        self, create_test_diagram, create_test_equipment, create_test_placed_equipment,
+        create_test_room,
+    };
+
+    fn create_test_connection(
+        from: &str,
+        to: &str,
+        cable_type: &str,
+        length_estimate: Option<f64>,
+    ) -> SignalConnection {
+        SignalConnection {
+            cable_type: cable_type.to_string(),
+            length_estimate,
+            ..test_support::create_test_connection(from, to, SignalType::Video)
+        }
+    }
+
+    #[test]
+    fn test_longest_run_returned() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "display-2", "HDMI", Some(11.5)),
+            create_test_connection("camera-1", "display-1", "HDMI", Some(57.5)),
+            create_test_connection("display-2", "display-1", "HDMI", None),
+        ]);
+
+        let longest = longest_run(&diagram).unwrap();
+
+        assert_eq!(longest.id, "conn-camera-1-display-1");
+        assert_eq!(longest.length_estimate, Some(57.5));
+    }
+
+    #[test]
+    fn test_longest_run_empty_diagram() {
+        assert!(longest_run(&create_test_diagram(Vec::new())).is_none());
+    }
+
+    #[test]
+    fn test_too_long_hdmi_run_flagged() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "display-1", "HDMI", Some(48.0)),
+            create_test_connection("camera-1", "display-3", "HDMI", Some(115.0)),
+            create_test_connection("camera-1", "display-3", "Cat6", Some(115.0)),
+        ]);
+
+        let flagged = overlength_runs(&diagram);
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].to_equipment_id, "display-3");
+        assert_eq!(flagged[0].cable_type, "HDMI");
+    }
+
+    /// Builds a room with a codec at the origin and a display at `(x, y)`
+    fn create_test_codec_room(id: &str, x: f64, y: f64) -> RoomInput {
+        let display = PlacedEquipmentInput {
+            x,
+            y,
+            ..create_test_placed_equipment("p-display", "display-1")
+        };
+        RoomInput {
+            id: id.to_string(),
+            ..create_test_room(vec![
+                create_test_placed_equipment("p-codec", "codec-1"),
+                display,
+            ])
+        }
+    }
+
+    #[test]
+    fn test_project_totals_sum_hdmi_across_rooms() {
+        let catalog = vec![
+            create_test_equipment("codec-1", EquipmentCategory::Video, "codecs"),
+            create_test_equipment("display-1", EquipmentCategory::Video, "displays"),
+        ];
+        let rooms = vec![
+            create_test_codec_room("room-1", 30.0, 40.0),
+            create_test_codec_room("room-2", 6.0, 8.0),
+        ];
+
+        let totals = project_cable_totals(&rooms, &catalog).unwrap();
+
+        // 50 + 10 feet straight-line, with the default 15% slack
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals["HDMI"], 69.0);
+    }
+}
@@ -0,0 +1,89 @@
+//! Equipment Certifications
+//!
+//! Filters the equipment catalog by platform certification, e.g. to list
+//! every Microsoft Teams or Zoom Rooms certified device.
+
+use super::electrical::EquipmentInput;
+
+/// Returns catalog items carrying a certification that matches `cert`
+///
+/// Matching is case-insensitive and accepts partial names, so "Teams"
+/// matches a "Microsoft Teams" certification. An empty query matches nothing.
+pub fn equipment_by_certification(
+    equipment_catalog: &[EquipmentInput],
+    cert: &str,
+) -> Vec<EquipmentInput> {
+    let query = cert.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    equipment_catalog
+        .iter()
+        .filter(|e| {
+            e.certifications
+                .iter()
+                .any(|c| c.to_lowercase().contains(&query))
+        })
+        .cloned()
+        .collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list catalog equipment with a given certification
+#[tauri::command]
+pub fn find_equipment_by_certification(
+    equipment_catalog: Vec<EquipmentInput>,
+    cert: String,
+) -> Result<Vec<EquipmentInput>, String> {
+    Ok(equipment_by_certification(&equipment_catalog, &cert))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::EquipmentCategory;
+    use crate::drawings::test_support;
+
+    fn create_test_equipment(id: &str, certifications: &[&str]) -> EquipmentInput {
+        EquipmentInput {
+            certifications: certifications.iter().map(|c| c.to_string()).collect(),
+            ..test_support::create_test_equipment(id, EquipmentCategory::Video, "codecs")
+        }
+    }
+
+    fn test_catalog() -> Vec<EquipmentInput> {
+        vec![
+            create_test_equipment("codec-teams", &["Microsoft Teams"]),
+            create_test_equipment("codec-zoom", &["Zoom Rooms"]),
+            create_test_equipment("codec-both", &["Zoom Rooms", "MICROSOFT TEAMS"]),
+            create_test_equipment("codec-none", &[]),
+        ]
+    }
+
+    #[test]
+    fn test_teams_query_returns_only_teams_certified() {
+        let matches = equipment_by_certification(&test_catalog(), "Teams");
+
+        let ids: Vec<&str> = matches.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["codec-teams", "codec-both"]);
+    }
+
+    #[test]
+    fn test_query_is_case_insensitive() {
+        let matches = equipment_by_certification(&test_catalog(), "zoom rooms");
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_query_matches_nothing() {
+        assert!(equipment_by_certification(&test_catalog(), "  ").is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+//! Combined Rooms
+//!
+//! Merges several rooms into a single coordinate space so one electrical
+//! diagram can span an open-plan area.
+
+use super::electrical::{PlacedEquipmentInput, Rect, RoomInput};
+
+/// Combines rooms into one room, translating each by its offset
+///
+/// Placed equipment ids are namespaced as `{room_id}:{placed_id}` so that
+/// ids from different rooms cannot collide. Zones are translated with their
+/// room. The combined room spans the furthest translated extent of every
+/// input room.
+pub fn combine_rooms(rooms: &[RoomInput], offsets: &[(f64, f64)]) -> Result<RoomInput, String> {
+    if rooms.is_empty() {
+        return Err("No rooms to combine".to_string());
+    }
+
+    if rooms.len() != offsets.len() {
+        return Err(format!(
+            "Expected {} offsets for {} rooms, got {}",
+            rooms.len(),
+            rooms.len(),
+            offsets.len()
+        ));
+    }
+
+    let mut placed_equipment = Vec::new();
+    let mut zones = Vec::new();
+    let mut width: f64 = 0.0;
+    let mut length: f64 = 0.0;
+    let mut ceiling_height: f64 = 0.0;
+
+    for (room, (dx, dy)) in rooms.iter().zip(offsets) {
+        width = width.max(dx + room.width);
+        length = length.max(dy + room.length);
+        ceiling_height = ceiling_height.max(room.ceiling_height);
+
+        zones.extend(room.zones.iter().map(|zone| Rect {
+            x: zone.x + dx,
+            y: zone.y + dy,
+            ..*zone
+        }));
+
+        placed_equipment.extend(
+            room.placed_equipment
+                .iter()
+                .map(|placed| PlacedEquipmentInput {
+                    id: format!("{}:{}", room.id, placed.id),
+                    x: placed.x + dx,
+                    y: placed.y + dy,
+                    ..placed.clone()
+                }),
+        );
+    }
+
+    Ok(RoomInput {
+        id: rooms
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect::<Vec<_>>()
+            .join("+"),
+        name: rooms
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" + "),
+        width,
+        length,
+        ceiling_height,
+        placed_equipment,
+        zones,
+        cable_slack: rooms.iter().find_map(|r| r.cable_slack),
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to merge multiple rooms into a single combined room
+#[tauri::command]
+pub fn combine_rooms_for_diagram(
+    rooms: Vec<RoomInput>,
+    offsets: Vec<(f64, f64)>,
+) -> Result<RoomInput, String> {
+    combine_rooms(&rooms, &offsets)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{self, create_test_placed_equipment};
+    use std::collections::HashSet;
+
+    fn create_test_room(id: &str, placed_equipment: Vec<PlacedEquipmentInput>) -> RoomInput {
+        RoomInput {
+            id: id.to_string(),
+            name: format!("Room {}", id),
+            ..test_support::create_test_room(placed_equipment)
+        }
+    }
+
+    #[test]
+    fn test_combine_rooms_placement_count_and_unique_ids() {
+        let room_a = create_test_room(
+            "room-a",
+            vec![
+                create_test_placed_equipment("p-1", "camera-1"),
+                create_test_placed_equipment("p-2", "display-1"),
+            ],
+        );
+        let room_b = create_test_room(
+            "room-b",
+            vec![create_test_placed_equipment("p-1", "camera-1")],
+        );
+
+        let combined = combine_rooms(&[room_a, room_b], &[(0.0, 0.0), (20.0, 0.0)]).unwrap();
+
+        assert_eq!(combined.placed_equipment.len(), 3);
+        let ids: HashSet<&str> = combined
+            .placed_equipment
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains("room-a:p-1"));
+        assert!(ids.contains("room-b:p-1"));
+    }
+
+    #[test]
+    fn test_combine_rooms_applies_offsets() {
+        let room_a = create_test_room("room-a", vec![]);
+        let room_b = create_test_room("room-b", vec![create_test_placed_equipment("p-1", "mic-1")]);
+
+        let combined = combine_rooms(&[room_a, room_b], &[(0.0, 0.0), (20.0, 5.0)]).unwrap();
+
+        assert_eq!(combined.placed_equipment[0].x, 20.0);
+        assert_eq!(combined.placed_equipment[0].y, 5.0);
+        assert_eq!(combined.placed_equipment[0].equipment_id, "mic-1");
+        assert_eq!(combined.width, 40.0);
+        assert_eq!(combined.length, 25.0);
+        assert_eq!(combined.id, "room-a+room-b");
+    }
+
+    #[test]
+    fn test_combine_rooms_offset_count_mismatch() {
+        let room = create_test_room("room-a", vec![]);
+        assert!(combine_rooms(&[room], &[]).is_err());
+    }
+
+    #[test]
+    fn test_combine_rooms_empty() {
+        assert!(combine_rooms(&[], &[]).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Conduit Fill
+//!
+//! Computes how much of a conduit's cross-section a bundle of cables
+//! occupies, checked against the NEC 40% fill guideline for three or more
+//! conductors.
+
+use super::electrical::SignalConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Maximum recommended fill ratio for a conduit carrying 3+ cables
+pub const NEC_MAX_FILL: f32 = 0.40;
+
+// ============================================================================
+// Conduit Fill Report
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConduitFillReport {
+    pub fill_ratio: f32,
+    pub max_fill: f32,
+    pub over_limit: bool,
+}
+
+// ============================================================================
+// Fill Calculation
+// ============================================================================
+
+/// Computes the cross-sectional fill ratio of the connections' cables
+///
+/// Each connection contributes one cable whose outside diameter is looked
+/// up by cable type in `cable_diameters`. Diameters share the unit of
+/// `conduit_diameter`. Errors on a non-positive conduit diameter or a cable
+/// type with no known diameter.
+pub fn conduit_fill(
+    connections: &[SignalConnection],
+    conduit_diameter: f64,
+    cable_diameters: &HashMap<String, f64>,
+) -> Result<f32, String> {
+    if conduit_diameter <= 0.0 {
+        return Err("Conduit diameter must be greater than zero".to_string());
+    }
+
+    let area = |diameter: f64| PI * (diameter / 2.0).powi(2);
+
+    let cable_area: f64 = connections
+        .iter()
+        .map(|c| {
+            cable_diameters
+                .get(&c.cable_type)
+                .map(|d| area(*d))
+                .ok_or_else(|| format!("No diameter known for cable type: {}", c.cable_type))
+        })
+        .sum::<Result<f64, String>>()?;
+
+    Ok((cable_area / area(conduit_diameter)) as f32)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to check a conduit's fill against the NEC 40% guideline
+#[tauri::command]
+pub fn compute_conduit_fill(
+    connections: Vec<SignalConnection>,
+    conduit_diameter: f64,
+    cable_diameters: HashMap<String, f64>,
+) -> Result<ConduitFillReport, String> {
+    let fill_ratio = conduit_fill(&connections, conduit_diameter, &cable_diameters)?;
+    Ok(ConduitFillReport {
+        fill_ratio,
+        max_fill: NEC_MAX_FILL,
+        over_limit: fill_ratio > NEC_MAX_FILL,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::SignalType;
+    use crate::drawings::test_support;
+
+    fn create_test_connection(id: usize, cable_type: &str) -> SignalConnection {
+        SignalConnection {
+            id: format!("conn-{}", id),
+            cable_type: cable_type.to_string(),
+            ..test_support::create_test_connection(
+                "rack-1",
+                &format!("device-{}", id),
+                SignalType::Network,
+            )
+        }
+    }
+
+    fn cable_diameters() -> HashMap<String, f64> {
+        HashMap::from([("Cat6".to_string(), 0.25), ("HDMI".to_string(), 0.3)])
+    }
+
+    #[test]
+    fn test_modest_bundle_passes() {
+        let connections: Vec<SignalConnection> =
+            (0..4).map(|i| create_test_connection(i, "Cat6")).collect();
+
+        let report = compute_conduit_fill(connections, 1.0, cable_diameters()).unwrap();
+
+        assert!((report.fill_ratio - 0.25).abs() < 1e-6);
+        assert!(!report.over_limit);
+    }
+
+    #[test]
+    fn test_overfull_conduit_flagged() {
+        let mut connections: Vec<SignalConnection> =
+            (0..6).map(|i| create_test_connection(i, "Cat6")).collect();
+        connections.push(create_test_connection(6, "HDMI"));
+
+        let report = compute_conduit_fill(connections, 1.0, cable_diameters()).unwrap();
+
+        assert!(report.fill_ratio > NEC_MAX_FILL);
+        assert!(report.over_limit);
+    }
+
+    #[test]
+    fn test_unknown_cable_type_errors() {
+        let connections = vec![create_test_connection(0, "SDI")];
+
+        let result = conduit_fill(&connections, 1.0, &cable_diameters());
+        assert!(result.unwrap_err().contains("SDI"));
+    }
+}
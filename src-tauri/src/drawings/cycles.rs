@@ -0,0 +1,140 @@
+//! Signal Cycle Detection
+//!
+//! Finds directed loops in a diagram's connections, such as two devices
+//! each controlling the other, so wiring loops are caught before export.
+
+use super::electrical::ElectricalDiagram;
+use std::collections::{HashMap, HashSet};
+
+// ============================================================================
+// Cycle Detection
+// ============================================================================
+
+/// Depth-first search state shared across the walk
+struct CycleSearch<'a> {
+    edges: HashMap<&'a str, Vec<&'a str>>,
+    finished: HashSet<&'a str>,
+    path: Vec<&'a str>,
+    cycles: Vec<Vec<String>>,
+}
+
+impl<'a> CycleSearch<'a> {
+    /// Walks outward from `node`, recording a cycle for every connection
+    /// that leads back to a device on the current path
+    fn visit(&mut self, node: &'a str) {
+        self.path.push(node);
+
+        let targets = self.edges.get(node).cloned().unwrap_or_default();
+        for next in targets {
+            if let Some(start) = self.path.iter().position(|n| *n == next) {
+                let cycle: Vec<String> = self.path[start..].iter().map(|n| n.to_string()).collect();
+                if !self.cycles.contains(&cycle) {
+                    self.cycles.push(cycle);
+                }
+            } else if !self.finished.contains(next) {
+                self.visit(next);
+            }
+        }
+
+        self.path.pop();
+        self.finished.insert(node);
+    }
+}
+
+/// Returns the directed cycles in a diagram's connection graph
+///
+/// Each cycle lists equipment ids in signal order, starting from the device
+/// where the search first entered it; the last device connects back to the
+/// first. A device connected to itself is a cycle of one. The search runs
+/// in connection order and reports one cycle per connection that closes a
+/// loop, so overlapping loops may share devices. Signal types are not
+/// distinguished, so a control link answered by a video return is a cycle.
+pub fn detect_cycles(diagram: &ElectricalDiagram) -> Vec<Vec<String>> {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut nodes: Vec<&str> = Vec::new();
+
+    for connection in &diagram.connections {
+        let from = connection.from_equipment_id.as_str();
+        let to = connection.to_equipment_id.as_str();
+        edges.entry(from).or_default().push(to);
+        for node in [from, to] {
+            if !nodes.contains(&node) {
+                nodes.push(node);
+            }
+        }
+    }
+
+    let mut search = CycleSearch {
+        edges,
+        finished: HashSet::new(),
+        path: Vec::new(),
+        cycles: Vec::new(),
+    };
+    for node in nodes {
+        if !search.finished.contains(node) {
+            search.visit(node);
+        }
+    }
+
+    search.cycles
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list wiring loops in a diagram
+#[tauri::command]
+pub fn compute_signal_cycles(diagram: ElectricalDiagram) -> Result<Vec<Vec<String>>, String> {
+    Ok(detect_cycles(&diagram))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::SignalType;
+    use crate::drawings::test_support::{create_test_connection, create_test_diagram};
+
+    #[test]
+    fn test_three_node_cycle_detected() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("proc-1", "dsp-1", SignalType::Control),
+            create_test_connection("dsp-1", "switcher-1", SignalType::Control),
+            create_test_connection("switcher-1", "proc-1", SignalType::Control),
+            create_test_connection("switcher-1", "display-1", SignalType::Video),
+        ]);
+
+        let cycles = detect_cycles(&diagram);
+
+        assert_eq!(cycles, vec![vec!["proc-1", "dsp-1", "switcher-1"]]);
+    }
+
+    #[test]
+    fn test_acyclic_fanout_has_no_cycles() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("proc-1", "display-1", SignalType::Control),
+            create_test_connection("proc-1", "display-2", SignalType::Control),
+            create_test_connection("display-1", "display-2", SignalType::Control),
+        ]);
+
+        assert!(detect_cycles(&diagram).is_empty());
+    }
+
+    #[test]
+    fn test_parallel_links_reported_once() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("a", "b", SignalType::Control),
+            create_test_connection("b", "a", SignalType::Control),
+            create_test_connection("b", "a", SignalType::Network),
+            create_test_connection("c", "c", SignalType::Audio),
+        ]);
+
+        let cycles = detect_cycles(&diagram);
+
+        assert_eq!(cycles, vec![vec!["a", "b"], vec!["c"]]);
+    }
+}
@@ -0,0 +1,217 @@
+//! Design Standard Gaps
+//!
+//! Compares a room's equipment against the device set required by a named
+//! design standard and lists what is missing.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, RoomInput};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Design Standard - named room standards
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DesignStandard {
+    TeamsRoom,
+    ZoomRoom,
+    HuddleRoom,
+}
+
+/// A device the standard requires, satisfied by any listed subcategory
+struct Requirement {
+    device: &'static str,
+    category: EquipmentCategory,
+    subcategories: &'static [&'static str],
+    count: u32,
+}
+
+const CAMERA: Requirement = Requirement {
+    device: "Camera",
+    category: EquipmentCategory::Video,
+    subcategories: &["cameras"],
+    count: 1,
+};
+
+const DISPLAY: Requirement = Requirement {
+    device: "Display",
+    category: EquipmentCategory::Video,
+    subcategories: &["displays"],
+    count: 1,
+};
+
+const COMPUTE: Requirement = Requirement {
+    device: "Compute",
+    category: EquipmentCategory::Video,
+    subcategories: &["codecs"],
+    count: 1,
+};
+
+const MICROPHONE: Requirement = Requirement {
+    device: "Microphone",
+    category: EquipmentCategory::Audio,
+    subcategories: &["microphones"],
+    count: 1,
+};
+
+const SPEAKER: Requirement = Requirement {
+    device: "Speaker",
+    category: EquipmentCategory::Audio,
+    subcategories: &["speakers"],
+    count: 1,
+};
+
+const TOUCH_PANEL: Requirement = Requirement {
+    device: "Touch Panel",
+    category: EquipmentCategory::Control,
+    subcategories: &["touch-panels"],
+    count: 1,
+};
+
+impl DesignStandard {
+    /// Devices required by the standard
+    fn requirements(&self) -> &'static [Requirement] {
+        match self {
+            DesignStandard::TeamsRoom | DesignStandard::ZoomRoom => {
+                &[CAMERA, MICROPHONE, SPEAKER, DISPLAY, COMPUTE, TOUCH_PANEL]
+            }
+            DesignStandard::HuddleRoom => &[CAMERA, DISPLAY, COMPUTE],
+        }
+    }
+}
+
+// ============================================================================
+// Gap Item - a requirement the room does not meet
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapItem {
+    pub device: String,
+    pub category: EquipmentCategory,
+    pub subcategories: Vec<String>,
+    pub required: u32,
+    pub present: u32,
+}
+
+// ============================================================================
+// Standards Gap
+// ============================================================================
+
+/// Lists the devices a room is missing to satisfy a design standard
+pub fn standards_gap(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+    standard: DesignStandard,
+) -> Vec<GapItem> {
+    let placed: Vec<&EquipmentInput> = room
+        .placed_equipment
+        .iter()
+        .filter_map(|p| equipment_catalog.iter().find(|e| e.id == p.equipment_id))
+        .collect();
+
+    standard
+        .requirements()
+        .iter()
+        .filter_map(|requirement| {
+            let present = placed
+                .iter()
+                .filter(|e| {
+                    e.category == requirement.category
+                        && requirement.subcategories.contains(&e.subcategory.as_str())
+                })
+                .count() as u32;
+
+            (present < requirement.count).then(|| GapItem {
+                device: requirement.device.to_string(),
+                category: requirement.category,
+                subcategories: requirement
+                    .subcategories
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect(),
+                required: requirement.count,
+                present,
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list devices missing for a design standard
+#[tauri::command]
+pub fn compute_standards_gap(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+    standard: DesignStandard,
+) -> Result<Vec<GapItem>, String> {
+    Ok(standards_gap(&room, &equipment_catalog, standard))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{
+        create_test_equipment, create_test_placed_equipment, create_test_room,
+    };
+
+    fn create_test_catalog() -> Vec<EquipmentInput> {
+        vec![
+            create_test_equipment("camera-1", EquipmentCategory::Video, "cameras"),
+            create_test_equipment("display-1", EquipmentCategory::Video, "displays"),
+            create_test_equipment("codec-1", EquipmentCategory::Video, "codecs"),
+            create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones"),
+            create_test_equipment("speaker-1", EquipmentCategory::Audio, "speakers"),
+            create_test_equipment("panel-1", EquipmentCategory::Control, "touch-panels"),
+        ]
+    }
+
+    #[test]
+    fn test_teams_room_missing_microphone() {
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+            create_test_placed_equipment("p-codec", "codec-1"),
+            create_test_placed_equipment("p-speaker", "speaker-1"),
+            create_test_placed_equipment("p-panel", "panel-1"),
+        ]);
+
+        let gaps = standards_gap(&room, &create_test_catalog(), DesignStandard::TeamsRoom);
+
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].device, "Microphone");
+        assert_eq!(gaps[0].present, 0);
+    }
+
+    #[test]
+    fn test_complete_teams_room_has_no_gaps() {
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+            create_test_placed_equipment("p-codec", "codec-1"),
+            create_test_placed_equipment("p-mic", "mic-1"),
+            create_test_placed_equipment("p-speaker", "speaker-1"),
+            create_test_placed_equipment("p-panel", "panel-1"),
+        ]);
+
+        let gaps = standards_gap(&room, &create_test_catalog(), DesignStandard::TeamsRoom);
+
+        assert!(gaps.is_empty());
+    }
+
+    #[test]
+    fn test_empty_huddle_room_lists_all_requirements() {
+        let room = create_test_room(vec![]);
+
+        let gaps = standards_gap(&room, &create_test_catalog(), DesignStandard::HuddleRoom);
+
+        assert_eq!(gaps.len(), 3);
+    }
+}
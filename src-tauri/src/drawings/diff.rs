@@ -0,0 +1,205 @@
+//! Diagram Diff
+//!
+//! Compares a regenerated diagram with the previous one so the UI can
+//! highlight or animate what changed instead of redrawing from scratch.
+
+use super::electrical::ElectricalDiagram;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Distance an element must shift, in room units, to count as moved
+pub const MOVE_TOLERANCE: f64 = 0.01;
+
+// ============================================================================
+// Diagram Diff - changes between two versions of a diagram
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElementMove {
+    pub id: String,
+    pub from_x: f64,
+    pub from_y: f64,
+    pub to_x: f64,
+    pub to_y: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagramDiff {
+    pub added_elements: Vec<String>,
+    pub removed_elements: Vec<String>,
+    pub moved_elements: Vec<ElementMove>,
+    pub added_connections: Vec<String>,
+    pub removed_connections: Vec<String>,
+}
+
+impl DiagramDiff {
+    /// Whether the two diagrams have the same elements, positions and connections
+    pub fn is_empty(&self) -> bool {
+        self.added_elements.is_empty()
+            && self.removed_elements.is_empty()
+            && self.moved_elements.is_empty()
+            && self.added_connections.is_empty()
+            && self.removed_connections.is_empty()
+    }
+}
+
+// ============================================================================
+// Diagram Comparison
+// ============================================================================
+
+/// Reports the elements and connections that changed between two diagrams
+///
+/// Elements and connections are matched by id. An element present in both
+/// is moved when its position shifted by more than `MOVE_TOLERANCE`; other
+/// changes, such as a new label or cable type, are not reported. Added and
+/// moved items follow the order of `new`, removed items the order of `old`.
+pub fn diff_diagrams(old: &ElectricalDiagram, new: &ElectricalDiagram) -> DiagramDiff {
+    let old_positions: HashMap<&str, (f64, f64)> = old
+        .elements
+        .iter()
+        .map(|e| (e.id.as_str(), (e.x, e.y)))
+        .collect();
+    let new_element_ids: Vec<&str> = new.elements.iter().map(|e| e.id.as_str()).collect();
+
+    let mut diff = DiagramDiff::default();
+    for element in &new.elements {
+        match old_positions.get(element.id.as_str()) {
+            None => diff.added_elements.push(element.id.clone()),
+            Some(&(x, y)) if (element.x - x).hypot(element.y - y) > MOVE_TOLERANCE => {
+                diff.moved_elements.push(ElementMove {
+                    id: element.id.clone(),
+                    from_x: x,
+                    from_y: y,
+                    to_x: element.x,
+                    to_y: element.y,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed_elements = old
+        .elements
+        .iter()
+        .filter(|e| !new_element_ids.contains(&e.id.as_str()))
+        .map(|e| e.id.clone())
+        .collect();
+
+    let has_connection =
+        |diagram: &ElectricalDiagram, id: &str| diagram.connections.iter().any(|c| c.id == id);
+    diff.added_connections = new
+        .connections
+        .iter()
+        .filter(|c| !has_connection(old, &c.id))
+        .map(|c| c.id.clone())
+        .collect();
+    diff.removed_connections = old
+        .connections
+        .iter()
+        .filter(|c| !has_connection(new, &c.id))
+        .map(|c| c.id.clone())
+        .collect();
+
+    diff
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to compare a regenerated diagram with the previous one
+#[tauri::command]
+pub fn compute_diagram_diff(
+    old: ElectricalDiagram,
+    new: ElectricalDiagram,
+) -> Result<DiagramDiff, String> {
+    Ok(diff_diagrams(&old, &new))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{DrawingElement, ElementType, SignalConnection, SignalType};
+    use crate::drawings::test_support;
+
+    fn create_test_element(id: &str, x: f64, y: f64) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type: ElementType::Equipment,
+            x,
+            y,
+            rotation: 0.0,
+            label: id.to_string(),
+            properties: serde_json::json!({}),
+        }
+    }
+
+    fn create_test_connection(from: &str, to: &str) -> SignalConnection {
+        test_support::create_test_connection(from, to, SignalType::Video)
+    }
+
+    fn create_test_diagram() -> ElectricalDiagram {
+        ElectricalDiagram {
+            elements: vec![
+                create_test_element("elem-source", 0.0, 0.0),
+                create_test_element("elem-switcher", 100.0, 0.0),
+                create_test_element("elem-display", 200.0, 0.0),
+            ],
+            ..test_support::create_test_diagram(vec![
+                create_test_connection("source", "switcher"),
+                create_test_connection("switcher", "display"),
+            ])
+        }
+    }
+
+    #[test]
+    fn test_identical_diagrams_have_empty_diff() {
+        let diagram = create_test_diagram();
+        assert!(diff_diagrams(&diagram, &diagram).is_empty());
+    }
+
+    #[test]
+    fn test_element_moved_50_units() {
+        let old = create_test_diagram();
+        let mut new = old.clone();
+        new.elements[1].y = 50.0;
+        new.elements[2].x += MOVE_TOLERANCE / 2.0;
+
+        let diff = diff_diagrams(&old, &new);
+
+        assert_eq!(
+            diff.moved_elements,
+            vec![ElementMove {
+                id: "elem-switcher".to_string(),
+                from_x: 100.0,
+                from_y: 0.0,
+                to_x: 100.0,
+                to_y: 50.0,
+            }]
+        );
+        assert!(diff.added_elements.is_empty() && diff.removed_elements.is_empty());
+    }
+
+    #[test]
+    fn test_connection_removed_and_element_replaced() {
+        let old = create_test_diagram();
+        let mut new = old.clone();
+        new.connections.remove(1);
+        new.elements[2] = create_test_element("elem-projector", 200.0, 0.0);
+        new.connections
+            .push(create_test_connection("switcher", "projector"));
+
+        let diff = diff_diagrams(&old, &new);
+
+        assert_eq!(diff.removed_connections, vec!["conn-switcher-display"]);
+        assert_eq!(diff.added_connections, vec!["conn-switcher-projector"]);
+        assert_eq!(diff.removed_elements, vec!["elem-display"]);
+        assert_eq!(diff.added_elements, vec!["elem-projector"]);
+        assert!(diff.moved_elements.is_empty());
+    }
+}
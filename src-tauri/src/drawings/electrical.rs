@@ -3,15 +3,21 @@
 //! Generates electrical line diagrams from room equipment data.
 //! Analyzes signal flow between equipment and creates diagram elements.
 
+use super::layout;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Default multiplier on straight-line runs to allow for routing and service loops
+pub const DEFAULT_CABLE_SLACK: f64 = 1.15;
 
 // ============================================================================
 // Equipment Category - mirrors TypeScript definitions
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EquipmentCategory {
+    #[default]
     Video,
     Audio,
     Control,
@@ -22,9 +28,10 @@ pub enum EquipmentCategory {
 // Mount Type - mirrors TypeScript definitions
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MountType {
+    #[default]
     Floor,
     Wall,
     Ceiling,
@@ -49,7 +56,7 @@ pub enum ElementType {
 // Signal Type - for signal flow analysis
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SignalType {
     Video,
@@ -63,7 +70,7 @@ pub enum SignalType {
 // Equipment Input Data - from frontend
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EquipmentInput {
     pub id: String,
@@ -71,13 +78,46 @@ pub struct EquipmentInput {
     pub model: String,
     pub category: EquipmentCategory,
     pub subcategory: String,
+    /// Weight in pounds, if known
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// Dealer cost, if known
+    #[serde(default)]
+    pub cost: Option<f64>,
+    /// Platform certifications, e.g. "Microsoft Teams" or "Zoom Rooms"
+    #[serde(default)]
+    pub certifications: Vec<String>,
+    /// Physical and electrical specs used on datasheets
+    #[serde(default)]
+    pub specs: EquipmentSpecs,
+}
+
+// ============================================================================
+// Equipment Specs - optional datasheet values
+// ============================================================================
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EquipmentSpecs {
+    /// Height in inches
+    pub height: Option<f64>,
+    /// Width in inches
+    pub width: Option<f64>,
+    /// Depth in inches
+    pub depth: Option<f64>,
+    /// Power draw in watts
+    pub wattage: Option<f64>,
+    /// Supply voltage, e.g. "100-240V AC"
+    pub voltage: Option<String>,
+    /// Product image URL or local path
+    pub image_url: Option<String>,
 }
 
 // ============================================================================
 // Placed Equipment Input - from frontend
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlacedEquipmentInput {
     pub id: String,
@@ -86,13 +126,20 @@ pub struct PlacedEquipmentInput {
     pub y: f64,
     pub rotation: f64,
     pub mount_type: MountType,
+    /// Height of the device's operable parts above finished floor, in inches
+    #[serde(default)]
+    pub mount_height: Option<f64>,
+    /// Amplifier zone (output channel group) a speaker is wired to
+    #[serde(default)]
+    pub amp_zone: Option<String>,
 }
 
 // ============================================================================
 // Room Input - from frontend
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A room to draw; dimensions and placement coordinates are in feet
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RoomInput {
     pub id: String,
@@ -101,6 +148,82 @@ pub struct RoomInput {
     pub length: f64,
     pub ceiling_height: f64,
     pub placed_equipment: Vec<PlacedEquipmentInput>,
+    /// Functional zones (audience, presenter, etc.) in room coordinates
+    #[serde(default)]
+    pub zones: Vec<Rect>,
+    /// Multiplier applied to straight-line cable runs, `DEFAULT_CABLE_SLACK` when unset
+    #[serde(default)]
+    pub cable_slack: Option<f64>,
+}
+
+// ============================================================================
+// Routing Rules - cable choices for generated connections
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RoutingRules {
+    /// Video cable types in order of preference; the first video source
+    /// gets the first entry, the next source the second, and so on, with
+    /// the last entry reused for any further sources
+    pub video_cables: Vec<String>,
+    pub audio_cable: String,
+    /// Cable for audio routed through a network switch (Dante/AVB)
+    pub network_audio_cable: String,
+    pub control_cable: String,
+}
+
+impl Default for RoutingRules {
+    fn default() -> Self {
+        Self {
+            video_cables: vec![
+                "HDMI".to_string(),
+                "DisplayPort".to_string(),
+                "SDI".to_string(),
+            ],
+            audio_cable: "XLR".to_string(),
+            network_audio_cable: "Cat6A".to_string(),
+            control_cable: "Cat6".to_string(),
+        }
+    }
+}
+
+impl RoutingRules {
+    /// Returns the video cable type for the source at `index`
+    ///
+    /// Falls back to HDMI when no video cables are configured.
+    pub fn video_cable(&self, index: usize) -> String {
+        self.video_cables
+            .get(index)
+            .or(self.video_cables.last())
+            .cloned()
+            .unwrap_or_else(|| "HDMI".to_string())
+    }
+}
+
+// ============================================================================
+// Rect - axis-aligned area in room coordinates
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Returns true if the point lies inside or on the edge of the rectangle
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    /// Returns true if the point lies strictly inside the rectangle
+    pub fn contains_interior(&self, x: f64, y: f64) -> bool {
+        x > self.x && x < self.x + self.width && y > self.y && y < self.y + self.height
+    }
 }
 
 // ============================================================================
@@ -131,19 +254,58 @@ pub struct SignalConnection {
     pub to_equipment_id: String,
     pub signal_type: SignalType,
     pub cable_type: String,
+    /// Installer cable label, filled in by `label_connections`
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Estimated cable length in feet, filled in by `analyze_signal_flow`
+    #[serde(default)]
+    pub length_estimate: Option<f64>,
 }
 
 // ============================================================================
 // Electrical Diagram - full diagram output
 // ============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ElectricalDiagram {
     pub room_id: String,
     pub elements: Vec<DrawingElement>,
     pub connections: Vec<SignalConnection>,
     pub generated_at: String,
+    /// Placed equipment ids with no connections, see `find_unconnected_equipment`
+    #[serde(default)]
+    pub unconnected: Vec<String>,
+    /// Placement ids positioned outside the room, see `validate_placements`
+    #[serde(default)]
+    pub placement_warnings: Vec<String>,
+    /// Element ids snapped onto another element, see `snap_to_grid`
+    #[serde(default)]
+    pub grid_collisions: Vec<String>,
+}
+
+// ============================================================================
+// Cable Schedule - numbered cable table for installers
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CableScheduleRow {
+    /// Sequential cable number, e.g. `C-001`
+    pub cable_id: String,
+    pub from_equipment_id: String,
+    pub to_equipment_id: String,
+    pub signal_type: SignalType,
+    pub cable_type: String,
+    /// Estimated run length in feet including slack and ceiling drops, when known
+    pub estimated_length: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CableSchedule {
+    pub room_id: String,
+    pub rows: Vec<CableScheduleRow>,
 }
 
 // ============================================================================
@@ -154,6 +316,7 @@ pub struct ElectricalDiagram {
 pub fn generate_electrical_diagram(
     room: &RoomInput,
     equipment_catalog: &[EquipmentInput],
+    rules: &RoutingRules,
 ) -> Result<ElectricalDiagram, String> {
     if room.placed_equipment.is_empty() {
         return Ok(ElectricalDiagram {
@@ -161,6 +324,9 @@ pub fn generate_electrical_diagram(
             elements: Vec::new(),
             connections: Vec::new(),
             generated_at: chrono::Utc::now().to_rfc3339(),
+            unconnected: Vec::new(),
+            placement_warnings: Vec::new(),
+            grid_collisions: Vec::new(),
         });
     }
 
@@ -182,39 +348,54 @@ pub fn generate_electrical_diagram(
             element_type: ElementType::Equipment,
             x: placed.x,
             y: placed.y,
-            rotation: placed.rotation,
+            rotation: normalize_rotation(placed.rotation),
             label,
             properties: serde_json::json!({
                 "equipment_id": placed.equipment_id,
                 "mount_type": placed.mount_type,
+                "category": equipment.map(|eq| eq.category),
             }),
         });
     }
 
     // Analyze signal flow to create connections
-    let connections = analyze_signal_flow(room, equipment_catalog);
+    let connections = analyze_signal_flow(room, equipment_catalog, rules);
 
-    Ok(ElectricalDiagram {
+    let mut diagram = ElectricalDiagram {
         room_id: room.id.clone(),
         elements,
         connections,
         generated_at: chrono::Utc::now().to_rfc3339(),
-    })
+        unconnected: Vec::new(),
+        placement_warnings: Vec::new(),
+        grid_collisions: Vec::new(),
+    };
+    diagram.unconnected = find_unconnected_equipment(&diagram, room);
+    diagram.placement_warnings = validate_placements(room);
+
+    Ok(diagram)
 }
 
 /// Analyzes signal flow between equipment to determine connections
+///
+/// Cable types for each signal are chosen by `rules`. When the room has a
+/// network switch, audio is routed through it as networked audio instead
+/// of point-to-point links.
 pub fn analyze_signal_flow(
     room: &RoomInput,
     equipment_catalog: &[EquipmentInput],
+    rules: &RoutingRules,
 ) -> Vec<SignalConnection> {
     let mut connections = Vec::new();
 
     // Find equipment by category for signal routing
     let mut video_sources: Vec<&PlacedEquipmentInput> = Vec::new();
     let mut video_displays: Vec<&PlacedEquipmentInput> = Vec::new();
+    let mut video_switchers: Vec<&PlacedEquipmentInput> = Vec::new();
     let mut audio_sources: Vec<&PlacedEquipmentInput> = Vec::new();
     let mut audio_outputs: Vec<&PlacedEquipmentInput> = Vec::new();
     let mut control_devices: Vec<&PlacedEquipmentInput> = Vec::new();
+    let mut network_switches: Vec<&PlacedEquipmentInput> = Vec::new();
 
     for placed in &room.placed_equipment {
         if let Some(equipment) = equipment_catalog
@@ -225,6 +406,7 @@ pub fn analyze_signal_flow(
                 EquipmentCategory::Video => match equipment.subcategory.as_str() {
                     "cameras" | "codecs" => video_sources.push(placed),
                     "displays" => video_displays.push(placed),
+                    "switchers" => video_switchers.push(placed),
                     _ => {}
                 },
                 EquipmentCategory::Audio => match equipment.subcategory.as_str() {
@@ -235,64 +417,241 @@ pub fn analyze_signal_flow(
                 EquipmentCategory::Control => {
                     control_devices.push(placed);
                 }
-                EquipmentCategory::Infrastructure => {
-                    // Infrastructure doesn't typically create signal connections
-                }
+                EquipmentCategory::Infrastructure => match equipment.subcategory.as_str() {
+                    "switch" | "network" => network_switches.push(placed),
+                    // Other infrastructure doesn't create signal connections
+                    _ => {}
+                },
             }
         }
     }
 
-    // Create video signal connections: sources -> displays
+    // Create video signal connections. Matrix switchers sit between sources
+    // and displays, so route sources -> switchers -> displays when present,
+    // otherwise sources -> displays directly.
+    let source_targets = if video_switchers.is_empty() {
+        &video_displays
+    } else {
+        &video_switchers
+    };
+
     for (idx, source) in video_sources.iter().enumerate() {
-        for display in &video_displays {
+        for target in source_targets {
             connections.push(SignalConnection {
-                id: format!("conn-video-{}-{}", source.id, display.id),
+                id: format!("conn-video-{}-{}", source.id, target.id),
                 from_equipment_id: source.equipment_id.clone(),
+                to_equipment_id: target.equipment_id.clone(),
+                signal_type: SignalType::Video,
+                cable_type: rules.video_cable(idx),
+                label: None,
+                length_estimate: Some(estimate_cable_length(room, source, target)),
+            });
+        }
+    }
+
+    for switcher in &video_switchers {
+        for display in &video_displays {
+            connections.push(SignalConnection {
+                id: format!("conn-video-{}-{}", switcher.id, display.id),
+                from_equipment_id: switcher.equipment_id.clone(),
                 to_equipment_id: display.equipment_id.clone(),
                 signal_type: SignalType::Video,
-                cable_type: determine_video_cable_type(idx),
+                cable_type: rules.video_cable(0),
+                label: None,
+                length_estimate: Some(estimate_cable_length(room, switcher, display)),
             });
         }
     }
 
-    // Create audio signal connections: sources -> outputs
-    for source in &audio_sources {
-        for output in &audio_outputs {
+    // Create audio signal connections. Networked audio (Dante/AVB) runs
+    // sources -> switch -> outputs when a network switch is present,
+    // otherwise sources connect to outputs directly.
+    if let Some(switch) = network_switches.first() {
+        for source in &audio_sources {
             connections.push(SignalConnection {
-                id: format!("conn-audio-{}-{}", source.id, output.id),
+                id: format!("conn-netaudio-{}-{}", source.id, switch.id),
                 from_equipment_id: source.equipment_id.clone(),
+                to_equipment_id: switch.equipment_id.clone(),
+                signal_type: SignalType::Network,
+                cable_type: rules.network_audio_cable.clone(),
+                label: None,
+                length_estimate: Some(estimate_cable_length(room, source, switch)),
+            });
+        }
+        for output in &audio_outputs {
+            connections.push(SignalConnection {
+                id: format!("conn-netaudio-{}-{}", switch.id, output.id),
+                from_equipment_id: switch.equipment_id.clone(),
                 to_equipment_id: output.equipment_id.clone(),
-                signal_type: SignalType::Audio,
-                cable_type: "XLR".to_string(),
+                signal_type: SignalType::Network,
+                cable_type: rules.network_audio_cable.clone(),
+                label: None,
+                length_estimate: Some(estimate_cable_length(room, switch, output)),
             });
         }
-    }
-
-    // Create control connections from control devices to all other equipment
-    for control in &control_devices {
-        for placed in &room.placed_equipment {
-            if placed.id != control.id {
+    } else {
+        for source in &audio_sources {
+            for output in &audio_outputs {
                 connections.push(SignalConnection {
-                    id: format!("conn-ctrl-{}-{}", control.id, placed.id),
-                    from_equipment_id: control.equipment_id.clone(),
-                    to_equipment_id: placed.equipment_id.clone(),
-                    signal_type: SignalType::Control,
-                    cable_type: "Cat6".to_string(),
+                    id: format!("conn-audio-{}-{}", source.id, output.id),
+                    from_equipment_id: source.equipment_id.clone(),
+                    to_equipment_id: output.equipment_id.clone(),
+                    signal_type: SignalType::Audio,
+                    cable_type: rules.audio_cable.clone(),
+                    label: None,
+                    length_estimate: Some(estimate_cable_length(room, source, output)),
                 });
             }
         }
     }
 
+    // Create control connections. Each placement is controlled by its nearest
+    // control device only, and a given pair of devices is linked once.
+    let mut control_links: HashSet<(&str, &str)> = HashSet::new();
+    for placed in &room.placed_equipment {
+        let Some(control) = control_devices
+            .iter()
+            .filter(|c| c.id != placed.id)
+            .min_by(|a, b| placement_distance(a, placed).total_cmp(&placement_distance(b, placed)))
+        else {
+            continue;
+        };
+
+        let from = control.equipment_id.as_str();
+        let to = placed.equipment_id.as_str();
+        // Two control devices would otherwise link to each other both ways
+        if control_links.contains(&(to, from)) || !control_links.insert((from, to)) {
+            continue;
+        }
+
+        connections.push(SignalConnection {
+            id: format!("conn-ctrl-{}-{}", control.id, placed.id),
+            from_equipment_id: control.equipment_id.clone(),
+            to_equipment_id: placed.equipment_id.clone(),
+            signal_type: SignalType::Control,
+            cable_type: rules.control_cable.clone(),
+            label: None,
+            length_estimate: Some(estimate_cable_length(room, control, placed)),
+        });
+    }
+
     connections
 }
 
-/// Determines video cable type based on connection index
-fn determine_video_cable_type(index: usize) -> String {
-    // First source typically uses HDMI, subsequent sources may use other types
-    match index {
-        0 => "HDMI".to_string(),
-        1 => "DisplayPort".to_string(),
-        _ => "SDI".to_string(),
+/// Straight-line distance between two placements in feet
+fn placement_distance(a: &PlacedEquipmentInput, b: &PlacedEquipmentInput) -> f64 {
+    ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+}
+
+/// Height of a placement above the floor in feet
+///
+/// Ceiling-mounted gear sits at the room's ceiling height; anything else
+/// sits at its `mount_height` (given in inches), or on the floor when unset.
+fn mount_elevation(room: &RoomInput, placed: &PlacedEquipmentInput) -> f64 {
+    match placed.mount_type {
+        MountType::Ceiling => room.ceiling_height,
+        _ => placed.mount_height.map_or(0.0, |inches| inches / 12.0),
+    }
+}
+
+/// Estimates the cable length in feet between two placements
+///
+/// The straight-line distance is multiplied by the room's slack factor,
+/// plus the vertical drop between the two ends' mount heights, so a
+/// ceiling speaker wired to a ceiling microphone adds no drop while one
+/// wired to floor gear drops the full ceiling height.
+/// The result is rounded to one decimal place.
+fn estimate_cable_length(
+    room: &RoomInput,
+    from: &PlacedEquipmentInput,
+    to: &PlacedEquipmentInput,
+) -> f64 {
+    let distance = placement_distance(from, to);
+    let slack = room.cable_slack.unwrap_or(DEFAULT_CABLE_SLACK);
+    let drop = (mount_elevation(room, from) - mount_elevation(room, to)).abs();
+
+    ((distance * slack + drop) * 10.0).round() / 10.0
+}
+
+/// Returns the ids of placed equipment that appear in no connection
+///
+/// Connections reference catalog equipment ids, so a placement counts as
+/// connected when its equipment id is an endpoint of any connection.
+/// Infrastructure is excluded since it legitimately has no signal links;
+/// it is recognized by the `category` property of the placement's element.
+pub fn find_unconnected_equipment(diagram: &ElectricalDiagram, room: &RoomInput) -> Vec<String> {
+    room.placed_equipment
+        .iter()
+        .filter(|placed| {
+            let element_id = format!("elem-{}", placed.id);
+            !diagram
+                .elements
+                .iter()
+                .any(|e| e.id == element_id && e.properties["category"] == "infrastructure")
+        })
+        .filter(|placed| {
+            !diagram.connections.iter().any(|c| {
+                c.from_equipment_id == placed.equipment_id
+                    || c.to_equipment_id == placed.equipment_id
+            })
+        })
+        .map(|placed| placed.id.clone())
+        .collect()
+}
+
+/// Returns the ids of placements outside the room rectangle
+///
+/// The room spans `0..=width` on x and `0..=length` on y, so equipment on a
+/// wall is inside. Placements with non-finite coordinates are reported too.
+pub fn validate_placements(room: &RoomInput) -> Vec<String> {
+    room.placed_equipment
+        .iter()
+        .filter(|placed| {
+            !(0.0..=room.width).contains(&placed.x) || !(0.0..=room.length).contains(&placed.y)
+        })
+        .map(|placed| placed.id.clone())
+        .collect()
+}
+
+/// Rolls a diagram's connections into a numbered cable schedule
+///
+/// Rows are sorted by signal type, then source equipment id, with ties
+/// kept in connection order, and numbered `C-001`, `C-002`, … in that order.
+pub fn cable_schedule(diagram: &ElectricalDiagram) -> CableSchedule {
+    let mut connections: Vec<&SignalConnection> = diagram.connections.iter().collect();
+    connections.sort_by(|a, b| {
+        a.signal_type
+            .cmp(&b.signal_type)
+            .then_with(|| a.from_equipment_id.cmp(&b.from_equipment_id))
+    });
+
+    let rows = connections
+        .into_iter()
+        .enumerate()
+        .map(|(idx, connection)| CableScheduleRow {
+            cable_id: format!("C-{:03}", idx + 1),
+            from_equipment_id: connection.from_equipment_id.clone(),
+            to_equipment_id: connection.to_equipment_id.clone(),
+            signal_type: connection.signal_type,
+            cable_type: connection.cable_type.clone(),
+            estimated_length: connection.length_estimate,
+        })
+        .collect();
+
+    CableSchedule {
+        room_id: diagram.room_id.clone(),
+        rows,
+    }
+}
+
+/// Normalizes a rotation in degrees to the range [0, 360)
+pub fn normalize_rotation(rotation: f64) -> f64 {
+    let normalized = rotation.rem_euclid(360.0);
+    // Tiny negative angles can round up to exactly 360
+    if normalized >= 360.0 {
+        0.0
+    } else {
+        normalized
     }
 }
 
@@ -301,12 +660,35 @@ fn determine_video_cable_type(index: usize) -> String {
 // ============================================================================
 
 /// Tauri command to generate electrical diagram
+///
+/// Routing rules default to the standard cable choices when not provided.
+/// Elements keep their placed coordinates unless `auto_layout` is set, in
+/// which case crowded elements are spread apart. When `grid_size` is set,
+/// element positions are then rounded to that grid, and elements that land
+/// on the same grid point are listed in `grid_collisions`.
 #[tauri::command]
 pub fn generate_electrical(
     room: RoomInput,
     equipment_catalog: Vec<EquipmentInput>,
+    rules: Option<RoutingRules>,
+    auto_layout: Option<bool>,
+    grid_size: Option<f64>,
 ) -> Result<ElectricalDiagram, String> {
-    generate_electrical_diagram(&room, &equipment_catalog)
+    let mut diagram =
+        generate_electrical_diagram(&room, &equipment_catalog, &rules.unwrap_or_default())?;
+    if auto_layout.unwrap_or(false) {
+        layout::auto_layout(&mut diagram.elements, layout::DEFAULT_MIN_SPACING);
+    }
+    if let Some(grid_size) = grid_size {
+        diagram.grid_collisions = layout::snap_to_grid(&mut diagram.elements, grid_size);
+    }
+    Ok(diagram)
+}
+
+/// Tauri command to generate a cable schedule from a diagram
+#[tauri::command]
+pub fn generate_cable_schedule(diagram: ElectricalDiagram) -> Result<CableSchedule, String> {
+    Ok(cable_schedule(&diagram))
 }
 
 // ============================================================================
@@ -316,42 +698,10 @@ pub fn generate_electrical(
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_test_equipment(
-        id: &str,
-        category: EquipmentCategory,
-        subcategory: &str,
-    ) -> EquipmentInput {
-        EquipmentInput {
-            id: id.to_string(),
-            manufacturer: "Test Manufacturer".to_string(),
-            model: format!("Model {}", id),
-            category,
-            subcategory: subcategory.to_string(),
-        }
-    }
-
-    fn create_test_placed_equipment(id: &str, equipment_id: &str) -> PlacedEquipmentInput {
-        PlacedEquipmentInput {
-            id: id.to_string(),
-            equipment_id: equipment_id.to_string(),
-            x: 100.0,
-            y: 100.0,
-            rotation: 0.0,
-            mount_type: MountType::Floor,
-        }
-    }
-
-    fn create_test_room(placed_equipment: Vec<PlacedEquipmentInput>) -> RoomInput {
-        RoomInput {
-            id: "room-1".to_string(),
-            name: "Test Room".to_string(),
-            width: 20.0,
-            length: 20.0,
-            ceiling_height: 10.0,
-            placed_equipment,
-        }
-    }
+    use crate::drawings::test_support::{
+        create_test_connection, create_test_diagram, create_test_equipment,
+        create_test_placed_equipment, create_test_room,
+    };
 
     // ========================================================================
     // Type Serialization Tests
@@ -400,7 +750,7 @@ mod tests {
         let room = create_test_room(vec![]);
         let catalog: Vec<EquipmentInput> = vec![];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -420,7 +770,7 @@ mod tests {
         let room = create_test_room(vec![placed]);
         let catalog = vec![camera];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -435,7 +785,7 @@ mod tests {
         let room = create_test_room(vec![placed]);
         let catalog: Vec<EquipmentInput> = vec![];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -458,7 +808,7 @@ mod tests {
         let room = create_test_room(vec![placed_camera, placed_display]);
         let catalog = vec![camera, display];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -487,7 +837,7 @@ mod tests {
         let room = create_test_room(vec![placed_mic, placed_speaker]);
         let catalog = vec![mic, speaker];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -516,7 +866,7 @@ mod tests {
         let room = create_test_room(vec![placed_proc, placed_display]);
         let catalog = vec![processor, display];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -533,6 +883,68 @@ mod tests {
         assert_eq!(control_connections[0].cable_type, "Cat6");
     }
 
+    #[test]
+    fn test_control_targets_use_nearest_processor() {
+        let proc_a = create_test_equipment("proc-a", EquipmentCategory::Control, "processors");
+        let proc_b = create_test_equipment("proc-b", EquipmentCategory::Control, "processors");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+        let dsp = create_test_equipment("dsp-1", EquipmentCategory::Audio, "processors");
+        let rack = create_test_equipment("rack-1", EquipmentCategory::Infrastructure, "racks");
+
+        let place = |id: &str, equipment_id: &str, x: f64| PlacedEquipmentInput {
+            x,
+            y: 0.0,
+            ..create_test_placed_equipment(id, equipment_id)
+        };
+        let room = create_test_room(vec![
+            place("p-proc-a", "proc-a", 0.0),
+            place("p-proc-b", "proc-b", 100.0),
+            place("p-display", "display-1", 10.0),
+            place("p-dsp", "dsp-1", 90.0),
+            place("p-rack", "rack-1", 30.0),
+        ]);
+        let catalog = vec![proc_a, proc_b, display, dsp, rack];
+
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
+
+        let mut links: Vec<(&str, &str)> = connections
+            .iter()
+            .filter(|c| c.signal_type == SignalType::Control)
+            .map(|c| (c.from_equipment_id.as_str(), c.to_equipment_id.as_str()))
+            .collect();
+        links.sort();
+        // One link per target plus one between the processors, instead of
+        // both processors fanning out to every other device (8 links)
+        assert_eq!(
+            links,
+            vec![
+                ("proc-a", "display-1"),
+                ("proc-a", "rack-1"),
+                ("proc-b", "dsp-1"),
+                ("proc-b", "proc-a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_control_links_deduplicated() {
+        let processor = create_test_equipment("proc-1", EquipmentCategory::Control, "processors");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-proc", "proc-1"),
+            create_test_placed_equipment("p-display-left", "display-1"),
+            create_test_placed_equipment("p-display-right", "display-1"),
+        ]);
+
+        let connections =
+            analyze_signal_flow(&room, &[processor, display], &RoutingRules::default());
+
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].from_equipment_id, "proc-1");
+        assert_eq!(connections[0].to_equipment_id, "display-1");
+    }
+
     #[test]
     fn test_multiple_video_sources_different_cables() {
         let camera1 = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
@@ -546,7 +958,7 @@ mod tests {
         let room = create_test_room(vec![placed_camera1, placed_camera2, placed_display]);
         let catalog = vec![camera1, camera2, display];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -587,7 +999,7 @@ mod tests {
 
         let catalog = vec![camera, display, mic, speaker, processor];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -616,7 +1028,7 @@ mod tests {
         assert_eq!(video_count, 1);
         // Audio: mic -> speaker (1)
         assert_eq!(audio_count, 1);
-        // Control: processor -> all other equipment (4)
+        // Control: processor -> each other device (4)
         assert_eq!(control_count, 4);
     }
 
@@ -636,7 +1048,7 @@ mod tests {
         let room = create_test_room(vec![placed]);
         let catalog = vec![camera];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -645,6 +1057,32 @@ mod tests {
         assert_eq!(diagram.elements[0].rotation, 45.0);
     }
 
+    #[test]
+    fn test_element_rotation_normalized() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+
+        let mut negative = create_test_placed_equipment("placed-1", "camera-1");
+        negative.rotation = -90.0;
+        let mut oversized = create_test_placed_equipment("placed-2", "camera-1");
+        oversized.rotation = 450.0;
+
+        let room = create_test_room(vec![negative, oversized]);
+        let catalog = vec![camera];
+
+        let diagram =
+            generate_electrical_diagram(&room, &catalog, &RoutingRules::default()).unwrap();
+        assert_eq!(diagram.elements[0].rotation, 270.0);
+        assert_eq!(diagram.elements[1].rotation, 90.0);
+    }
+
+    #[test]
+    fn test_normalize_rotation_range() {
+        assert_eq!(normalize_rotation(0.0), 0.0);
+        assert_eq!(normalize_rotation(360.0), 0.0);
+        assert_eq!(normalize_rotation(-720.0), 0.0);
+        assert_eq!(normalize_rotation(359.5), 359.5);
+    }
+
     #[test]
     fn test_element_properties_include_metadata() {
         let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
@@ -655,7 +1093,7 @@ mod tests {
         let room = create_test_room(vec![placed]);
         let catalog = vec![camera];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -681,7 +1119,7 @@ mod tests {
 
         let catalog = vec![rack, camera];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -690,6 +1128,129 @@ mod tests {
         assert!(diagram.connections.is_empty());
     }
 
+    // ========================================================================
+    // Unconnected Equipment Tests
+    // ========================================================================
+
+    #[test]
+    fn test_unconnected_equipment_reported() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+        let mic = create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones");
+        let rack = create_test_equipment("rack-1", EquipmentCategory::Infrastructure, "racks");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+            create_test_placed_equipment("p-mic", "mic-1"),
+            create_test_placed_equipment("p-rack", "rack-1"),
+        ]);
+
+        let catalog = vec![camera, display, mic, rack];
+
+        let diagram =
+            generate_electrical_diagram(&room, &catalog, &RoutingRules::default()).unwrap();
+
+        assert_eq!(diagram.unconnected, vec!["p-mic"]);
+        assert_eq!(find_unconnected_equipment(&diagram, &room), vec!["p-mic"]);
+    }
+
+    #[test]
+    fn test_fully_connected_room_has_no_orphans() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+        ]);
+
+        let diagram =
+            generate_electrical_diagram(&room, &[camera, display], &RoutingRules::default())
+                .unwrap();
+
+        assert!(diagram.unconnected.is_empty());
+    }
+
+    // ========================================================================
+    // Placement Bounds Tests
+    // ========================================================================
+
+    #[test]
+    fn test_placement_beyond_room_width_reported() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+
+        let mut inside = create_test_placed_equipment("p-camera", "camera-1");
+        (inside.x, inside.y) = (0.0, 20.0);
+        let mut outside = create_test_placed_equipment("p-display", "display-1");
+        (outside.x, outside.y) = (25.0, 10.0);
+        let room = create_test_room(vec![inside, outside]);
+
+        let diagram =
+            generate_electrical_diagram(&room, &[camera, display], &RoutingRules::default())
+                .unwrap();
+
+        assert_eq!(diagram.placement_warnings, vec!["p-display"]);
+        assert_eq!(validate_placements(&room), vec!["p-display"]);
+    }
+
+    // ========================================================================
+    // Network Audio Tests
+    // ========================================================================
+
+    #[test]
+    fn test_audio_routed_through_network_switch() {
+        let mic = create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones");
+        let speaker = create_test_equipment("speaker-1", EquipmentCategory::Audio, "speakers");
+        let switch = create_test_equipment("switch-1", EquipmentCategory::Infrastructure, "switch");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-mic", "mic-1"),
+            create_test_placed_equipment("p-speaker", "speaker-1"),
+            create_test_placed_equipment("p-switch", "switch-1"),
+        ]);
+
+        let catalog = vec![mic, speaker, switch];
+
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
+
+        let links: Vec<(&str, &str)> = connections
+            .iter()
+            .map(|c| (c.from_equipment_id.as_str(), c.to_equipment_id.as_str()))
+            .collect();
+        assert_eq!(
+            links,
+            vec![("mic-1", "switch-1"), ("switch-1", "speaker-1")]
+        );
+        assert!(connections
+            .iter()
+            .all(|c| c.signal_type == SignalType::Network && c.cable_type == "Cat6A"));
+    }
+
+    #[test]
+    fn test_audio_without_switch_uses_xlr() {
+        let mic = create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones");
+        let speaker = create_test_equipment("speaker-1", EquipmentCategory::Audio, "speakers");
+        let rack = create_test_equipment("rack-1", EquipmentCategory::Infrastructure, "racks");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-mic", "mic-1"),
+            create_test_placed_equipment("p-speaker", "speaker-1"),
+            create_test_placed_equipment("p-rack", "rack-1"),
+        ]);
+
+        let catalog = vec![mic, speaker, rack];
+
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
+
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].from_equipment_id, "mic-1");
+        assert_eq!(connections[0].to_equipment_id, "speaker-1");
+        assert_eq!(connections[0].signal_type, SignalType::Audio);
+        assert_eq!(connections[0].cable_type, "XLR");
+    }
+
     // ========================================================================
     // Codec Tests (Video Source)
     // ========================================================================
@@ -706,7 +1267,7 @@ mod tests {
 
         let catalog = vec![codec, display];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -721,6 +1282,53 @@ mod tests {
         assert_eq!(video_connections[0].from_equipment_id, "codec-1");
     }
 
+    // ========================================================================
+    // Matrix Switcher Tests
+    // ========================================================================
+
+    #[test]
+    fn test_switcher_routes_sources_to_displays() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let codec = create_test_equipment("codec-1", EquipmentCategory::Video, "codecs");
+        let switcher = create_test_equipment("switcher-1", EquipmentCategory::Video, "switchers");
+        let display1 = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+        let display2 = create_test_equipment("display-2", EquipmentCategory::Video, "displays");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-codec", "codec-1"),
+            create_test_placed_equipment("p-switcher", "switcher-1"),
+            create_test_placed_equipment("p-display1", "display-1"),
+            create_test_placed_equipment("p-display2", "display-2"),
+        ]);
+
+        let catalog = vec![camera, codec, switcher, display1, display2];
+
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
+
+        let video_connections: Vec<_> = connections
+            .iter()
+            .filter(|c| c.signal_type == SignalType::Video)
+            .collect();
+
+        // Star topology: 2 sources -> switcher, switcher -> 2 displays
+        assert_eq!(video_connections.len(), 4);
+        assert!(video_connections
+            .iter()
+            .all(|c| c.from_equipment_id == "switcher-1" || c.to_equipment_id == "switcher-1"));
+
+        let into_switcher = video_connections
+            .iter()
+            .filter(|c| c.to_equipment_id == "switcher-1")
+            .count();
+        let out_of_switcher = video_connections
+            .iter()
+            .filter(|c| c.from_equipment_id == "switcher-1")
+            .count();
+        assert_eq!(into_switcher, 2);
+        assert_eq!(out_of_switcher, 2);
+    }
+
     // ========================================================================
     // Amplifier Tests (Audio Output)
     // ========================================================================
@@ -737,7 +1345,7 @@ mod tests {
 
         let catalog = vec![mic, amp];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -761,7 +1369,7 @@ mod tests {
         let room = create_test_room(vec![]);
         let catalog: Vec<EquipmentInput> = vec![];
 
-        let result = generate_electrical_diagram(&room, &catalog);
+        let result = generate_electrical_diagram(&room, &catalog, &RoutingRules::default());
         assert!(result.is_ok());
 
         let diagram = result.unwrap();
@@ -779,7 +1387,7 @@ mod tests {
         let room = create_test_room(vec![]);
         let catalog: Vec<EquipmentInput> = vec![];
 
-        let connections = analyze_signal_flow(&room, &catalog);
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
         assert!(connections.is_empty());
     }
 
@@ -795,19 +1403,144 @@ mod tests {
 
         let catalog = vec![camera, display];
 
-        let connections = analyze_signal_flow(&room, &catalog);
+        let connections = analyze_signal_flow(&room, &catalog, &RoutingRules::default());
         assert!(!connections.is_empty());
     }
 
     // ========================================================================
-    // Cable Type Tests
+    // Cable Length Tests
+    // ========================================================================
+
+    #[test]
+    fn test_length_estimate_applies_slack_and_drop() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+
+        let mut p_camera = create_test_placed_equipment("p-camera", "camera-1");
+        p_camera.mount_type = MountType::Ceiling;
+        let mut p_display = create_test_placed_equipment("p-display", "display-1");
+        p_display.x = 4.0;
+        p_display.mount_type = MountType::Wall;
+        p_display.mount_height = Some(48.0);
+
+        let mut room = create_test_room(vec![p_camera, p_display]);
+        let catalog = vec![camera, display];
+        let estimate = |room: &RoomInput| {
+            let connections = analyze_signal_flow(room, &catalog, &RoutingRules::default());
+            assert_eq!(connections.len(), 1);
+            connections[0].length_estimate
+        };
+
+        // 4 ft * 1.15 slack + drop from the 10 ft ceiling to the 4 ft display
+        assert_eq!(estimate(&room), Some(10.6));
+
+        // Both ends at the ceiling, so no drop
+        room.placed_equipment[1].mount_type = MountType::Ceiling;
+        assert_eq!(estimate(&room), Some(4.6));
+
+        // 4 ft * 1.5 slack + rise from the floor to the 4 ft display
+        room.cable_slack = Some(1.5);
+        room.placed_equipment[0].mount_type = MountType::Floor;
+        room.placed_equipment[1].mount_type = MountType::Wall;
+        assert_eq!(estimate(&room), Some(10.0));
+    }
+
+    // ========================================================================
+    // Routing Rules Tests
+    // ========================================================================
+
+    #[test]
+    fn test_default_video_cable_preferences() {
+        let rules = RoutingRules::default();
+        assert_eq!(rules.video_cable(0), "HDMI");
+        assert_eq!(rules.video_cable(1), "DisplayPort");
+        assert_eq!(rules.video_cable(2), "SDI");
+        assert_eq!(rules.video_cable(10), "SDI");
+    }
+
+    #[test]
+    fn test_routing_rules_override_cable_types() {
+        let camera = create_test_equipment("camera-1", EquipmentCategory::Video, "cameras");
+        let display = create_test_equipment("display-1", EquipmentCategory::Video, "displays");
+        let mic = create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones");
+        let speaker = create_test_equipment("speaker-1", EquipmentCategory::Audio, "speakers");
+
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-camera", "camera-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+            create_test_placed_equipment("p-mic", "mic-1"),
+            create_test_placed_equipment("p-speaker", "speaker-1"),
+        ]);
+        let rules = RoutingRules {
+            video_cables: vec!["HDBaseT".to_string(), "HDMI".to_string()],
+            audio_cable: "Dante".to_string(),
+            ..Default::default()
+        };
+
+        let connections = analyze_signal_flow(&room, &[camera, display, mic, speaker], &rules);
+
+        let video = connections
+            .iter()
+            .find(|c| c.signal_type == SignalType::Video)
+            .unwrap();
+        let audio = connections
+            .iter()
+            .find(|c| c.signal_type == SignalType::Audio)
+            .unwrap();
+        assert_eq!(video.cable_type, "HDBaseT");
+        assert_eq!(audio.cable_type, "Dante");
+    }
+
+    #[test]
+    fn test_routing_rules_deserialize_partial() {
+        let rules: RoutingRules = serde_json::from_str(r#"{"videoCables": ["HDBaseT"]}"#).unwrap();
+        assert_eq!(rules.video_cable(3), "HDBaseT");
+        assert_eq!(rules.control_cable, "Cat6");
+    }
+
+    // ========================================================================
+    // Cable Schedule Tests
     // ========================================================================
 
     #[test]
-    fn test_determine_video_cable_type() {
-        assert_eq!(determine_video_cable_type(0), "HDMI");
-        assert_eq!(determine_video_cable_type(1), "DisplayPort");
-        assert_eq!(determine_video_cable_type(2), "SDI");
-        assert_eq!(determine_video_cable_type(10), "SDI");
+    fn test_cable_schedule_sorted_and_numbered() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("proc-1", "display-1", SignalType::Control),
+            create_test_connection("mic-1", "dsp-1", SignalType::Audio),
+            create_test_connection("codec-1", "display-1", SignalType::Video),
+            create_test_connection("camera-1", "codec-1", SignalType::Video),
+        ]);
+
+        let schedule = cable_schedule(&diagram);
+
+        let rows: Vec<(&str, &str)> = schedule
+            .rows
+            .iter()
+            .map(|r| (r.cable_id.as_str(), r.from_equipment_id.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("C-001", "camera-1"),
+                ("C-002", "codec-1"),
+                ("C-003", "mic-1"),
+                ("C-004", "proc-1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cable_schedule_uses_length_estimate() {
+        let mut estimated = create_test_connection("camera-1", "display-1", SignalType::Video);
+        estimated.length_estimate = Some(57.5);
+        let diagram = create_test_diagram(vec![
+            estimated,
+            create_test_connection("camera-1", "codec-9", SignalType::Video),
+        ]);
+
+        let schedule = cable_schedule(&diagram);
+
+        assert_eq!(schedule.rows[0].estimated_length, Some(57.5));
+        assert_eq!(schedule.rows[1].estimated_length, None);
     }
 }
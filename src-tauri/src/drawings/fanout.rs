@@ -0,0 +1,84 @@
+//! Source Fan-out
+//!
+//! Summarizes signal flow by source device for troubleshooting: which
+//! sinks each source feeds.
+
+use super::electrical::{ElectricalDiagram, SignalType};
+use std::collections::HashMap;
+
+// ============================================================================
+// Source Fan-out
+// ============================================================================
+
+/// Maps each source equipment id to the sink ids it feeds
+///
+/// Only video and audio connections are considered; control, power and
+/// network connections are excluded. Sinks are listed once each, in
+/// connection order.
+pub fn source_fanout(diagram: &ElectricalDiagram) -> HashMap<String, Vec<String>> {
+    let mut fanout: HashMap<String, Vec<String>> = HashMap::new();
+
+    for connection in diagram
+        .connections
+        .iter()
+        .filter(|c| matches!(c.signal_type, SignalType::Video | SignalType::Audio))
+    {
+        let sinks = fanout
+            .entry(connection.from_equipment_id.clone())
+            .or_default();
+        if !sinks.contains(&connection.to_equipment_id) {
+            sinks.push(connection.to_equipment_id.clone());
+        }
+    }
+
+    fanout
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to summarize a diagram's signal flow by source device
+#[tauri::command]
+pub fn compute_source_fanout(
+    diagram: ElectricalDiagram,
+) -> Result<HashMap<String, Vec<String>>, String> {
+    Ok(source_fanout(&diagram))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{create_test_connection, create_test_diagram};
+
+    #[test]
+    fn test_camera_feeding_two_displays() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "display-1", SignalType::Video),
+            create_test_connection("camera-1", "display-2", SignalType::Video),
+        ]);
+
+        let fanout = source_fanout(&diagram);
+
+        assert_eq!(fanout.len(), 1);
+        assert_eq!(fanout["camera-1"], vec!["display-1", "display-2"]);
+    }
+
+    #[test]
+    fn test_control_connections_excluded() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("mic-1", "dsp-1", SignalType::Audio),
+            create_test_connection("proc-1", "display-1", SignalType::Control),
+            create_test_connection("ups-1", "dsp-1", SignalType::Power),
+        ]);
+
+        let fanout = source_fanout(&diagram);
+
+        assert_eq!(fanout.len(), 1);
+        assert_eq!(fanout["mic-1"], vec!["dsp-1"]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Floor Plan Generator
+//!
+//! Draws the room outline and a symbol for each placed device, scaled so
+//! the whole room fits the drawable area of a sheet.
+
+use super::electrical::{
+    normalize_rotation, DrawingElement, ElementType, EquipmentInput, MountType, Rect, RoomInput,
+};
+use serde::{Deserialize, Serialize};
+
+/// Drawable area of a landscape Letter sheet with half-inch margins, in points
+pub const FLOOR_PLAN_AREA: (f64, f64) = (720.0, 540.0);
+
+/// Symbol size for devices without a catalog footprint, in points
+const DEFAULT_SYMBOL_SIZE: f64 = 12.0;
+
+// ============================================================================
+// Floor Plan - scaled plan view of one room
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FloorPlan {
+    pub room_id: String,
+    /// Points per room unit (foot)
+    pub scale: f64,
+    /// Room walls, in points
+    pub outline: Rect,
+    pub elements: Vec<DrawingElement>,
+}
+
+// ============================================================================
+// Floor Plan Generator
+// ============================================================================
+
+/// Symbol style drawn for a mount type
+///
+/// Ceiling devices are drawn as circles, the reflected-ceiling convention,
+/// wall devices as half-boxes against the wall, and everything else as boxes.
+fn symbol_style(mount_type: MountType) -> &'static str {
+    match mount_type {
+        MountType::Ceiling => "circle",
+        MountType::Wall => "half-box",
+        MountType::Floor | MountType::Rack => "box",
+    }
+}
+
+/// Points per room unit that fit the room inside a drawable area
+pub fn floor_plan_scale(room: &RoomInput, area: (f64, f64)) -> f64 {
+    (area.0 / room.width).min(area.1 / room.length)
+}
+
+/// Generates a floor plan of a room's placed equipment
+///
+/// The room is scaled to fit [`FLOOR_PLAN_AREA`], preserving its aspect
+/// ratio. Device symbols are sized from the catalog footprint when known.
+pub fn generate_floor_plan(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+) -> Result<FloorPlan, String> {
+    if room.width <= 0.0 || room.length <= 0.0 {
+        return Err("Room dimensions must be greater than zero".to_string());
+    }
+
+    let scale = floor_plan_scale(room, FLOOR_PLAN_AREA);
+    // Catalog footprints are in inches, room units in feet
+    let footprint = |inches: Option<f64>| inches.map_or(DEFAULT_SYMBOL_SIZE, |i| i / 12.0 * scale);
+
+    let elements = room
+        .placed_equipment
+        .iter()
+        .map(|placed| {
+            let equipment = equipment_catalog
+                .iter()
+                .find(|e| e.id == placed.equipment_id);
+
+            let label = match equipment {
+                Some(eq) => format!("{} {}", eq.manufacturer, eq.model),
+                None => format!("Unknown Equipment ({})", placed.equipment_id),
+            };
+            let specs = equipment.map(|e| &e.specs);
+
+            DrawingElement {
+                id: format!("plan-{}", placed.id),
+                element_type: match placed.mount_type {
+                    MountType::Ceiling => ElementType::Symbol,
+                    _ => ElementType::Equipment,
+                },
+                x: placed.x * scale,
+                y: placed.y * scale,
+                rotation: normalize_rotation(placed.rotation),
+                label,
+                properties: serde_json::json!({
+                    "equipment_id": placed.equipment_id,
+                    "mount_type": placed.mount_type,
+                    "symbol": symbol_style(placed.mount_type),
+                    "width": footprint(specs.and_then(|s| s.width)),
+                    "depth": footprint(specs.and_then(|s| s.depth)),
+                }),
+            }
+        })
+        .collect();
+
+    Ok(FloorPlan {
+        room_id: room.id.clone(),
+        scale,
+        outline: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: room.width * scale,
+            height: room.length * scale,
+        },
+        elements,
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to generate a scaled floor plan of a room
+#[tauri::command]
+pub fn generate_floor_plan_drawing(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<FloorPlan, String> {
+    generate_floor_plan(&room, &equipment_catalog)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentCategory, EquipmentSpecs, PlacedEquipmentInput};
+    use crate::drawings::test_support::{self, create_test_placed_equipment};
+
+    fn create_test_equipment(id: &str, width: Option<f64>) -> EquipmentInput {
+        EquipmentInput {
+            manufacturer: "Shure".to_string(),
+            model: "MXA920".to_string(),
+            specs: EquipmentSpecs {
+                width,
+                depth: width,
+                ..Default::default()
+            },
+            ..test_support::create_test_equipment(id, EquipmentCategory::Audio, "microphones")
+        }
+    }
+
+    fn create_test_room(width: f64, length: f64, mounts: &[MountType]) -> RoomInput {
+        let placed_equipment = mounts
+            .iter()
+            .enumerate()
+            .map(|(i, mount_type)| PlacedEquipmentInput {
+                x: 10.0,
+                y: 5.0,
+                rotation: -90.0,
+                mount_type: *mount_type,
+                ..create_test_placed_equipment(&format!("p{}", i), "mic")
+            })
+            .collect();
+        RoomInput {
+            width,
+            length,
+            ..test_support::create_test_room(placed_equipment)
+        }
+    }
+
+    #[test]
+    fn test_20ft_room_fits_drawable_area() {
+        let room = create_test_room(20.0, 20.0, &[]);
+
+        let plan = generate_floor_plan(&room, &[]).unwrap();
+
+        assert_eq!(plan.scale, 27.0);
+        assert!(plan.outline.width <= FLOOR_PLAN_AREA.0);
+        assert!(plan.outline.height <= FLOOR_PLAN_AREA.1);
+        assert_eq!(plan.outline.height, FLOOR_PLAN_AREA.1);
+    }
+
+    #[test]
+    fn test_equipment_scaled_and_labeled() {
+        let catalog = vec![create_test_equipment("mic", Some(24.0))];
+        let room = create_test_room(20.0, 20.0, &[MountType::Ceiling]);
+
+        let plan = generate_floor_plan(&room, &catalog).unwrap();
+
+        let element = &plan.elements[0];
+        assert_eq!((element.x, element.y), (270.0, 135.0));
+        assert_eq!(element.rotation, 270.0);
+        assert_eq!(element.label, "Shure MXA920");
+        assert_eq!(element.properties["width"], 54.0);
+    }
+
+    #[test]
+    fn test_mount_type_picks_symbol_style() {
+        let room = create_test_room(
+            20.0,
+            20.0,
+            &[MountType::Ceiling, MountType::Wall, MountType::Floor],
+        );
+
+        let plan = generate_floor_plan(&room, &[]).unwrap();
+
+        let styles: Vec<&str> = plan
+            .elements
+            .iter()
+            .map(|e| e.properties["symbol"].as_str().unwrap())
+            .collect();
+        assert_eq!(styles, vec!["circle", "half-box", "box"]);
+        assert_eq!(plan.elements[0].element_type, ElementType::Symbol);
+        assert_eq!(plan.elements[1].element_type, ElementType::Equipment);
+    }
+
+    #[test]
+    fn test_zero_size_room_rejected() {
+        assert!(generate_floor_plan(&create_test_room(0.0, 20.0, &[]), &[]).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+//! Installation Sequence
+//!
+//! Orders placed equipment for project scheduling so that supporting
+//! infrastructure goes in before the devices that depend on it.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, RoomInput};
+
+// ============================================================================
+// Install Phase
+// ============================================================================
+
+/// Installation phases, in the order they are carried out on site
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum InstallPhase {
+    /// Racks and enclosures that other devices mount into
+    Racks,
+    /// Remaining infrastructure such as network switches and power
+    Infrastructure,
+    /// Signal sources (cameras, codecs, microphones)
+    Sources,
+    /// Signal destinations (displays, speakers, amplifiers)
+    Outputs,
+    /// Equipment missing from the catalog
+    Unknown,
+    /// Control processors and touch panels, commissioned last
+    Control,
+}
+
+/// Determines the install phase for a catalog item
+fn install_phase(equipment: Option<&EquipmentInput>) -> InstallPhase {
+    let Some(equipment) = equipment else {
+        return InstallPhase::Unknown;
+    };
+
+    match equipment.category {
+        EquipmentCategory::Infrastructure => match equipment.subcategory.as_str() {
+            "racks" => InstallPhase::Racks,
+            _ => InstallPhase::Infrastructure,
+        },
+        EquipmentCategory::Video => match equipment.subcategory.as_str() {
+            "cameras" | "codecs" => InstallPhase::Sources,
+            _ => InstallPhase::Outputs,
+        },
+        EquipmentCategory::Audio => match equipment.subcategory.as_str() {
+            "microphones" => InstallPhase::Sources,
+            _ => InstallPhase::Outputs,
+        },
+        EquipmentCategory::Control => InstallPhase::Control,
+    }
+}
+
+// ============================================================================
+// Install Order
+// ============================================================================
+
+/// Computes the installation order of a room's placed equipment
+///
+/// Returns placed equipment ids ordered by phase: racks, other
+/// infrastructure, sources, outputs, then control. Equipment within the
+/// same phase keeps its placement order.
+pub fn install_order(room: &RoomInput, equipment_catalog: &[EquipmentInput]) -> Vec<String> {
+    let mut phased: Vec<(InstallPhase, &str)> = room
+        .placed_equipment
+        .iter()
+        .map(|placed| {
+            let equipment = equipment_catalog
+                .iter()
+                .find(|e| e.id == placed.equipment_id);
+            (install_phase(equipment), placed.id.as_str())
+        })
+        .collect();
+
+    // Stable sort keeps placement order within each phase
+    phased.sort_by_key(|(phase, _)| *phase);
+
+    phased.into_iter().map(|(_, id)| id.to_string()).collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to compute the installation sequence for a room
+#[tauri::command]
+pub fn compute_install_order(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<Vec<String>, String> {
+    Ok(install_order(&room, &equipment_catalog))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{MountType, PlacedEquipmentInput};
+    use crate::drawings::test_support::{self, create_test_equipment, create_test_room};
+
+    fn create_test_placed_equipment(id: &str, equipment_id: &str) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            mount_type: MountType::Rack,
+            ..test_support::create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    fn position(order: &[String], id: &str) -> usize {
+        order.iter().position(|o| o == id).unwrap()
+    }
+
+    #[test]
+    fn test_install_order_empty_room() {
+        let room = create_test_room(vec![]);
+        assert!(install_order(&room, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_racks_precede_mounted_devices() {
+        let catalog = vec![
+            create_test_equipment("codec-1", EquipmentCategory::Video, "codecs"),
+            create_test_equipment("amp-1", EquipmentCategory::Audio, "amplifiers"),
+            create_test_equipment("switch-1", EquipmentCategory::Infrastructure, "switches"),
+            create_test_equipment("rack-1", EquipmentCategory::Infrastructure, "racks"),
+        ];
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-codec", "codec-1"),
+            create_test_placed_equipment("p-amp", "amp-1"),
+            create_test_placed_equipment("p-switch", "switch-1"),
+            create_test_placed_equipment("p-rack", "rack-1"),
+        ]);
+
+        let order = install_order(&room, &catalog);
+
+        assert_eq!(order, vec!["p-rack", "p-switch", "p-codec", "p-amp"]);
+    }
+
+    #[test]
+    fn test_control_devices_come_last() {
+        let catalog = vec![
+            create_test_equipment("proc-1", EquipmentCategory::Control, "processors"),
+            create_test_equipment("display-1", EquipmentCategory::Video, "displays"),
+            create_test_equipment("mic-1", EquipmentCategory::Audio, "microphones"),
+        ];
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-proc", "proc-1"),
+            create_test_placed_equipment("p-display", "display-1"),
+            create_test_placed_equipment("p-unknown", "missing"),
+            create_test_placed_equipment("p-mic", "mic-1"),
+        ]);
+
+        let order = install_order(&room, &catalog);
+
+        assert_eq!(order.len(), 4);
+        assert_eq!(order.last().unwrap(), "p-proc");
+        assert!(position(&order, "p-mic") < position(&order, "p-display"));
+        assert!(position(&order, "p-unknown") < position(&order, "p-proc"));
+    }
+}
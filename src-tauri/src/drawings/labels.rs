@@ -0,0 +1,152 @@
+//! Connection Labels
+//!
+//! Generates installer cable labels for diagram connections from a naming
+//! scheme such as `{room}-{signal}-{seq}`.
+
+use super::electrical::{ElectricalDiagram, SignalType};
+
+/// Tokens that may appear in a labeling scheme
+const KNOWN_TOKENS: [&str; 3] = ["room", "signal", "seq"];
+
+/// Short signal code used in cable labels
+fn signal_code(signal_type: SignalType) -> &'static str {
+    match signal_type {
+        SignalType::Video => "VID",
+        SignalType::Audio => "AUD",
+        SignalType::Control => "CTL",
+        SignalType::Power => "PWR",
+        SignalType::Network => "NET",
+    }
+}
+
+/// Checks that every `{token}` in the scheme is known and braces are balanced
+fn validate_scheme(scheme: &str) -> Result<(), String> {
+    let mut rest = scheme;
+
+    while let Some(open) = rest.find('{') {
+        let after = &rest[open + 1..];
+        let close = after
+            .find('}')
+            .ok_or_else(|| format!("Unclosed token in label scheme: '{}'", scheme))?;
+        let token = &after[..close];
+
+        if !KNOWN_TOKENS.contains(&token) {
+            return Err(format!(
+                "Unknown token '{{{}}}' in label scheme (expected one of: {})",
+                token,
+                KNOWN_TOKENS.join(", ")
+            ));
+        }
+
+        rest = &after[close + 1..];
+    }
+
+    if rest.contains('}') {
+        return Err(format!("Unmatched '}}' in label scheme: '{}'", scheme));
+    }
+
+    Ok(())
+}
+
+/// Fills in the label of every connection by expanding the scheme
+///
+/// `{room}` expands to the diagram's room id, `{signal}` to a short signal
+/// code (e.g. `VID`), and `{seq}` to a three-digit sequence number that
+/// increments across the diagram's connections.
+pub fn label_connections(
+    diagram: &ElectricalDiagram,
+    scheme: &str,
+) -> Result<ElectricalDiagram, String> {
+    validate_scheme(scheme)?;
+
+    let mut labeled = diagram.clone();
+
+    for (idx, connection) in labeled.connections.iter_mut().enumerate() {
+        let label = scheme
+            .replace("{room}", &diagram.room_id)
+            .replace("{signal}", signal_code(connection.signal_type))
+            .replace("{seq}", &format!("{:03}", idx + 1));
+        connection.label = Some(label);
+    }
+
+    Ok(labeled)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to label diagram connections using a naming scheme
+#[tauri::command]
+pub fn generate_connection_labels(
+    diagram: ElectricalDiagram,
+    scheme: String,
+) -> Result<ElectricalDiagram, String> {
+    label_connections(&diagram, &scheme)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::SignalConnection;
+    use crate::drawings::test_support;
+
+    fn create_test_connection(id: &str, signal_type: SignalType) -> SignalConnection {
+        SignalConnection {
+            id: id.to_string(),
+            cable_type: "Cat6".to_string(),
+            ..test_support::create_test_connection("from", "to", signal_type)
+        }
+    }
+
+    fn create_test_diagram() -> ElectricalDiagram {
+        ElectricalDiagram {
+            room_id: "CR1".to_string(),
+            ..test_support::create_test_diagram(vec![
+                create_test_connection("conn-1", SignalType::Video),
+                create_test_connection("conn-2", SignalType::Audio),
+                create_test_connection("conn-3", SignalType::Control),
+            ])
+        }
+    }
+
+    #[test]
+    fn test_labels_follow_template() {
+        let diagram = create_test_diagram();
+
+        let labeled = label_connections(&diagram, "{room}-{signal}-{seq}").unwrap();
+
+        let labels: Vec<&str> = labeled
+            .connections
+            .iter()
+            .map(|c| c.label.as_deref().unwrap())
+            .collect();
+        assert_eq!(labels, vec!["CR1-VID-001", "CR1-AUD-002", "CR1-CTL-003"]);
+    }
+
+    #[test]
+    fn test_labels_literal_text_preserved() {
+        let diagram = create_test_diagram();
+
+        let labeled = label_connections(&diagram, "W{seq}").unwrap();
+
+        assert_eq!(labeled.connections[1].label.as_deref(), Some("W002"));
+    }
+
+    #[test]
+    fn test_unknown_token_rejected() {
+        let diagram = create_test_diagram();
+        let result = label_connections(&diagram, "{room}-{floor}");
+        assert!(result.unwrap_err().contains("floor"));
+    }
+
+    #[test]
+    fn test_unclosed_token_rejected() {
+        let diagram = create_test_diagram();
+        assert!(label_connections(&diagram, "{room-{seq}").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Diagram Auto-Layout
+//!
+//! Nudges diagram elements apart so equipment placed close together in the
+//! room does not overlap on the drawing, and snaps them to a drafting grid.
+
+use super::electrical::DrawingElement;
+use std::collections::HashSet;
+
+/// Default minimum distance between element centers, in room units
+pub const DEFAULT_MIN_SPACING: f64 = 2.0;
+
+/// Separation passes to run before giving up on a dense cluster
+const MAX_LAYOUT_ITERATIONS: usize = 200;
+
+/// Extra separation added to each push so floating point error cannot
+/// leave a pair a hair closer than `min_spacing`
+const SPACING_EPSILON: f64 = 1e-6;
+
+/// Pushes overlapping elements apart until every pair is `min_spacing` apart
+///
+/// Each pass finds every crowded pair and pushes both elements away from
+/// each other along the line between them, by half the shortfall each. The
+/// pushes of a pass are summed and applied together, so a crowded row or
+/// column spreads out without elements jumping past each other. Elements at
+/// the same point are separated horizontally, earlier elements to the left.
+pub fn auto_layout(elements: &mut [DrawingElement], min_spacing: f64) {
+    if min_spacing <= 0.0 {
+        return;
+    }
+
+    for _ in 0..MAX_LAYOUT_ITERATIONS {
+        let mut offsets = vec![(0.0, 0.0); elements.len()];
+        let mut crowded = false;
+
+        for i in 0..elements.len() {
+            for j in i + 1..elements.len() {
+                let dx = elements[j].x - elements[i].x;
+                let dy = elements[j].y - elements[i].y;
+                let distance = dx.hypot(dy);
+                if distance >= min_spacing {
+                    continue;
+                }
+
+                let (ux, uy) = if distance > 0.0 {
+                    (dx / distance, dy / distance)
+                } else {
+                    (1.0, 0.0)
+                };
+                let push = (min_spacing - distance) / 2.0 + SPACING_EPSILON;
+
+                offsets[i].0 -= ux * push;
+                offsets[i].1 -= uy * push;
+                offsets[j].0 += ux * push;
+                offsets[j].1 += uy * push;
+                crowded = true;
+            }
+        }
+
+        if !crowded {
+            break;
+        }
+        for (element, (dx, dy)) in elements.iter_mut().zip(offsets) {
+            element.x += dx;
+            element.y += dy;
+        }
+    }
+}
+
+/// Rounds each element's position to the nearest multiple of `grid_size`
+///
+/// Connections reference equipment by id, so their endpoints follow the
+/// snapped positions. Elements less than a grid cell apart can land on the
+/// same point, so the ids of elements that land on a point already taken by
+/// an earlier element are returned. A grid size that is not positive leaves
+/// elements unchanged.
+pub fn snap_to_grid(elements: &mut [DrawingElement], grid_size: f64) -> Vec<String> {
+    if !(grid_size > 0.0 && grid_size.is_finite()) {
+        return Vec::new();
+    }
+
+    let mut occupied = HashSet::new();
+    let mut collisions = Vec::new();
+    for element in elements {
+        let column = (element.x / grid_size).round();
+        let row = (element.y / grid_size).round();
+        element.x = column * grid_size;
+        element.y = row * grid_size;
+        if !occupied.insert((column as i64, row as i64)) {
+            collisions.push(element.id.clone());
+        }
+    }
+    collisions
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::ElementType;
+
+    fn create_test_element(id: &str, x: f64, y: f64) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type: ElementType::Equipment,
+            x,
+            y,
+            rotation: 0.0,
+            label: id.to_string(),
+            properties: serde_json::json!({}),
+        }
+    }
+
+    fn min_distance(elements: &[DrawingElement]) -> f64 {
+        let mut min = f64::INFINITY;
+        for (i, a) in elements.iter().enumerate() {
+            for b in &elements[i + 1..] {
+                min = min.min((b.x - a.x).hypot(b.y - a.y));
+            }
+        }
+        min
+    }
+
+    #[test]
+    fn test_spread_elements_unchanged() {
+        let mut elements = vec![
+            create_test_element("a", 0.0, 0.0),
+            create_test_element("b", 5.0, 0.0),
+        ];
+
+        auto_layout(&mut elements, 2.0);
+
+        assert_eq!((elements[0].x, elements[1].x), (0.0, 5.0));
+    }
+
+    #[test]
+    fn test_coincident_elements_separated_in_order() {
+        let mut elements = vec![
+            create_test_element("a", 3.0, 3.0),
+            create_test_element("b", 3.0, 3.0),
+        ];
+
+        auto_layout(&mut elements, 2.0);
+
+        assert!(min_distance(&elements) >= 2.0);
+        assert!(elements[0].x < elements[1].x);
+        assert_eq!(elements[0].y, elements[1].y);
+    }
+
+    #[test]
+    fn test_cluster_separated_keeping_order() {
+        let mut elements: Vec<DrawingElement> = (0..5)
+            .map(|i| create_test_element(&format!("e{}", i), 10.0 + i as f64 * 0.1, 4.0))
+            .collect();
+
+        auto_layout(&mut elements, DEFAULT_MIN_SPACING);
+
+        assert!(min_distance(&elements) >= DEFAULT_MIN_SPACING);
+        assert!(elements.windows(2).all(|w| w[0].x < w[1].x));
+    }
+
+    #[test]
+    fn test_crowded_column_keeps_order() {
+        let mut elements = vec![
+            create_test_element("top", 2.0, 1.0),
+            create_test_element("middle", 2.0, 1.5),
+            create_test_element("bottom", 2.0, 1.6),
+        ];
+
+        auto_layout(&mut elements, DEFAULT_MIN_SPACING);
+
+        assert!(min_distance(&elements) >= DEFAULT_MIN_SPACING);
+        assert!(elements.windows(2).all(|w| w[0].y < w[1].y));
+        assert!(elements.iter().all(|e| e.x == 2.0));
+    }
+
+    #[test]
+    fn test_snap_to_grid_rounds_to_nearest() {
+        let mut elements = vec![
+            create_test_element("a", 103.0, 107.0),
+            create_test_element("b", 107.0, -103.0),
+        ];
+
+        let collisions = snap_to_grid(&mut elements, 10.0);
+
+        assert_eq!((elements[0].x, elements[0].y), (100.0, 110.0));
+        assert_eq!((elements[1].x, elements[1].y), (110.0, -100.0));
+        assert!(collisions.is_empty());
+    }
+
+    #[test]
+    fn test_snap_to_grid_reports_collisions() {
+        let mut elements = vec![
+            create_test_element("a", 0.0, 0.0),
+            create_test_element("b", 2.0, 0.0),
+            create_test_element("c", 4.0, 0.0),
+            create_test_element("d", 6.0, 0.0),
+        ];
+
+        auto_layout(&mut elements, DEFAULT_MIN_SPACING);
+        let collisions = snap_to_grid(&mut elements, 10.0);
+
+        assert_eq!(collisions, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_snap_to_grid_ignores_invalid_grid() {
+        let mut elements = vec![create_test_element("a", 103.0, 107.0)];
+
+        assert!(snap_to_grid(&mut elements, 0.0).is_empty());
+        assert!(snap_to_grid(&mut elements, f64::NAN).is_empty());
+
+        assert_eq!((elements[0].x, elements[0].y), (103.0, 107.0));
+    }
+}
@@ -4,6 +4,58 @@
 //! It includes electrical line diagrams, signal flow analysis, and
 //! other drawing types.
 
+pub mod accessibility;
+pub mod alternatives;
+pub mod amplifier;
+pub mod audio_zones;
+pub mod cable_runs;
+pub mod certifications;
+pub mod combine;
+pub mod conduit;
+pub mod cycles;
+pub mod design_standard;
+pub mod diff;
 pub mod electrical;
+pub mod fanout;
+pub mod floorplan;
+pub mod install;
+pub mod labels;
+pub mod layout;
+pub mod mounting;
+pub mod placement;
+pub mod power;
+pub mod prune;
+pub mod rack;
+pub mod rcp;
+pub mod reachability;
+pub mod room_zones;
+pub mod routing;
+#[cfg(test)]
+pub(crate) mod test_support;
 
+pub use accessibility::*;
+pub use alternatives::*;
+pub use amplifier::*;
+pub use audio_zones::*;
+pub use cable_runs::*;
+pub use certifications::*;
+pub use combine::*;
+pub use conduit::*;
+pub use cycles::*;
+pub use design_standard::*;
+pub use diff::*;
 pub use electrical::*;
+pub use fanout::*;
+pub use floorplan::*;
+pub use install::*;
+pub use labels::*;
+pub use layout::*;
+pub use mounting::*;
+pub use placement::*;
+pub use power::*;
+pub use prune::*;
+pub use rack::*;
+pub use rcp::*;
+pub use reachability::*;
+pub use room_zones::*;
+pub use routing::*;
@@ -0,0 +1,231 @@
+//! Mount Load Check
+//!
+//! Sums equipment weight at each mount point and flags mounts whose load
+//! exceeds the configured limit for their mount type.
+
+use super::electrical::{EquipmentInput, MountType, RoomInput};
+use serde::{Deserialize, Serialize};
+
+/// Distance, in room units, within which equipment shares a mount point
+pub const MOUNT_POINT_TOLERANCE: f64 = 0.1;
+
+// ============================================================================
+// Mount Limits - configured load limits per mount type
+// ============================================================================
+
+/// Maximum load per mount point in pounds; `None` means unlimited
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MountLimits {
+    pub floor: Option<f64>,
+    pub wall: Option<f64>,
+    pub ceiling: Option<f64>,
+    pub rack: Option<f64>,
+}
+
+impl MountLimits {
+    /// Returns the configured limit for a mount type
+    pub fn limit_for(&self, mount_type: MountType) -> Option<f64> {
+        match mount_type {
+            MountType::Floor => self.floor,
+            MountType::Wall => self.wall,
+            MountType::Ceiling => self.ceiling,
+            MountType::Rack => self.rack,
+        }
+    }
+}
+
+// ============================================================================
+// Mount Issue - an overloaded mount point
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MountIssue {
+    pub mount_type: MountType,
+    pub x: f64,
+    pub y: f64,
+    pub placed_equipment_ids: Vec<String>,
+    pub total_weight: f64,
+    pub limit: f64,
+}
+
+// ============================================================================
+// Mount Load Check
+// ============================================================================
+
+/// Flags mount points whose total equipment weight exceeds the limit
+///
+/// Equipment sharing a mount type and lying within `MOUNT_POINT_TOLERANCE`
+/// of a mount point's first device is treated as part of that mount point.
+/// Equipment without a known weight contributes nothing.
+pub fn mount_load_check(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+    limits: &MountLimits,
+) -> Vec<MountIssue> {
+    let mut mount_points: Vec<MountIssue> = Vec::new();
+
+    for placed in &room.placed_equipment {
+        let Some(limit) = limits.limit_for(placed.mount_type) else {
+            continue;
+        };
+
+        let weight = equipment_catalog
+            .iter()
+            .find(|e| e.id == placed.equipment_id)
+            .and_then(|e| e.weight)
+            .unwrap_or(0.0);
+
+        let existing = mount_points.iter_mut().find(|m| {
+            m.mount_type == placed.mount_type
+                && (m.x - placed.x).hypot(m.y - placed.y) <= MOUNT_POINT_TOLERANCE
+        });
+
+        match existing {
+            Some(mount) => {
+                mount.total_weight += weight;
+                mount.placed_equipment_ids.push(placed.id.clone());
+            }
+            None => mount_points.push(MountIssue {
+                mount_type: placed.mount_type,
+                x: placed.x,
+                y: placed.y,
+                placed_equipment_ids: vec![placed.id.clone()],
+                total_weight: weight,
+                limit,
+            }),
+        }
+    }
+
+    mount_points
+        .into_iter()
+        .filter(|m| m.total_weight > m.limit)
+        .collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to flag overloaded mount points in a room
+#[tauri::command]
+pub fn check_mount_loads(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+    limits: MountLimits,
+) -> Result<Vec<MountIssue>, String> {
+    Ok(mount_load_check(&room, &equipment_catalog, &limits))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentCategory, PlacedEquipmentInput};
+    use crate::drawings::test_support::{self, create_test_room};
+
+    fn create_test_equipment(id: &str, weight: f64) -> EquipmentInput {
+        EquipmentInput {
+            weight: Some(weight),
+            ..test_support::create_test_equipment(id, EquipmentCategory::Video, "displays")
+        }
+    }
+
+    fn create_test_placed_equipment(
+        id: &str,
+        equipment_id: &str,
+        mount_type: MountType,
+    ) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            x: 100.0,
+            mount_type,
+            ..test_support::create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    fn wall_limits() -> MountLimits {
+        MountLimits {
+            wall: Some(100.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wall_mount_over_limit_flagged() {
+        let catalog = vec![
+            create_test_equipment("display-1", 85.0),
+            create_test_equipment("camera-1", 25.0),
+        ];
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-display", "display-1", MountType::Wall),
+            create_test_placed_equipment("p-camera", "camera-1", MountType::Wall),
+        ]);
+
+        let issues = mount_load_check(&room, &catalog, &wall_limits());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].total_weight, 110.0);
+        assert_eq!(
+            issues[0].placed_equipment_ids,
+            vec!["p-display", "p-camera"]
+        );
+    }
+
+    #[test]
+    fn test_nearby_devices_share_mount_point() {
+        let catalog = vec![
+            create_test_equipment("display-1", 85.0),
+            create_test_equipment("camera-1", 25.0),
+            create_test_equipment("speaker-1", 25.0),
+        ];
+        let camera = PlacedEquipmentInput {
+            x: 100.0 + MOUNT_POINT_TOLERANCE / 2.0,
+            ..create_test_placed_equipment("p-camera", "camera-1", MountType::Wall)
+        };
+        let speaker = PlacedEquipmentInput {
+            x: 100.0 + MOUNT_POINT_TOLERANCE * 2.0,
+            ..create_test_placed_equipment("p-speaker", "speaker-1", MountType::Wall)
+        };
+        let room = create_test_room(vec![
+            create_test_placed_equipment("p-display", "display-1", MountType::Wall),
+            camera,
+            speaker,
+        ]);
+
+        let issues = mount_load_check(&room, &catalog, &wall_limits());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].placed_equipment_ids,
+            vec!["p-display", "p-camera"]
+        );
+    }
+
+    #[test]
+    fn test_wall_mount_under_limit_passes() {
+        let catalog = vec![create_test_equipment("display-1", 85.0)];
+        let room = create_test_room(vec![create_test_placed_equipment(
+            "p-display",
+            "display-1",
+            MountType::Wall,
+        )]);
+
+        assert!(mount_load_check(&room, &catalog, &wall_limits()).is_empty());
+    }
+
+    #[test]
+    fn test_unlimited_mount_type_ignored() {
+        let catalog = vec![create_test_equipment("display-1", 500.0)];
+        let room = create_test_room(vec![create_test_placed_equipment(
+            "p-display",
+            "display-1",
+            MountType::Floor,
+        )]);
+
+        assert!(mount_load_check(&room, &catalog, &wall_limits()).is_empty());
+    }
+}
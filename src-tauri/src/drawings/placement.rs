@@ -0,0 +1,121 @@
+//! Placement Suggestions
+//!
+//! Recommends a starting position and mount for new equipment based on its
+//! category and the room dimensions. The front wall is at `y = 0`.
+
+use super::electrical::{EquipmentCategory, EquipmentInput, MountType, RoomInput};
+
+/// Size of a standard ceiling tile in feet
+pub const CEILING_TILE_SIZE: f64 = 2.0;
+
+// ============================================================================
+// Placement Suggestion
+// ============================================================================
+
+/// Suggests an `(x, y, mount)` placement for equipment in a room
+///
+/// Displays and cameras go to the front wall center, speakers to the ceiling
+/// tile nearest the room center, and microphones to the table center. All
+/// other equipment is suggested in a rack along the back wall.
+pub fn suggest_placement(room: &RoomInput, equipment: &EquipmentInput) -> (f64, f64, MountType) {
+    let center_x = room.width / 2.0;
+    let center_y = room.length / 2.0;
+
+    match (equipment.category, equipment.subcategory.as_str()) {
+        (EquipmentCategory::Video, "displays" | "cameras") => (center_x, 0.0, MountType::Wall),
+        (EquipmentCategory::Audio, "speakers") => (
+            snap_to_tile_center(center_x, room.width),
+            snap_to_tile_center(center_y, room.length),
+            MountType::Ceiling,
+        ),
+        (EquipmentCategory::Audio, "microphones") => (center_x, center_y, MountType::Floor),
+        _ => (center_x, room.length, MountType::Rack),
+    }
+}
+
+/// Snaps a coordinate to the center of its ceiling tile, staying in the room
+fn snap_to_tile_center(value: f64, extent: f64) -> f64 {
+    let snapped = (value / CEILING_TILE_SIZE).floor() * CEILING_TILE_SIZE + CEILING_TILE_SIZE / 2.0;
+    snapped.min(extent)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to suggest a placement for equipment in a room
+#[tauri::command]
+pub fn suggest_equipment_placement(
+    room: RoomInput,
+    equipment: EquipmentInput,
+) -> Result<(f64, f64, MountType), String> {
+    if room.width <= 0.0 || room.length <= 0.0 {
+        return Err("Room dimensions must be greater than zero".to_string());
+    }
+
+    Ok(suggest_placement(&room, &equipment))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support;
+
+    fn create_test_equipment(category: EquipmentCategory, subcategory: &str) -> EquipmentInput {
+        test_support::create_test_equipment("eq-1", category, subcategory)
+    }
+
+    fn create_test_room(width: f64, length: f64) -> RoomInput {
+        RoomInput {
+            width,
+            length,
+            ..test_support::create_test_room(Vec::new())
+        }
+    }
+
+    #[test]
+    fn test_display_suggested_at_front_wall_center() {
+        let room = create_test_room(20.0, 30.0);
+        let display = create_test_equipment(EquipmentCategory::Video, "displays");
+
+        let (x, y, mount) = suggest_placement(&room, &display);
+
+        assert_eq!(x, 10.0);
+        assert_eq!(y, 0.0);
+        assert_eq!(mount, MountType::Wall);
+    }
+
+    #[test]
+    fn test_speaker_snaps_to_ceiling_tile() {
+        let room = create_test_room(20.0, 30.0);
+        let speaker = create_test_equipment(EquipmentCategory::Audio, "speakers");
+
+        let (x, y, mount) = suggest_placement(&room, &speaker);
+
+        assert_eq!((x, y), (11.0, 15.0));
+        assert_eq!(mount, MountType::Ceiling);
+    }
+
+    #[test]
+    fn test_microphone_suggested_at_table_center() {
+        let room = create_test_room(20.0, 30.0);
+        let mic = create_test_equipment(EquipmentCategory::Audio, "microphones");
+
+        assert_eq!(
+            suggest_placement(&room, &mic),
+            (10.0, 15.0, MountType::Floor)
+        );
+    }
+
+    #[test]
+    fn test_command_rejects_empty_room() {
+        let room = create_test_room(0.0, 30.0);
+        let display = create_test_equipment(EquipmentCategory::Video, "displays");
+
+        assert!(suggest_equipment_placement(room, display).is_err());
+    }
+}
@@ -0,0 +1,390 @@
+//! Power and Heat Load Calculation
+//!
+//! Sums the power draw of a room's placed equipment per circuit voltage and
+//! flags loads that would overrun a standard 15A or 20A branch circuit, and
+//! converts the same draw to heat output for HVAC coordination.
+
+use super::electrical::{EquipmentCategory, RoomInput};
+use crate::database::Equipment;
+use serde::{Deserialize, Serialize};
+
+/// Circuit voltage assumed for universal (e.g. "100-240V") or unspecified
+/// supplies, in volts
+pub const DEFAULT_CIRCUIT_VOLTS: f64 = 120.0;
+
+/// Share of a breaker rating usable by continuous loads such as AV gear
+pub const CONTINUOUS_LOAD_FACTOR: f64 = 0.8;
+
+/// Standard branch circuit breaker ratings, in amps
+const CIRCUIT_15A: f64 = 15.0;
+const CIRCUIT_20A: f64 = 20.0;
+
+/// Heat output per watt of power draw, in BTU per hour
+pub const BTU_PER_WATT: f64 = 3.412;
+
+/// Categories in heat report order
+const HEAT_CATEGORIES: [EquipmentCategory; 4] = [
+    EquipmentCategory::Video,
+    EquipmentCategory::Audio,
+    EquipmentCategory::Control,
+    EquipmentCategory::Infrastructure,
+];
+
+// ============================================================================
+// Power Summary - room load grouped by circuit voltage
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitLoad {
+    /// Circuit voltage, in volts
+    pub volts: f64,
+    /// Placed equipment drawing from circuits at this voltage
+    pub placed_ids: Vec<String>,
+    pub total_watts: f64,
+    pub amps: f64,
+    /// Load exceeds the continuous rating of a 15A circuit
+    pub exceeds_15a: bool,
+    /// Load exceeds the continuous rating of a 20A circuit
+    pub exceeds_20a: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerSummary {
+    pub room_id: String,
+    pub total_watts: f64,
+    pub circuits: Vec<CircuitLoad>,
+    /// Placed equipment left out of the totals, with the reason
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// Heat Summary - room heat output by equipment category
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EquipmentHeat {
+    pub placed_id: String,
+    pub category: EquipmentCategory,
+    /// Zero when the equipment has no wattage rating
+    pub btu_per_hour: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryHeat {
+    pub category: EquipmentCategory,
+    pub btu_per_hour: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatSummary {
+    pub room_id: String,
+    pub total_btu_per_hour: f64,
+    /// Heat output of every category, including those with none placed
+    pub categories: Vec<CategoryHeat>,
+    pub equipment: Vec<EquipmentHeat>,
+    /// Placed equipment counted as zero, with the reason
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// Power Load Calculation
+// ============================================================================
+
+/// Circuit voltage for a catalog supply voltage such as "230V AC"
+///
+/// Universal supplies given as a range, and missing or unreadable values,
+/// run on the default circuit.
+fn circuit_volts(voltage: Option<&str>) -> f64 {
+    let Some(voltage) = voltage.map(str::trim).filter(|v| !v.is_empty()) else {
+        return DEFAULT_CIRCUIT_VOLTS;
+    };
+    if voltage.contains('-') || voltage.contains('~') {
+        return DEFAULT_CIRCUIT_VOLTS;
+    }
+
+    let digits: String = voltage
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    digits
+        .parse::<f64>()
+        .ok()
+        .filter(|v| *v > 0.0)
+        .unwrap_or(DEFAULT_CIRCUIT_VOLTS)
+}
+
+/// Whether a load in amps exceeds a breaker's continuous rating
+fn exceeds(amps: f64, breaker_amps: f64) -> bool {
+    amps > breaker_amps * CONTINUOUS_LOAD_FACTOR
+}
+
+/// Sums the power draw of a room's placed equipment by circuit voltage
+///
+/// Wattage comes from the catalog. Equipment without a wattage rating, or
+/// missing from the catalog, is reported in `warnings` and left out of the
+/// totals. Circuits are listed in the order their first device appears in
+/// the room, and flagged against the continuous (80%) rating of 15A and
+/// 20A breakers.
+pub fn calculate_power_load(room: &RoomInput, catalog: &[Equipment]) -> PowerSummary {
+    let mut circuits: Vec<CircuitLoad> = Vec::new();
+    let mut warnings = Vec::new();
+
+    for placed in &room.placed_equipment {
+        let Some(equipment) = catalog.iter().find(|e| e.id == placed.equipment_id) else {
+            warnings.push(format!(
+                "{}: equipment {} not found in catalog",
+                placed.id, placed.equipment_id
+            ));
+            continue;
+        };
+        let Some(watts) = equipment.specs.wattage else {
+            warnings.push(format!(
+                "{}: no wattage rating for {} {}",
+                placed.id, equipment.manufacturer, equipment.model
+            ));
+            continue;
+        };
+
+        let volts = circuit_volts(equipment.specs.voltage.as_deref());
+        let index = match circuits.iter().position(|c| c.volts == volts) {
+            Some(index) => index,
+            None => {
+                circuits.push(CircuitLoad {
+                    volts,
+                    placed_ids: Vec::new(),
+                    total_watts: 0.0,
+                    amps: 0.0,
+                    exceeds_15a: false,
+                    exceeds_20a: false,
+                });
+                circuits.len() - 1
+            }
+        };
+
+        let circuit = &mut circuits[index];
+        circuit.placed_ids.push(placed.id.clone());
+        circuit.total_watts += watts;
+    }
+
+    for circuit in &mut circuits {
+        circuit.amps = circuit.total_watts / circuit.volts;
+        circuit.exceeds_15a = exceeds(circuit.amps, CIRCUIT_15A);
+        circuit.exceeds_20a = exceeds(circuit.amps, CIRCUIT_20A);
+    }
+
+    PowerSummary {
+        room_id: room.id.clone(),
+        total_watts: circuits.iter().map(|c| c.total_watts).sum(),
+        circuits,
+        warnings,
+    }
+}
+
+// ============================================================================
+// Heat Load Calculation
+// ============================================================================
+
+/// Converts a room's placed equipment power draw to heat output in BTU/hr
+///
+/// Equipment without a wattage rating counts as zero and is reported in
+/// `warnings`; equipment missing from the catalog is reported and left out.
+pub fn calculate_heat_load(room: &RoomInput, catalog: &[Equipment]) -> HeatSummary {
+    let mut equipment = Vec::new();
+    let mut warnings = Vec::new();
+
+    for placed in &room.placed_equipment {
+        let Some(catalog_entry) = catalog.iter().find(|e| e.id == placed.equipment_id) else {
+            warnings.push(format!(
+                "{}: equipment {} not found in catalog",
+                placed.id, placed.equipment_id
+            ));
+            continue;
+        };
+        let watts = catalog_entry.specs.wattage.unwrap_or_else(|| {
+            warnings.push(format!(
+                "{}: no wattage rating for {} {}, counted as 0 BTU/hr",
+                placed.id, catalog_entry.manufacturer, catalog_entry.model
+            ));
+            0.0
+        });
+
+        equipment.push(EquipmentHeat {
+            placed_id: placed.id.clone(),
+            category: catalog_entry.category,
+            btu_per_hour: watts * BTU_PER_WATT,
+        });
+    }
+
+    let categories = HEAT_CATEGORIES
+        .iter()
+        .map(|category| CategoryHeat {
+            category: *category,
+            btu_per_hour: equipment
+                .iter()
+                .filter(|e| e.category == *category)
+                .map(|e| e.btu_per_hour)
+                .sum(),
+        })
+        .collect();
+
+    HeatSummary {
+        room_id: room.id.clone(),
+        total_btu_per_hour: equipment.iter().map(|e| e.btu_per_hour).sum(),
+        categories,
+        equipment,
+        warnings,
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to total a room's power draw per circuit voltage
+#[tauri::command]
+pub fn calculate_power(room: RoomInput, catalog: Vec<Equipment>) -> Result<PowerSummary, String> {
+    Ok(calculate_power_load(&room, &catalog))
+}
+
+/// Tauri command to report a room's heat output for HVAC coordination
+#[tauri::command]
+pub fn calculate_heat(room: RoomInput, catalog: Vec<Equipment>) -> Result<HeatSummary, String> {
+    Ok(calculate_heat_load(&room, &catalog))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::EquipmentSpecs;
+    use crate::drawings::test_support::{
+        self, create_test_catalog_equipment, create_test_placed_equipment,
+    };
+
+    fn create_test_equipment(id: &str, wattage: Option<f64>, voltage: Option<&str>) -> Equipment {
+        Equipment {
+            specs: EquipmentSpecs {
+                wattage,
+                voltage: voltage.map(str::to_string),
+                ..Default::default()
+            },
+            ..create_test_catalog_equipment(id, EquipmentCategory::Video, "displays")
+        }
+    }
+
+    fn create_test_room(equipment_ids: &[&str]) -> RoomInput {
+        test_support::create_test_room(
+            equipment_ids
+                .iter()
+                .enumerate()
+                .map(|(i, equipment_id)| {
+                    create_test_placed_equipment(&format!("p{}", i), equipment_id)
+                })
+                .collect(),
+        )
+    }
+
+    fn create_test_catalog() -> Vec<Equipment> {
+        vec![
+            create_test_equipment("display", Some(600.0), Some("100-240V AC")),
+            create_test_equipment("amp", Some(500.0), Some("120V")),
+            create_test_equipment("projector", Some(1150.0), Some("230V AC")),
+            create_test_equipment("unrated", None, Some("120V")),
+        ]
+    }
+
+    #[test]
+    fn test_loads_grouped_by_voltage() {
+        let room = create_test_room(&["display", "projector", "amp"]);
+
+        let summary = calculate_power_load(&room, &create_test_catalog());
+
+        assert_eq!(summary.total_watts, 2250.0);
+        let volts: Vec<f64> = summary.circuits.iter().map(|c| c.volts).collect();
+        assert_eq!(volts, vec![120.0, 230.0]);
+        assert_eq!(summary.circuits[0].placed_ids, vec!["p0", "p2"]);
+        assert_eq!(summary.circuits[0].total_watts, 1100.0);
+        assert!(summary.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_overloaded_circuit_flagged() {
+        // 3 x 600W at 120V is 15A, over the 12A continuous limit of a 15A
+        // breaker but within the 16A limit of a 20A one
+        let room = create_test_room(&["display", "display", "display"]);
+
+        let summary = calculate_power_load(&room, &create_test_catalog());
+
+        let circuit = &summary.circuits[0];
+        assert_eq!(circuit.amps, 15.0);
+        assert!(circuit.exceeds_15a);
+        assert!(!circuit.exceeds_20a);
+    }
+
+    #[test]
+    fn test_missing_wattage_reported() {
+        let room = create_test_room(&["amp", "unrated", "missing"]);
+
+        let summary = calculate_power_load(&room, &create_test_catalog());
+
+        assert_eq!(summary.total_watts, 500.0);
+        assert_eq!(summary.circuits[0].placed_ids, vec!["p0"]);
+        assert_eq!(summary.warnings.len(), 2);
+        assert!(summary.warnings[0].starts_with("p1: no wattage rating"));
+        assert!(summary.warnings[1].contains("missing not found"));
+    }
+
+    #[test]
+    fn test_heat_load_by_category() {
+        let mut catalog = create_test_catalog();
+        catalog[1].category = EquipmentCategory::Audio;
+        let room = create_test_room(&["display", "amp", "unrated"]);
+
+        let summary = calculate_heat_load(&room, &catalog);
+
+        assert!((summary.total_btu_per_hour - 1100.0 * BTU_PER_WATT).abs() < 1e-9);
+        let by_category: Vec<(EquipmentCategory, f64)> = summary
+            .categories
+            .iter()
+            .map(|c| (c.category, c.btu_per_hour))
+            .collect();
+        assert_eq!(
+            by_category,
+            vec![
+                (EquipmentCategory::Video, 600.0 * BTU_PER_WATT),
+                (EquipmentCategory::Audio, 500.0 * BTU_PER_WATT),
+                (EquipmentCategory::Control, 0.0),
+                (EquipmentCategory::Infrastructure, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heat_load_unrated_counts_as_zero() {
+        let room = create_test_room(&["unrated"]);
+
+        let summary = calculate_heat_load(&room, &create_test_catalog());
+
+        assert_eq!(summary.equipment.len(), 1);
+        assert_eq!(summary.equipment[0].btu_per_hour, 0.0);
+        assert_eq!(summary.total_btu_per_hour, 0.0);
+        assert_eq!(summary.warnings.len(), 1);
+        assert!(summary.warnings[0].starts_with("p0: no wattage rating"));
+    }
+
+    #[test]
+    fn test_circuit_volts_parsing() {
+        assert_eq!(circuit_volts(Some("230V AC")), 230.0);
+        assert_eq!(circuit_volts(Some("100-240V AC")), DEFAULT_CIRCUIT_VOLTS);
+        assert_eq!(circuit_volts(Some("AC")), DEFAULT_CIRCUIT_VOLTS);
+        assert_eq!(circuit_volts(None), DEFAULT_CIRCUIT_VOLTS);
+    }
+}
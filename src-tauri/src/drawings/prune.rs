@@ -0,0 +1,76 @@
+//! Connection Pruning
+//!
+//! Cleans up connections left dangling when the frontend deletes
+//! equipment from a diagram.
+
+use super::electrical::ElectricalDiagram;
+
+// ============================================================================
+// Connection Pruning
+// ============================================================================
+
+/// Drops the connections touching removed equipment
+///
+/// A connection is dropped when either end references one of
+/// `removed_ids`. Remaining connections keep their order, and the
+/// diagram's elements are left as they are.
+pub fn prune_removed_connections(diagram: &mut ElectricalDiagram, removed_ids: &[String]) {
+    diagram.connections.retain(|c| {
+        !removed_ids.contains(&c.from_equipment_id) && !removed_ids.contains(&c.to_equipment_id)
+    });
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to drop connections touching removed equipment
+#[tauri::command]
+pub fn prune_connections(
+    mut diagram: ElectricalDiagram,
+    removed_ids: Vec<String>,
+) -> Result<ElectricalDiagram, String> {
+    prune_removed_connections(&mut diagram, &removed_ids);
+    Ok(diagram)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{SignalConnection, SignalType};
+    use crate::drawings::test_support::{self, create_test_diagram};
+
+    fn create_test_connection(from: &str, to: &str) -> SignalConnection {
+        test_support::create_test_connection(from, to, SignalType::Video)
+    }
+
+    #[test]
+    fn test_connections_to_deleted_device_removed() {
+        let mut diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "codec-1"),
+            create_test_connection("codec-1", "display-1"),
+            create_test_connection("camera-2", "display-2"),
+        ]);
+
+        prune_removed_connections(&mut diagram, &["codec-1".to_string()]);
+
+        let ids: Vec<&str> = diagram.connections.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["conn-camera-2-display-2"]);
+    }
+
+    #[test]
+    fn test_no_removed_ids_keeps_everything() {
+        let mut diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "display-1"),
+            create_test_connection("mic-1", "dsp-1"),
+        ]);
+
+        prune_removed_connections(&mut diagram, &["speaker-9".to_string()]);
+
+        assert_eq!(diagram.connections.len(), 2);
+    }
+}
@@ -0,0 +1,427 @@
+//! Rack Packing and Elevation
+//!
+//! Assigns rack-mounted equipment to the minimum number of equipment racks
+//! using first-fit-decreasing bin packing, and lays out a room's rack
+//! equipment as a front elevation drawing.
+
+use super::electrical::{
+    DrawingElement, ElementType, EquipmentInput, MountType, PlacedEquipmentInput, RoomInput,
+};
+use serde::{Deserialize, Serialize};
+
+/// Height of one rack unit, in inches
+pub const RACK_UNIT_INCHES: f64 = 1.75;
+
+/// Fraction of a rack unit ignored when rounding a height up to whole units
+pub const RACK_UNIT_TOLERANCE: f64 = 1e-6;
+
+/// Usable height of a standard equipment rack, in rack units
+pub const STANDARD_RACK_UNITS: u32 = 42;
+
+// ============================================================================
+// Rack Device Input - from frontend
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RackDevice {
+    pub id: String,
+    pub rack_units: u32,
+}
+
+// ============================================================================
+// Rack Assignment - devices placed in a single rack
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RackAssignment {
+    pub rack_index: usize,
+    pub device_ids: Vec<String>,
+    pub used_units: u32,
+    pub free_units: u32,
+}
+
+// ============================================================================
+// Rack Elevation - front view of one rack
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RackElevation {
+    pub room_id: String,
+    /// Usable height of the rack, in rack units
+    pub rack_units: u32,
+    /// Rack units taken by the stacked equipment
+    pub used_units: u32,
+    /// One element per device, positioned in inches up from the bottom of the rack
+    pub elements: Vec<DrawingElement>,
+    /// True when the equipment needs more units than the rack has
+    pub overfilled: bool,
+    /// Placed equipment ids stacked past the top of the rack
+    pub overflow_ids: Vec<String>,
+    /// Placed equipment ids with no height, drawn as 1U
+    pub unsized_ids: Vec<String>,
+}
+
+// ============================================================================
+// Rack Packing
+// ============================================================================
+
+/// Packs devices into the minimum number of racks of the given height
+///
+/// Devices are sorted tallest first and each is placed in the first rack
+/// with enough free space, opening a new rack when none fits.
+pub fn pack_racks(
+    equipment: &[RackDevice],
+    rack_height_u: u32,
+) -> Result<Vec<RackAssignment>, String> {
+    if rack_height_u == 0 {
+        return Err("Rack height must be greater than zero".to_string());
+    }
+
+    if let Some(device) = equipment.iter().find(|d| d.rack_units > rack_height_u) {
+        return Err(format!(
+            "Device {} ({}U) does not fit in a {}U rack",
+            device.id, device.rack_units, rack_height_u
+        ));
+    }
+
+    let mut sorted: Vec<&RackDevice> = equipment.iter().collect();
+    sorted.sort_by_key(|d| std::cmp::Reverse(d.rack_units));
+
+    let mut racks: Vec<RackAssignment> = Vec::new();
+
+    for device in sorted {
+        match racks.iter_mut().find(|r| r.free_units >= device.rack_units) {
+            Some(rack) => {
+                rack.device_ids.push(device.id.clone());
+                rack.used_units += device.rack_units;
+                rack.free_units -= device.rack_units;
+            }
+            None => racks.push(RackAssignment {
+                rack_index: racks.len(),
+                device_ids: vec![device.id.clone()],
+                used_units: device.rack_units,
+                free_units: rack_height_u - device.rack_units,
+            }),
+        }
+    }
+
+    Ok(racks)
+}
+
+/// Returns the fraction of a rack's units in use, from 0.0 to 1.0
+pub fn rack_utilization(assignment: &RackAssignment) -> f32 {
+    let total_units = assignment.used_units + assignment.free_units;
+    if total_units == 0 {
+        return 0.0;
+    }
+
+    assignment.used_units as f32 / total_units as f32
+}
+
+// ============================================================================
+// Rack Elevation Generator
+// ============================================================================
+
+/// Rack units a device of the given height in inches occupies
+///
+/// Heights within `RACK_UNIT_TOLERANCE` units of a whole unit count as that
+/// unit, so heights converted from millimeters do not round up a unit.
+pub fn rack_units_for_height(height: f64) -> u32 {
+    (height / RACK_UNIT_INCHES - RACK_UNIT_TOLERANCE)
+        .ceil()
+        .max(1.0) as u32
+}
+
+/// Stacks a room's rack-mounted equipment into a standard 42U rack elevation
+///
+/// Devices are stacked from the bottom up in the order they were placed,
+/// each taking the rack units its catalog height needs. Devices that do not
+/// fit are still stacked above the rack and reported in `overflow_ids`.
+pub fn generate_rack_elevation(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+) -> RackElevation {
+    let rack_equipment = room
+        .placed_equipment
+        .iter()
+        .filter(|p| p.mount_type == MountType::Rack);
+
+    let mut elevation = RackElevation {
+        room_id: room.id.clone(),
+        rack_units: STANDARD_RACK_UNITS,
+        used_units: 0,
+        elements: Vec::new(),
+        overfilled: false,
+        overflow_ids: Vec::new(),
+        unsized_ids: Vec::new(),
+    };
+
+    for placed in rack_equipment {
+        let equipment = equipment_catalog
+            .iter()
+            .find(|e| e.id == placed.equipment_id);
+        let height = equipment.and_then(|e| e.specs.height);
+        if height.is_none() {
+            elevation.unsized_ids.push(placed.id.clone());
+        }
+
+        let units = height.map_or(1, rack_units_for_height);
+        elevation
+            .elements
+            .push(rack_element(placed, equipment, elevation.used_units, units));
+        elevation.used_units += units;
+
+        if elevation.used_units > STANDARD_RACK_UNITS {
+            elevation.overflow_ids.push(placed.id.clone());
+        }
+    }
+
+    elevation.overfilled = !elevation.overflow_ids.is_empty();
+    elevation
+}
+
+/// Builds the front-panel element for a device whose bottom sits `start` units up
+fn rack_element(
+    placed: &PlacedEquipmentInput,
+    equipment: Option<&EquipmentInput>,
+    start: u32,
+    units: u32,
+) -> DrawingElement {
+    let label = match equipment {
+        Some(eq) => format!("{} {}", eq.manufacturer, eq.model),
+        None => format!("Unknown Equipment ({})", placed.equipment_id),
+    };
+
+    DrawingElement {
+        id: format!("rack-{}", placed.id),
+        element_type: ElementType::Equipment,
+        x: 0.0,
+        y: start as f64 * RACK_UNIT_INCHES,
+        rotation: 0.0,
+        label,
+        properties: serde_json::json!({
+            "equipment_id": placed.equipment_id,
+            "start_unit": start + 1,
+            "rack_units": units,
+            "height": units as f64 * RACK_UNIT_INCHES,
+        }),
+    }
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to pack rack-mounted equipment into racks
+#[tauri::command]
+pub fn compute_rack_packing(
+    equipment: Vec<RackDevice>,
+    rack_height_u: u32,
+) -> Result<Vec<RackAssignment>, String> {
+    pack_racks(&equipment, rack_height_u)
+}
+
+/// Tauri command to compute the utilization of each packed rack
+#[tauri::command]
+pub fn compute_rack_utilization(assignments: Vec<RackAssignment>) -> Result<Vec<f32>, String> {
+    Ok(assignments.iter().map(rack_utilization).collect())
+}
+
+/// Tauri command to generate a rack elevation for a room's rack equipment
+#[tauri::command]
+pub fn generate_rack(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<RackElevation, String> {
+    Ok(generate_rack_elevation(&room, &equipment_catalog))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentCategory, EquipmentSpecs};
+    use crate::drawings::test_support::{self, create_test_placed_equipment};
+
+    fn create_test_device(id: &str, rack_units: u32) -> RackDevice {
+        RackDevice {
+            id: id.to_string(),
+            rack_units,
+        }
+    }
+
+    #[test]
+    fn test_pack_racks_empty() {
+        let racks = pack_racks(&[], 42).unwrap();
+        assert!(racks.is_empty());
+    }
+
+    #[test]
+    fn test_pack_50u_into_two_42u_racks() {
+        let equipment = vec![
+            create_test_device("ups", 4),
+            create_test_device("amp-1", 2),
+            create_test_device("server", 20),
+            create_test_device("switch", 1),
+            create_test_device("storage", 16),
+            create_test_device("codec", 2),
+            create_test_device("dsp", 1),
+            create_test_device("shelf", 4),
+        ];
+
+        let racks = pack_racks(&equipment, 42).unwrap();
+
+        assert_eq!(racks.len(), 2);
+        assert!(racks.iter().all(|r| r.used_units <= 42));
+        assert_eq!(racks.iter().map(|r| r.used_units).sum::<u32>(), 50);
+        assert_eq!(
+            racks.iter().map(|r| r.device_ids.len()).sum::<usize>(),
+            equipment.len()
+        );
+    }
+
+    #[test]
+    fn test_pack_racks_tallest_first() {
+        let equipment = vec![
+            create_test_device("small", 1),
+            create_test_device("large", 10),
+        ];
+
+        let racks = pack_racks(&equipment, 42).unwrap();
+
+        assert_eq!(racks.len(), 1);
+        assert_eq!(racks[0].device_ids, vec!["large", "small"]);
+        assert_eq!(racks[0].free_units, 31);
+    }
+
+    #[test]
+    fn test_pack_racks_device_too_tall() {
+        let equipment = vec![create_test_device("tall", 45)];
+        let result = pack_racks(&equipment, 42);
+        assert!(result.unwrap_err().contains("tall"));
+    }
+
+    #[test]
+    fn test_pack_racks_zero_height() {
+        assert!(pack_racks(&[create_test_device("amp", 2)], 0).is_err());
+    }
+
+    #[test]
+    fn test_rack_utilization_half_full() {
+        let racks = pack_racks(&[create_test_device("server", 21)], 42).unwrap();
+        assert_eq!(rack_utilization(&racks[0]), 0.5);
+    }
+
+    #[test]
+    fn test_rack_utilization_full() {
+        let equipment = vec![
+            create_test_device("server", 40),
+            create_test_device("switch", 2),
+        ];
+
+        let racks = pack_racks(&equipment, 42).unwrap();
+
+        assert_eq!(racks.len(), 1);
+        assert_eq!(rack_utilization(&racks[0]), 1.0);
+    }
+
+    fn create_test_equipment(id: &str, height: Option<f64>) -> EquipmentInput {
+        EquipmentInput {
+            specs: EquipmentSpecs {
+                height,
+                ..Default::default()
+            },
+            ..test_support::create_test_equipment(id, EquipmentCategory::Infrastructure, "racks")
+        }
+    }
+
+    fn create_test_room(placed: &[(&str, &str, MountType)]) -> RoomInput {
+        test_support::create_test_room(
+            placed
+                .iter()
+                .map(|(id, equipment_id, mount_type)| PlacedEquipmentInput {
+                    mount_type: *mount_type,
+                    ..create_test_placed_equipment(id, equipment_id)
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_rack_units_for_height() {
+        assert_eq!(rack_units_for_height(1.72), 1);
+        assert_eq!(rack_units_for_height(3.5), 2);
+        assert_eq!(rack_units_for_height(5.6), 4);
+    }
+
+    #[test]
+    fn test_rack_units_for_metric_height() {
+        assert_eq!(rack_units_for_height(44.45 / 25.4), 1);
+        assert_eq!(rack_units_for_height(88.9 / 25.4), 2);
+        assert_eq!(rack_units_for_height(177.8 / 25.4), 4);
+        assert_eq!(rack_units_for_height(45.0 / 25.4), 2);
+    }
+
+    #[test]
+    fn test_rack_elevation_stacks_from_bottom() {
+        let catalog = vec![
+            create_test_equipment("amp", Some(3.5)),
+            create_test_equipment("dsp", Some(1.75)),
+            create_test_equipment("display", Some(30.0)),
+        ];
+        let room = create_test_room(&[
+            ("p1", "amp", MountType::Rack),
+            ("p2", "display", MountType::Wall),
+            ("p3", "dsp", MountType::Rack),
+        ]);
+
+        let elevation = generate_rack_elevation(&room, &catalog);
+
+        assert_eq!(elevation.rack_units, STANDARD_RACK_UNITS);
+        assert_eq!(elevation.used_units, 3);
+        assert!(!elevation.overfilled);
+        let positions: Vec<(&str, f64)> = elevation
+            .elements
+            .iter()
+            .map(|e| (e.id.as_str(), e.y))
+            .collect();
+        assert_eq!(positions, vec![("rack-p1", 0.0), ("rack-p3", 3.5)]);
+        assert_eq!(elevation.elements[1].properties["start_unit"], 3);
+    }
+
+    #[test]
+    fn test_rack_elevation_flags_overfill() {
+        let catalog = vec![create_test_equipment(
+            "server",
+            Some(20.0 * RACK_UNIT_INCHES),
+        )];
+        let room = create_test_room(&[
+            ("p1", "server", MountType::Rack),
+            ("p2", "server", MountType::Rack),
+            ("p3", "server", MountType::Rack),
+        ]);
+
+        let elevation = generate_rack_elevation(&room, &catalog);
+
+        assert!(elevation.overfilled);
+        assert_eq!(elevation.used_units, 60);
+        assert_eq!(elevation.overflow_ids, vec!["p3"]);
+    }
+
+    #[test]
+    fn test_rack_elevation_unsized_equipment_takes_one_unit() {
+        let catalog = vec![create_test_equipment("shelf", None)];
+        let room = create_test_room(&[("p1", "shelf", MountType::Rack)]);
+
+        let elevation = generate_rack_elevation(&room, &catalog);
+
+        assert_eq!(elevation.used_units, 1);
+        assert_eq!(elevation.unsized_ids, vec!["p1"]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Reflected Ceiling Plan Generator
+//!
+//! Draws the ceiling tile grid and the ceiling-mounted devices of a room,
+//! with each device snapped to the nearest grid intersection.
+
+use super::electrical::{
+    normalize_rotation, DrawingElement, ElementType, EquipmentInput, MountType, RoomInput,
+};
+use serde::{Deserialize, Serialize};
+
+/// Standard 2ft x 2ft acoustic ceiling tile, in room units (feet)
+pub const DEFAULT_TILE_SIZE: f64 = 2.0;
+
+/// Upper bound on grid lines to keep plans small enough to draw
+const MAX_GRID_LINES: usize = 10_000;
+
+// ============================================================================
+// Reflected Ceiling Plan - grid and ceiling devices
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridLine {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReflectedCeilingPlan {
+    pub room_id: String,
+    pub tile_size: f64,
+    pub grid_lines: Vec<GridLine>,
+    pub elements: Vec<DrawingElement>,
+}
+
+// ============================================================================
+// Reflected Ceiling Plan Generator
+// ============================================================================
+
+/// Grid positions from 0 up to `extent`, one per tile
+fn grid_positions(extent: f64, tile_size: f64) -> impl Iterator<Item = f64> {
+    let count = (extent / tile_size).floor() as usize;
+    (0..=count).map(move |i| i as f64 * tile_size)
+}
+
+/// Snaps a coordinate to the nearest grid position inside the room
+fn snap_to_tile(value: f64, extent: f64, tile_size: f64) -> f64 {
+    let last = (extent / tile_size).floor();
+    (value / tile_size).round().clamp(0.0, last) * tile_size
+}
+
+/// Generates a reflected ceiling plan of a room's ceiling-mounted equipment
+///
+/// Grid lines run the full width and length of the room, one per tile
+/// starting from the origin corner. Equipment mounted anywhere other than
+/// the ceiling is left off the plan. A tile size so small that the grid
+/// would need more than `MAX_GRID_LINES` lines is rejected.
+pub fn reflected_ceiling_plan(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+    tile_size: f64,
+) -> Result<ReflectedCeilingPlan, String> {
+    if !tile_size.is_finite() || tile_size <= 0.0 {
+        return Err("Tile size must be greater than zero".to_string());
+    }
+    if !(room.width.is_finite() && room.length.is_finite())
+        || room.width <= 0.0
+        || room.length <= 0.0
+    {
+        return Err("Room dimensions must be greater than zero".to_string());
+    }
+
+    let line_count = (room.width / tile_size).floor() + (room.length / tile_size).floor() + 2.0;
+    if line_count > MAX_GRID_LINES as f64 {
+        return Err(format!(
+            "Tile size {} is too small for a {} x {} room",
+            tile_size, room.width, room.length
+        ));
+    }
+
+    let vertical = grid_positions(room.width, tile_size).map(|x| GridLine {
+        x1: x,
+        y1: 0.0,
+        x2: x,
+        y2: room.length,
+    });
+    let horizontal = grid_positions(room.length, tile_size).map(|y| GridLine {
+        x1: 0.0,
+        y1: y,
+        x2: room.width,
+        y2: y,
+    });
+    let grid_lines = vertical.chain(horizontal).collect();
+
+    let elements = room
+        .placed_equipment
+        .iter()
+        .filter(|p| p.mount_type == MountType::Ceiling)
+        .map(|placed| {
+            let equipment = equipment_catalog
+                .iter()
+                .find(|e| e.id == placed.equipment_id);
+
+            let label = match equipment {
+                Some(eq) => format!("{} {}", eq.manufacturer, eq.model),
+                None => format!("Unknown Equipment ({})", placed.equipment_id),
+            };
+
+            DrawingElement {
+                id: format!("rcp-{}", placed.id),
+                element_type: ElementType::Symbol,
+                x: snap_to_tile(placed.x, room.width, tile_size),
+                y: snap_to_tile(placed.y, room.length, tile_size),
+                rotation: normalize_rotation(placed.rotation),
+                label,
+                properties: serde_json::json!({
+                    "equipment_id": placed.equipment_id,
+                    "category": equipment.map(|eq| eq.category),
+                    "subcategory": equipment.map(|eq| eq.subcategory.as_str()),
+                }),
+            }
+        })
+        .collect();
+
+    Ok(ReflectedCeilingPlan {
+        room_id: room.id.clone(),
+        tile_size,
+        grid_lines,
+        elements,
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to generate a reflected ceiling plan, on a 2ft grid by default
+#[tauri::command]
+pub fn generate_rcp(
+    room: RoomInput,
+    equipment_catalog: Vec<EquipmentInput>,
+    tile_size: Option<f64>,
+) -> Result<ReflectedCeilingPlan, String> {
+    reflected_ceiling_plan(
+        &room,
+        &equipment_catalog,
+        tile_size.unwrap_or(DEFAULT_TILE_SIZE),
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{EquipmentCategory, PlacedEquipmentInput};
+    use crate::drawings::test_support::{self, create_test_placed_equipment};
+
+    fn create_test_equipment(id: &str, subcategory: &str) -> EquipmentInput {
+        test_support::create_test_equipment(id, EquipmentCategory::Audio, subcategory)
+    }
+
+    fn create_test_placed(
+        id: &str,
+        equipment_id: &str,
+        x: f64,
+        y: f64,
+        mount_type: MountType,
+    ) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            x,
+            y,
+            mount_type,
+            ..create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    fn create_test_room(placed_equipment: Vec<PlacedEquipmentInput>) -> RoomInput {
+        RoomInput {
+            width: 12.0,
+            length: 9.0,
+            ..test_support::create_test_room(placed_equipment)
+        }
+    }
+
+    fn create_test_catalog() -> Vec<EquipmentInput> {
+        vec![
+            create_test_equipment("speaker", "speakers"),
+            create_test_equipment("mic", "microphones"),
+            create_test_equipment("dsp", "dsp"),
+        ]
+    }
+
+    #[test]
+    fn test_floor_mounted_equipment_excluded() {
+        let room = create_test_room(vec![
+            create_test_placed("p1", "speaker", 4.0, 4.0, MountType::Ceiling),
+            create_test_placed("p2", "dsp", 1.0, 1.0, MountType::Floor),
+            create_test_placed("p3", "mic", 6.0, 4.0, MountType::Ceiling),
+            create_test_placed("p4", "speaker", 0.0, 4.0, MountType::Wall),
+        ]);
+
+        let plan =
+            reflected_ceiling_plan(&room, &create_test_catalog(), DEFAULT_TILE_SIZE).unwrap();
+
+        let ids: Vec<&str> = plan.elements.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["rcp-p1", "rcp-p3"]);
+    }
+
+    #[test]
+    fn test_devices_snap_to_nearest_intersection() {
+        let room = create_test_room(vec![
+            create_test_placed("p1", "speaker", 4.9, 3.2, MountType::Ceiling),
+            create_test_placed("p2", "mic", 11.9, 8.9, MountType::Ceiling),
+        ]);
+
+        let plan =
+            reflected_ceiling_plan(&room, &create_test_catalog(), DEFAULT_TILE_SIZE).unwrap();
+
+        let positions: Vec<(f64, f64)> = plan.elements.iter().map(|e| (e.x, e.y)).collect();
+        // The second device snaps inside the room rather than past the 9ft wall
+        assert_eq!(positions, vec![(4.0, 4.0), (12.0, 8.0)]);
+    }
+
+    #[test]
+    fn test_grid_lines_follow_tile_size() {
+        let room = create_test_room(Vec::new());
+
+        let plan = reflected_ceiling_plan(&room, &[], 3.0).unwrap();
+
+        // 12ft wide gives 5 vertical lines, 9ft long gives 4 horizontal lines
+        assert_eq!(plan.grid_lines.len(), 9);
+        assert_eq!(
+            plan.grid_lines[4],
+            GridLine {
+                x1: 12.0,
+                y1: 0.0,
+                x2: 12.0,
+                y2: 9.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_zero_tile_size_rejected() {
+        let room = create_test_room(Vec::new());
+        assert!(reflected_ceiling_plan(&room, &[], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_tiny_tile_size_rejected() {
+        let room = create_test_room(Vec::new());
+        assert!(reflected_ceiling_plan(&room, &[], 1e-9).is_err());
+        assert!(reflected_ceiling_plan(&room, &[], f64::MIN_POSITIVE).is_err());
+
+        let huge = RoomInput {
+            width: f64::INFINITY,
+            ..create_test_room(Vec::new())
+        };
+        assert!(reflected_ceiling_plan(&huge, &[], DEFAULT_TILE_SIZE).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! Signal Reachability
+//!
+//! Follows directed connections from a root device to list everything
+//! downstream of it, for commissioning checks.
+
+use super::electrical::ElectricalDiagram;
+use std::collections::{HashSet, VecDeque};
+
+// ============================================================================
+// Reachability
+// ============================================================================
+
+/// Returns the equipment ids reachable from `root_id` along connections
+///
+/// Connections are followed from their source to their destination only.
+/// Ids are listed breadth-first in connection order, once each, and the
+/// root itself is not included.
+pub fn reachable_from(diagram: &ElectricalDiagram, root_id: &str) -> Vec<String> {
+    let mut visited: HashSet<&str> = HashSet::from([root_id]);
+    let mut queue: VecDeque<&str> = VecDeque::from([root_id]);
+    let mut reachable = Vec::new();
+
+    while let Some(current) = queue.pop_front() {
+        for connection in diagram
+            .connections
+            .iter()
+            .filter(|c| c.from_equipment_id == current)
+        {
+            let next = connection.to_equipment_id.as_str();
+            if visited.insert(next) {
+                reachable.push(next.to_string());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    reachable
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list the equipment downstream of a root device
+#[tauri::command]
+pub fn compute_reachable_from(
+    diagram: ElectricalDiagram,
+    root_id: String,
+) -> Result<Vec<String>, String> {
+    Ok(reachable_from(&diagram, &root_id))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::SignalType;
+    use crate::drawings::test_support::{create_test_connection, create_test_diagram};
+
+    #[test]
+    fn test_source_reaches_two_displays() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("camera-1", "switcher-1", SignalType::Video),
+            create_test_connection("switcher-1", "display-1", SignalType::Video),
+            create_test_connection("switcher-1", "display-2", SignalType::Video),
+            create_test_connection("camera-2", "switcher-1", SignalType::Video),
+        ]);
+
+        let reachable = reachable_from(&diagram, "camera-1");
+
+        assert_eq!(reachable, vec!["switcher-1", "display-1", "display-2"]);
+    }
+
+    #[test]
+    fn test_control_device_reaches_targets() {
+        let diagram = create_test_diagram(vec![
+            create_test_connection("proc-1", "display-1", SignalType::Control),
+            create_test_connection("proc-1", "camera-1", SignalType::Control),
+            create_test_connection("camera-1", "proc-1", SignalType::Video),
+            create_test_connection("mic-1", "dsp-1", SignalType::Audio),
+        ]);
+
+        let reachable = reachable_from(&diagram, "proc-1");
+
+        assert_eq!(reachable, vec!["display-1", "camera-1"]);
+    }
+}
@@ -0,0 +1,112 @@
+//! Room Zone Coverage
+//!
+//! Finds placed equipment that sits outside every functional zone defined
+//! for a room.
+
+use super::electrical::RoomInput;
+
+/// Returns ids of placed equipment not inside any of the room's zones
+///
+/// When the room defines no zones, `orphan_all_when_no_zones` decides
+/// whether every device is reported as an orphan or none are.
+pub fn orphan_equipment(room: &RoomInput, orphan_all_when_no_zones: bool) -> Vec<String> {
+    if room.zones.is_empty() && !orphan_all_when_no_zones {
+        return Vec::new();
+    }
+
+    room.placed_equipment
+        .iter()
+        .filter(|placed| {
+            !room
+                .zones
+                .iter()
+                .any(|zone| zone.contains(placed.x, placed.y))
+        })
+        .map(|placed| placed.id.clone())
+        .collect()
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to list equipment placed outside every room zone
+#[tauri::command]
+pub fn find_orphan_equipment(
+    room: RoomInput,
+    orphan_all_when_no_zones: Option<bool>,
+) -> Result<Vec<String>, String> {
+    Ok(orphan_equipment(
+        &room,
+        orphan_all_when_no_zones.unwrap_or(false),
+    ))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::electrical::{PlacedEquipmentInput, Rect};
+    use crate::drawings::test_support;
+
+    fn create_test_placed_equipment(id: &str, x: f64, y: f64) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            x,
+            y,
+            ..test_support::create_test_placed_equipment(id, "eq-1")
+        }
+    }
+
+    fn create_test_room(
+        placed_equipment: Vec<PlacedEquipmentInput>,
+        zones: Vec<Rect>,
+    ) -> RoomInput {
+        RoomInput {
+            zones,
+            ..test_support::create_test_room(placed_equipment)
+        }
+    }
+
+    fn presenter_zone() -> Rect {
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 5.0,
+        }
+    }
+
+    #[test]
+    fn test_device_inside_zone_is_not_orphan() {
+        let room = create_test_room(
+            vec![create_test_placed_equipment("p-inside", 5.0, 2.0)],
+            vec![presenter_zone()],
+        );
+
+        assert!(orphan_equipment(&room, false).is_empty());
+    }
+
+    #[test]
+    fn test_device_outside_zone_is_orphan() {
+        let room = create_test_room(
+            vec![
+                create_test_placed_equipment("p-inside", 5.0, 2.0),
+                create_test_placed_equipment("p-outside", 15.0, 12.0),
+            ],
+            vec![presenter_zone()],
+        );
+
+        assert_eq!(orphan_equipment(&room, false), vec!["p-outside"]);
+    }
+
+    #[test]
+    fn test_no_zones_is_configurable() {
+        let room = create_test_room(vec![create_test_placed_equipment("p-1", 5.0, 2.0)], vec![]);
+
+        assert!(orphan_equipment(&room, false).is_empty());
+        assert_eq!(orphan_equipment(&room, true), vec!["p-1"]);
+    }
+}
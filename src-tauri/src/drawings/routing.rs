@@ -0,0 +1,362 @@
+//! Cable Tray Routing
+//!
+//! Finds an orthogonal cable-tray route between two points that avoids
+//! obstacle rectangles, using A* search over a regular grid.
+
+use super::electrical::Rect;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Grid spacing for tray routing, in room units
+pub const TRAY_GRID_SIZE: f64 = 1.0;
+
+/// Free grid cells kept around the start, end and obstacles
+const GRID_MARGIN_CELLS: usize = 2;
+
+/// Upper bound on grid size to keep routing responsive
+const MAX_GRID_NODES: usize = 1_000_000;
+
+/// Routing grid anchored so that the start point lies on a grid node
+struct Grid<'a> {
+    origin_x: f64,
+    origin_y: f64,
+    columns: usize,
+    rows: usize,
+    obstacles: &'a [Rect],
+}
+
+impl Grid<'_> {
+    fn point(&self, node: usize) -> (f64, f64) {
+        let column = node % self.columns;
+        let row = node / self.columns;
+        (
+            self.origin_x + column as f64 * TRAY_GRID_SIZE,
+            self.origin_y + row as f64 * TRAY_GRID_SIZE,
+        )
+    }
+
+    fn node_nearest(&self, (x, y): (f64, f64)) -> usize {
+        let column = ((x - self.origin_x) / TRAY_GRID_SIZE).round().max(0.0) as usize;
+        let row = ((y - self.origin_y) / TRAY_GRID_SIZE).round().max(0.0) as usize;
+        row.min(self.rows - 1) * self.columns + column.min(self.columns - 1)
+    }
+
+    fn is_blocked(&self, x: f64, y: f64) -> bool {
+        self.obstacles.iter().any(|r| r.contains_interior(x, y))
+    }
+
+    /// Orthogonal neighbours reachable without crossing an obstacle
+    fn neighbours(&self, node: usize) -> Vec<usize> {
+        let column = node % self.columns;
+        let row = node / self.columns;
+        let mut candidates = Vec::with_capacity(4);
+
+        if column > 0 {
+            candidates.push(node - 1);
+        }
+        if column + 1 < self.columns {
+            candidates.push(node + 1);
+        }
+        if row > 0 {
+            candidates.push(node - self.columns);
+        }
+        if row + 1 < self.rows {
+            candidates.push(node + self.columns);
+        }
+
+        let (x, y) = self.point(node);
+        candidates
+            .into_iter()
+            .filter(|next| {
+                let (nx, ny) = self.point(*next);
+                // Check the midpoint too so thin obstacles are not stepped over
+                !self.is_blocked(nx, ny) && !self.is_blocked((x + nx) / 2.0, (y + ny) / 2.0)
+            })
+            .collect()
+    }
+
+    /// Corner joining `from` to `to` with one horizontal and one vertical leg
+    ///
+    /// Turns horizontally first unless that leg is blocked and the
+    /// vertical-first corner is clear.
+    fn elbow(&self, from: (f64, f64), to: (f64, f64)) -> (f64, f64) {
+        let leg_clear = |a: (f64, f64), b: (f64, f64)| {
+            !self.is_blocked(b.0, b.1) && !self.is_blocked((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+        };
+        let horizontal_first = (to.0, from.1);
+        let vertical_first = (from.0, to.1);
+
+        let horizontal_clear = leg_clear(from, horizontal_first) && leg_clear(horizontal_first, to);
+        let vertical_clear = leg_clear(from, vertical_first) && leg_clear(vertical_first, to);
+        if !horizontal_clear && vertical_clear {
+            vertical_first
+        } else {
+            horizontal_first
+        }
+    }
+
+    fn manhattan(&self, a: usize, b: usize) -> usize {
+        let (ac, ar) = (a % self.columns, a / self.columns);
+        let (bc, br) = (b % self.columns, b / self.columns);
+        ac.abs_diff(bc) + ar.abs_diff(br)
+    }
+}
+
+/// Appends a waypoint, skipping repeats and merging straight runs
+fn push_waypoint(waypoints: &mut Vec<(f64, f64)>, point: (f64, f64)) {
+    if waypoints.last() == Some(&point) {
+        return;
+    }
+
+    let straight = match waypoints.as_slice() {
+        [.., a, b] => (a.0 == b.0 && b.0 == point.0) || (a.1 == b.1 && b.1 == point.1),
+        _ => false,
+    };
+    if straight {
+        waypoints.pop();
+    }
+    waypoints.push(point);
+}
+
+/// Computes a cable-tray route from `from` to `to` avoiding obstacles
+///
+/// Returns the route as waypoints, starting at `from` and ending at `to`,
+/// with a waypoint at every change of direction. Every segment is
+/// horizontal or vertical; an off-grid `to` is reached from the last grid
+/// node with a dogleg. Coordinates must be finite, and routes spanning
+/// more than `MAX_GRID_NODES` grid nodes are rejected.
+pub fn tray_route(
+    from: (f64, f64),
+    to: (f64, f64),
+    obstacles: &[Rect],
+) -> Result<Vec<(f64, f64)>, String> {
+    let finite = [from.0, from.1, to.0, to.1]
+        .into_iter()
+        .chain(obstacles.iter().flat_map(|r| [r.x, r.y, r.width, r.height]))
+        .all(f64::is_finite);
+    if !finite {
+        return Err("Route coordinates must be finite".to_string());
+    }
+
+    if obstacles
+        .iter()
+        .any(|r| r.contains_interior(from.0, from.1) || r.contains_interior(to.0, to.1))
+    {
+        return Err("Route endpoints must not lie inside an obstacle".to_string());
+    }
+
+    let margin = GRID_MARGIN_CELLS as f64 * TRAY_GRID_SIZE;
+    let mut min_x = from.0.min(to.0);
+    let mut min_y = from.1.min(to.1);
+    let mut max_x = from.0.max(to.0);
+    let mut max_y = from.1.max(to.1);
+    for r in obstacles {
+        min_x = min_x.min(r.x);
+        min_y = min_y.min(r.y);
+        max_x = max_x.max(r.x + r.width);
+        max_y = max_y.max(r.y + r.height);
+    }
+
+    let origin_x = from.0 - ((from.0 - min_x + margin) / TRAY_GRID_SIZE).ceil() * TRAY_GRID_SIZE;
+    let origin_y = from.1 - ((from.1 - min_y + margin) / TRAY_GRID_SIZE).ceil() * TRAY_GRID_SIZE;
+    // Sized in floating point so huge extents fail the bound instead of
+    // wrapping when converted to node counts
+    let columns = ((max_x + margin - origin_x) / TRAY_GRID_SIZE).ceil() + 1.0;
+    let rows = ((max_y + margin - origin_y) / TRAY_GRID_SIZE).ceil() + 1.0;
+
+    if columns * rows > MAX_GRID_NODES as f64 {
+        return Err("Routing area is too large".to_string());
+    }
+    let (columns, rows) = (columns as usize, rows as usize);
+
+    let grid = Grid {
+        origin_x,
+        origin_y,
+        columns,
+        rows,
+        obstacles,
+    };
+
+    let start = grid.node_nearest(from);
+    let goal = grid.node_nearest(to);
+
+    let mut came_from: Vec<Option<usize>> = vec![None; columns * rows];
+    let mut cost: Vec<usize> = vec![usize::MAX; columns * rows];
+    let mut open = BinaryHeap::new();
+
+    cost[start] = 0;
+    open.push(Reverse((grid.manhattan(start, goal), 0usize, start)));
+
+    while let Some(Reverse((_, g, node))) = open.pop() {
+        if node == goal {
+            break;
+        }
+        if g > cost[node] {
+            continue;
+        }
+
+        for next in grid.neighbours(node) {
+            let next_cost = g + 1;
+            if next_cost < cost[next] {
+                cost[next] = next_cost;
+                came_from[next] = Some(node);
+                open.push(Reverse((
+                    next_cost + grid.manhattan(next, goal),
+                    next_cost,
+                    next,
+                )));
+            }
+        }
+    }
+
+    if cost[goal] == usize::MAX {
+        return Err("No tray route found between the given points".to_string());
+    }
+
+    let mut nodes = vec![goal];
+    let mut current = goal;
+    while let Some(previous) = came_from[current] {
+        nodes.push(previous);
+        current = previous;
+    }
+    nodes.reverse();
+
+    let points: Vec<(f64, f64)> = nodes.iter().map(|n| grid.point(*n)).collect();
+    let mut waypoints = vec![from];
+
+    // Keep only the corners of the grid path
+    for window in points.windows(3) {
+        let (a, b, c) = (window[0], window[1], window[2]);
+        let straight = (a.0 == b.0 && b.0 == c.0) || (a.1 == b.1 && b.1 == c.1);
+        if !straight {
+            waypoints.push(b);
+        }
+    }
+
+    let last = grid.point(goal);
+    for point in [last, grid.elbow(last, to), to] {
+        push_waypoint(&mut waypoints, point);
+    }
+
+    Ok(waypoints)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to compute a cable-tray route around obstacles
+#[tauri::command]
+pub fn compute_tray_route(
+    from: (f64, f64),
+    to: (f64, f64),
+    obstacles: Vec<Rect>,
+) -> Result<Vec<(f64, f64)>, String> {
+    tray_route(from, to, &obstacles)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns true if any sampled point on the route is inside an obstacle
+    fn route_crosses(route: &[(f64, f64)], obstacles: &[Rect]) -> bool {
+        route.windows(2).any(|segment| {
+            let (a, b) = (segment[0], segment[1]);
+            (0..=100).any(|step| {
+                let t = step as f64 / 100.0;
+                let x = a.0 + (b.0 - a.0) * t;
+                let y = a.1 + (b.1 - a.1) * t;
+                obstacles.iter().any(|r| r.contains_interior(x, y))
+            })
+        })
+    }
+
+    #[test]
+    fn test_clear_straight_route() {
+        let route = tray_route((0.0, 0.0), (10.0, 0.0), &[]).unwrap();
+        assert_eq!(route, vec![(0.0, 0.0), (10.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_route_detours_around_central_obstacle() {
+        let obstacles = vec![Rect {
+            x: 4.0,
+            y: -3.0,
+            width: 2.0,
+            height: 6.0,
+        }];
+
+        let route = tray_route((0.0, 0.0), (10.0, 0.0), &obstacles).unwrap();
+
+        assert!(route.len() > 2);
+        assert_eq!(route.first(), Some(&(0.0, 0.0)));
+        assert_eq!(route.last(), Some(&(10.0, 0.0)));
+        assert!(is_orthogonal(&route));
+        assert!(!route_crosses(&route, &obstacles));
+    }
+
+    fn is_orthogonal(route: &[(f64, f64)]) -> bool {
+        route
+            .windows(2)
+            .all(|segment| segment[0].0 == segment[1].0 || segment[0].1 == segment[1].1)
+    }
+
+    #[test]
+    fn test_off_grid_endpoint_reached_with_dogleg() {
+        let route = tray_route((0.0, 0.0), (10.3, 0.4), &[]).unwrap();
+        assert_eq!(route, vec![(0.0, 0.0), (10.3, 0.0), (10.3, 0.4)]);
+    }
+
+    #[test]
+    fn test_dogleg_turns_away_from_obstacle() {
+        let obstacles = vec![Rect {
+            x: 10.2,
+            y: -0.3,
+            width: 1.0,
+            height: 0.5,
+        }];
+
+        let route = tray_route((0.0, 0.0), (10.4, 0.4), &obstacles).unwrap();
+
+        assert_eq!(route.last(), Some(&(10.4, 0.4)));
+        assert!(is_orthogonal(&route));
+        assert!(!route_crosses(&route, &obstacles));
+    }
+
+    #[test]
+    fn test_endpoint_inside_obstacle_rejected() {
+        let obstacles = vec![Rect {
+            x: -1.0,
+            y: -1.0,
+            width: 2.0,
+            height: 2.0,
+        }];
+
+        assert!(tray_route((0.0, 0.0), (10.0, 0.0), &obstacles).is_err());
+    }
+
+    #[test]
+    fn test_non_finite_coordinates_rejected() {
+        assert!(tray_route((f64::NAN, 0.0), (10.0, 0.0), &[]).is_err());
+        assert!(tray_route((0.0, 0.0), (f64::INFINITY, 0.0), &[]).is_err());
+
+        let obstacles = vec![Rect {
+            x: 4.0,
+            y: 0.0,
+            width: f64::INFINITY,
+            height: 1.0,
+        }];
+        assert!(tray_route((0.0, 0.0), (10.0, 0.0), &obstacles).is_err());
+    }
+
+    #[test]
+    fn test_huge_routing_area_rejected() {
+        assert!(tray_route((-1e300, 0.0), (1e300, 0.0), &[]).is_err());
+        assert!(tray_route((0.0, 0.0), (1e6, 1e6), &[]).is_err());
+    }
+}
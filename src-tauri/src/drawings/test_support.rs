@@ -0,0 +1,98 @@
+//! Test Support
+//!
+//! Fixture builders shared by the drawing, export and standards tests.
+//! Builders fill in the required fields and leave the rest at their
+//! defaults; tests override what they exercise with struct update syntax.
+
+use super::electrical::{
+    ElectricalDiagram, EquipmentCategory, EquipmentInput, PlacedEquipmentInput, RoomInput,
+    SignalConnection, SignalType,
+};
+use crate::database::Equipment;
+
+/// Builds a catalog device with a generated manufacturer and model
+pub(crate) fn create_test_equipment(
+    id: &str,
+    category: EquipmentCategory,
+    subcategory: &str,
+) -> EquipmentInput {
+    EquipmentInput {
+        id: id.to_string(),
+        manufacturer: "Test Manufacturer".to_string(),
+        model: format!("Model {}", id),
+        category,
+        subcategory: subcategory.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Builds a catalog record with a generated manufacturer and model
+pub(crate) fn create_test_catalog_equipment(
+    id: &str,
+    category: EquipmentCategory,
+    subcategory: &str,
+) -> Equipment {
+    Equipment {
+        id: id.to_string(),
+        manufacturer: "Test Manufacturer".to_string(),
+        model: format!("Model {}", id),
+        sku: String::new(),
+        category,
+        subcategory: subcategory.to_string(),
+        description: None,
+        cost: None,
+        msrp: None,
+        weight: None,
+        certifications: Vec::new(),
+        specs: Default::default(),
+    }
+}
+
+/// Builds a floor-mounted placement of a catalog device at the room origin
+pub(crate) fn create_test_placed_equipment(id: &str, equipment_id: &str) -> PlacedEquipmentInput {
+    PlacedEquipmentInput {
+        id: id.to_string(),
+        equipment_id: equipment_id.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Builds a 20 x 20 room with a 10 ft ceiling holding the given placements
+pub(crate) fn create_test_room(placed_equipment: Vec<PlacedEquipmentInput>) -> RoomInput {
+    RoomInput {
+        id: "room-1".to_string(),
+        name: "Test Room".to_string(),
+        width: 20.0,
+        length: 20.0,
+        ceiling_height: 10.0,
+        placed_equipment,
+        ..Default::default()
+    }
+}
+
+/// Builds an HDMI connection whose id is derived from its two ends
+pub(crate) fn create_test_connection(
+    from: &str,
+    to: &str,
+    signal_type: SignalType,
+) -> SignalConnection {
+    SignalConnection {
+        id: format!("conn-{}-{}", from, to),
+        from_equipment_id: from.to_string(),
+        to_equipment_id: to.to_string(),
+        signal_type,
+        cable_type: "HDMI".to_string(),
+        label: None,
+        length_estimate: None,
+    }
+}
+
+/// Builds a diagram of "room-1" with no elements and the given connections
+pub(crate) fn create_test_diagram(connections: Vec<SignalConnection>) -> ElectricalDiagram {
+    ElectricalDiagram {
+        room_id: "room-1".to_string(),
+        connections,
+        generated_at: "2026-01-18T12:00:00Z".to_string(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,288 @@
+//! Bill of Materials
+//!
+//! Builds a priced bill of materials for a room from the equipment catalog,
+//! with the estimated cable quantities from its cable schedule.
+
+use crate::database::Equipment;
+use crate::drawings::{
+    cable_schedule, generate_electrical_diagram, EquipmentInput, RoomInput, RoutingRules,
+};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Bill of Materials - priced quantity lines for one room
+// ============================================================================
+
+/// What a bill of materials line counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BomItemKind {
+    /// Placed equipment, counted each
+    Equipment,
+    /// Bulk cable of one type, counted in feet
+    Cable,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillOfMaterialsLine {
+    pub kind: BomItemKind,
+    /// Equipment id, or cable type for cable lines
+    pub item_id: String,
+    pub manufacturer: String,
+    pub model: String,
+    /// Units for equipment, whole feet for cable
+    pub quantity: u32,
+    pub unit_cost: Option<f64>,
+    pub unit_msrp: Option<f64>,
+    pub extended_cost: Option<f64>,
+    pub extended_msrp: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillOfMaterials {
+    pub room_id: String,
+    pub lines: Vec<BillOfMaterialsLine>,
+    /// Sum of the extended costs that are known
+    pub total_cost: f64,
+    /// Sum of the extended MSRPs that are known
+    pub total_msrp: f64,
+}
+
+// ============================================================================
+// Bill of Materials Generation
+// ============================================================================
+
+/// Groups placed equipment into priced quantity lines, ordered by first
+/// placement
+///
+/// Equipment missing from the catalog is listed by id with no prices.
+pub fn equipment_lines(room: &RoomInput, catalog: &[Equipment]) -> Vec<BillOfMaterialsLine> {
+    let mut lines: Vec<BillOfMaterialsLine> = Vec::new();
+
+    for placed in &room.placed_equipment {
+        if let Some(line) = lines.iter_mut().find(|l| l.item_id == placed.equipment_id) {
+            line.quantity += 1;
+            let quantity = f64::from(line.quantity);
+            line.extended_cost = line.unit_cost.map(|cost| cost * quantity);
+            line.extended_msrp = line.unit_msrp.map(|msrp| msrp * quantity);
+            continue;
+        }
+
+        let equipment = catalog.iter().find(|e| e.id == placed.equipment_id);
+        lines.push(BillOfMaterialsLine {
+            kind: BomItemKind::Equipment,
+            item_id: placed.equipment_id.clone(),
+            manufacturer: equipment
+                .map(|e| e.manufacturer.clone())
+                .unwrap_or_default(),
+            model: equipment
+                .map(|e| e.model.clone())
+                .unwrap_or_else(|| placed.equipment_id.clone()),
+            quantity: 1,
+            unit_cost: equipment.and_then(|e| e.cost),
+            unit_msrp: equipment.and_then(|e| e.msrp),
+            extended_cost: equipment.and_then(|e| e.cost),
+            extended_msrp: equipment.and_then(|e| e.msrp),
+        });
+    }
+
+    lines
+}
+
+/// Totals the room's estimated cable runs into one line per cable type
+///
+/// Lengths come from the cable schedule of the room's generated diagram and
+/// are rounded up to whole feet. Runs without an estimated length are left
+/// out of the quantity.
+fn cable_lines(
+    room: &RoomInput,
+    catalog: &[Equipment],
+) -> Result<Vec<BillOfMaterialsLine>, String> {
+    let equipment_catalog: Vec<EquipmentInput> = catalog.iter().map(EquipmentInput::from).collect();
+    let diagram = generate_electrical_diagram(room, &equipment_catalog, &RoutingRules::default())?;
+
+    let mut lengths: Vec<(String, f64)> = Vec::new();
+    for row in cable_schedule(&diagram).rows {
+        let length = row.estimated_length.unwrap_or(0.0);
+        match lengths
+            .iter_mut()
+            .find(|(cable_type, _)| *cable_type == row.cable_type)
+        {
+            Some((_, total)) => *total += length,
+            None => lengths.push((row.cable_type, length)),
+        }
+    }
+
+    Ok(lengths
+        .into_iter()
+        .map(|(cable_type, feet)| BillOfMaterialsLine {
+            kind: BomItemKind::Cable,
+            item_id: cable_type.clone(),
+            manufacturer: String::new(),
+            model: cable_type,
+            quantity: feet.ceil() as u32,
+            unit_cost: None,
+            unit_msrp: None,
+            extended_cost: None,
+            extended_msrp: None,
+        })
+        .collect())
+}
+
+/// Builds a room's bill of materials
+///
+/// Placed equipment is grouped into one line per equipment id, priced from
+/// the catalog, followed by one line of estimated bulk cable per cable
+/// type. Cable lines carry no prices. Totals sum the known extended prices.
+pub fn bill_of_materials(
+    room: &RoomInput,
+    catalog: &[Equipment],
+) -> Result<BillOfMaterials, String> {
+    let mut lines = equipment_lines(room, catalog);
+    lines.extend(cable_lines(room, catalog)?);
+
+    Ok(BillOfMaterials {
+        room_id: room.id.clone(),
+        total_cost: lines.iter().filter_map(|l| l.extended_cost).sum(),
+        total_msrp: lines.iter().filter_map(|l| l.extended_msrp).sum(),
+        lines,
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to build a room's bill of materials
+#[tauri::command]
+pub fn generate_bom(room: RoomInput, catalog: Vec<Equipment>) -> Result<BillOfMaterials, String> {
+    bill_of_materials(&room, &catalog)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{
+        self, create_test_catalog_equipment, create_test_placed_equipment,
+    };
+    use crate::drawings::{EquipmentCategory, PlacedEquipmentInput};
+
+    fn create_test_equipment(
+        id: &str,
+        category: EquipmentCategory,
+        subcategory: &str,
+        cost: Option<f64>,
+    ) -> Equipment {
+        Equipment {
+            cost,
+            msrp: cost.map(|c| c * 1.25),
+            ..create_test_catalog_equipment(id, category, subcategory)
+        }
+    }
+
+    fn create_test_room(placed: &[(&str, f64)]) -> RoomInput {
+        test_support::create_test_room(
+            placed
+                .iter()
+                .enumerate()
+                .map(|(i, (equipment_id, x))| PlacedEquipmentInput {
+                    x: *x,
+                    ..create_test_placed_equipment(&format!("p{}", i), equipment_id)
+                })
+                .collect(),
+        )
+    }
+
+    fn create_test_catalog() -> Vec<Equipment> {
+        vec![
+            create_test_equipment("camera", EquipmentCategory::Video, "cameras", Some(1000.0)),
+            create_test_equipment(
+                "display",
+                EquipmentCategory::Video,
+                "displays",
+                Some(2000.0),
+            ),
+            create_test_equipment("mic", EquipmentCategory::Audio, "microphones", None),
+        ]
+    }
+
+    fn equipment_only(bom: &BillOfMaterials) -> Vec<&BillOfMaterialsLine> {
+        bom.lines
+            .iter()
+            .filter(|l| l.kind == BomItemKind::Equipment)
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_items_grouped() {
+        let room = create_test_room(&[("display", 0.0), ("mic", 5.0), ("display", 10.0)]);
+
+        let bom = bill_of_materials(&room, &create_test_catalog()).unwrap();
+
+        let lines = equipment_only(&bom);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            (lines[0].item_id.as_str(), lines[0].quantity),
+            ("display", 2)
+        );
+        assert_eq!(lines[0].extended_cost, Some(4000.0));
+        assert_eq!(lines[0].extended_msrp, Some(5000.0));
+        assert_eq!(lines[1].extended_cost, None);
+    }
+
+    #[test]
+    fn test_totals_sum_known_prices() {
+        let room = create_test_room(&[("display", 0.0), ("camera", 5.0), ("unknown", 8.0)]);
+
+        let bom = bill_of_materials(&room, &create_test_catalog()).unwrap();
+
+        assert_eq!(bom.total_cost, 3000.0);
+        assert_eq!(bom.total_msrp, 3750.0);
+        assert_eq!(equipment_only(&bom)[2].model, "unknown");
+    }
+
+    #[test]
+    fn test_cable_lines_follow_equipment() {
+        let room = create_test_room(&[("camera", 0.0), ("display", 12.5)]);
+        let catalog = create_test_catalog();
+        let equipment_catalog: Vec<EquipmentInput> =
+            catalog.iter().map(EquipmentInput::from).collect();
+        let diagram =
+            generate_electrical_diagram(&room, &equipment_catalog, &RoutingRules::default())
+                .unwrap();
+        let schedule = cable_schedule(&diagram);
+
+        let bom = bill_of_materials(&room, &catalog).unwrap();
+
+        let cables: Vec<&BillOfMaterialsLine> = bom
+            .lines
+            .iter()
+            .skip_while(|l| l.kind == BomItemKind::Equipment)
+            .collect();
+        assert!(!cables.is_empty());
+        assert!(cables.iter().all(|l| l.kind == BomItemKind::Cable));
+        let mut cable_types: Vec<&str> = schedule
+            .rows
+            .iter()
+            .map(|r| r.cable_type.as_str())
+            .collect();
+        cable_types.dedup();
+        assert_eq!(cables.len(), cable_types.len());
+        for line in cables {
+            let feet: f64 = schedule
+                .rows
+                .iter()
+                .filter(|r| r.cable_type == line.item_id)
+                .filter_map(|r| r.estimated_length)
+                .sum();
+            assert_eq!(line.quantity, feet.ceil() as u32);
+            assert_eq!(line.extended_cost, None);
+        }
+    }
+}
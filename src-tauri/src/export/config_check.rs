@@ -0,0 +1,389 @@
+//! Export Configuration Check
+//!
+//! Runs every pre-export check on a PDF export configuration in one pass
+//! so the UI can show all problems at once instead of failing on the first,
+//! and checks sheet numbering across a set of sheets.
+
+use super::pdf::{validate_element_ids, DrawingInput, PdfExportConfig};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Font families every PDF viewer can render without embedding
+const SUPPORTED_FONTS: [&str; 3] = ["Helvetica", "Times-Roman", "Courier"];
+
+// ============================================================================
+// Config Issue - a single problem found in an export configuration
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigIssue {
+    /// Configuration field the issue refers to, e.g. `pageLayout.marginTop`
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(field: &str, message: String) -> Self {
+        Self {
+            field: field.to_string(),
+            message,
+        }
+    }
+}
+
+// ============================================================================
+// Individual Checks
+// ============================================================================
+
+/// Flags negative margins and margins that leave no drawable area
+fn check_margins(config: &PdfExportConfig, issues: &mut Vec<ConfigIssue>) {
+    let layout = &config.page_layout;
+    let margins = [
+        ("pageLayout.marginTop", layout.margin_top),
+        ("pageLayout.marginBottom", layout.margin_bottom),
+        ("pageLayout.marginLeft", layout.margin_left),
+        ("pageLayout.marginRight", layout.margin_right),
+    ];
+
+    for (field, margin) in margins {
+        if !margin.is_finite() || margin < 0.0 {
+            issues.push(ConfigIssue::new(
+                field,
+                format!("Margin must be zero or positive, got {}", margin),
+            ));
+        }
+    }
+
+    let (width, height) = layout.drawable_area();
+    if width <= 0.0 || height <= 0.0 {
+        issues.push(ConfigIssue::new(
+            "pageLayout",
+            format!(
+                "Margins leave no drawable area on {:?} {:?} page",
+                layout.size, layout.orientation
+            ),
+        ));
+    }
+}
+
+/// Returns true if the scale is "NTS", a ratio like "1:50", or an
+/// architectural scale like `1/4" = 1'-0"`
+fn is_valid_scale(scale: &str) -> bool {
+    let scale = scale.trim();
+    if scale.eq_ignore_ascii_case("NTS") {
+        return true;
+    }
+    if let Some((left, right)) = scale.split_once(':') {
+        return matches!(
+            (left.trim().parse::<f64>(), right.trim().parse::<f64>()),
+            (Ok(l), Ok(r)) if l > 0.0 && r > 0.0
+        );
+    }
+    scale
+        .split_once('=')
+        .is_some_and(|(left, right)| !left.trim().is_empty() && !right.trim().is_empty())
+}
+
+/// Flags a title block scale that is neither NTS nor a recognizable scale
+fn check_scale(config: &PdfExportConfig, issues: &mut Vec<ConfigIssue>) {
+    let scale = &config.title_block.scale;
+    if !is_valid_scale(scale) {
+        issues.push(ConfigIssue::new(
+            "titleBlock.scale",
+            format!(
+                "Scale '{}' is not NTS, a ratio, or an architectural scale",
+                scale
+            ),
+        ));
+    }
+}
+
+/// Flags a font family that is not one of the standard PDF fonts
+fn check_font(config: &PdfExportConfig, issues: &mut Vec<ConfigIssue>) {
+    let Some(font) = &config.font_family else {
+        return;
+    };
+    if !SUPPORTED_FONTS.iter().any(|f| f.eq_ignore_ascii_case(font)) {
+        issues.push(ConfigIssue::new(
+            "fontFamily",
+            format!(
+                "Font '{}' is not supported; use one of {}",
+                font,
+                SUPPORTED_FONTS.join(", ")
+            ),
+        ));
+    }
+}
+
+/// Flags an output path that is empty, not a PDF, or in a missing directory
+fn check_output_path(output_path: &str, issues: &mut Vec<ConfigIssue>) {
+    if output_path.is_empty() {
+        issues.push(ConfigIssue::new(
+            "outputPath",
+            "Output path cannot be empty".to_string(),
+        ));
+        return;
+    }
+
+    let path = Path::new(output_path);
+    let is_pdf = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("pdf"));
+    if !is_pdf {
+        issues.push(ConfigIssue::new(
+            "outputPath",
+            format!("Output path '{}' must end in .pdf", output_path),
+        ));
+    }
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if !parent.is_dir() {
+            issues.push(ConfigIssue::new(
+                "outputPath",
+                format!("Directory '{}' does not exist", parent.display()),
+            ));
+        }
+    }
+}
+
+/// Flags drawings that would be rejected by the PDF generator
+fn check_drawing(drawing: &DrawingInput, config: &PdfExportConfig, issues: &mut Vec<ConfigIssue>) {
+    if drawing.layers.is_empty() {
+        issues.push(ConfigIssue::new(
+            "drawing.layers",
+            "Drawing has no layers to export".to_string(),
+        ));
+    } else if !drawing.layers.iter().any(|l| l.is_visible) {
+        issues.push(ConfigIssue::new(
+            "drawing.layers",
+            "Drawing has no visible layers to export".to_string(),
+        ));
+    } else if !drawing.layers.iter().any(|l| config.renders_layer(l)) {
+        issues.push(ConfigIssue::new(
+            "includeLayers",
+            "Drawing has no visible layers of the included types to export".to_string(),
+        ));
+    }
+
+    if config.reject_duplicate_ids {
+        if let Err(duplicates) = validate_element_ids(drawing) {
+            issues.push(ConfigIssue::new(
+                "drawing.elements",
+                format!(
+                    "Drawing has duplicate element ids: {}",
+                    duplicates.join(", ")
+                ),
+            ));
+        }
+    }
+}
+
+// ============================================================================
+// Combined Check
+// ============================================================================
+
+/// Runs all export checks and returns every issue found
+///
+/// The output path is only checked when one is given, since the UI may
+/// validate before the user has picked a destination.
+pub fn check_export_config(
+    config: &PdfExportConfig,
+    drawing: &DrawingInput,
+    output_path: Option<&str>,
+) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+
+    check_margins(config, &mut issues);
+    check_scale(config, &mut issues);
+    check_font(config, &mut issues);
+    if let Some(path) = output_path {
+        check_output_path(path, &mut issues);
+    }
+    check_drawing(drawing, config, &mut issues);
+
+    issues
+}
+
+// ============================================================================
+// Sheet Set Check
+// ============================================================================
+
+/// Checks that a set of sheets is numbered 1..N and every sheet says "of N"
+///
+/// On failure, the error lists each missing or repeated sheet number and
+/// each sheet whose `total_sheets` disagrees with the set size.
+pub fn check_sheet_numbering(configs: &[PdfExportConfig]) -> Result<(), String> {
+    if configs.is_empty() {
+        return Err("Sheet set is empty".to_string());
+    }
+
+    let total = configs.len() as u32;
+    let numbers: Vec<u32> = configs.iter().map(|c| c.title_block.sheet_number).collect();
+    let mut problems = Vec::new();
+
+    for n in 1..=total {
+        match numbers.iter().filter(|&&s| s == n).count() {
+            0 => problems.push(format!("sheet {} is missing", n)),
+            1 => {}
+            count => problems.push(format!("sheet {} appears {} times", n, count)),
+        }
+    }
+    for n in numbers.iter().filter(|&&s| s == 0 || s > total) {
+        problems.push(format!("sheet {} is outside 1..{}", n, total));
+    }
+    for config in configs {
+        let block = &config.title_block;
+        if block.total_sheets != total {
+            problems.push(format!(
+                "sheet {} says {} total sheets, expected {}",
+                block.sheet_number, block.total_sheets, total
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Invalid sheet numbering: {}", problems.join("; ")))
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to validate an export configuration before exporting
+#[tauri::command]
+pub fn validate_export_config(
+    config: PdfExportConfig,
+    drawing: DrawingInput,
+    output_path: Option<String>,
+) -> Result<Vec<ConfigIssue>, String> {
+    Ok(check_export_config(
+        &config,
+        &drawing,
+        output_path.as_deref(),
+    ))
+}
+
+/// Tauri command to check sheet numbers and totals across a drawing set
+#[tauri::command]
+pub fn validate_sheet_numbering(configs: Vec<PdfExportConfig>) -> Result<(), String> {
+    check_sheet_numbering(&configs)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{
+        DrawingElement, DrawingLayer, DrawingType, ElementType, LayerType, TitleBlock,
+    };
+
+    fn create_test_drawing() -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![DrawingLayer {
+                id: "layer-1".to_string(),
+                name: "AV".to_string(),
+                layer_type: LayerType::AvElements,
+                is_locked: false,
+                is_visible: true,
+                elements: vec![DrawingElement {
+                    id: "elem-1".to_string(),
+                    element_type: ElementType::Equipment,
+                    x: 100.0,
+                    y: 100.0,
+                    rotation: 0.0,
+                    properties: serde_json::json!({}),
+                }],
+            }],
+        }
+    }
+
+    fn create_test_config() -> PdfExportConfig {
+        PdfExportConfig::new(TitleBlock::new("Project", "Drawing"))
+    }
+
+    #[test]
+    fn test_valid_config_has_no_issues() {
+        let mut config = create_test_config();
+        config.font_family = Some("helvetica".to_string());
+        config.title_block.scale = "1:50".to_string();
+
+        let issues = check_export_config(&config, &create_test_drawing(), Some("drawing.pdf"));
+
+        assert!(issues.is_empty(), "unexpected issues: {:?}", issues);
+    }
+
+    #[test]
+    fn test_bad_margins_and_unknown_font_both_reported() {
+        let mut config = create_test_config();
+        config.page_layout.margin_left = -10.0;
+        config.font_family = Some("Comic Sans".to_string());
+
+        let issues = check_export_config(&config, &create_test_drawing(), None);
+
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, vec!["pageLayout.marginLeft", "fontFamily"]);
+    }
+
+    #[test]
+    fn test_invalid_scale_and_path_reported() {
+        let mut config = create_test_config();
+        config.title_block.scale = "huge".to_string();
+
+        let issues = check_export_config(
+            &config,
+            &create_test_drawing(),
+            Some("/no/such/dir/out.txt"),
+        );
+
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, vec!["titleBlock.scale", "outputPath", "outputPath"]);
+    }
+
+    fn create_test_sheet_set(sheet_numbers: &[u32], total_sheets: u32) -> Vec<PdfExportConfig> {
+        sheet_numbers
+            .iter()
+            .map(|&n| {
+                let mut config = create_test_config();
+                config.title_block.sheet_number = n;
+                config.title_block.total_sheets = total_sheets;
+                config
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_correct_sheet_set_passes() {
+        let configs = create_test_sheet_set(&[2, 1, 3], 3);
+        assert!(check_sheet_numbering(&configs).is_ok());
+    }
+
+    #[test]
+    fn test_gapped_sheet_set_fails() {
+        let configs = create_test_sheet_set(&[1, 2, 4], 3);
+
+        let err = check_sheet_numbering(&configs).unwrap_err();
+
+        assert!(err.contains("sheet 3 is missing"));
+        assert!(err.contains("sheet 4 is outside 1..3"));
+    }
+
+    #[test]
+    fn test_stale_total_sheets_fails() {
+        let configs = create_test_sheet_set(&[1, 2], 3);
+
+        let err = check_sheet_numbering(&configs).unwrap_err();
+
+        assert!(err.contains("says 3 total sheets, expected 2"));
+    }
+}
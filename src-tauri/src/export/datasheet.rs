@@ -0,0 +1,492 @@
+//! Equipment Datasheet Export
+//!
+//! Composes a one-page submittal datasheet for a single device: product
+//! image (or a placeholder when none is on record), a specs table and the
+//! title block.
+
+use super::pdf::{
+    builtin_font, draw_title_block, load_image, load_logo, pt, save_pdf, stroke_line, stroke_rect,
+    title_block_height, title_block_lines, written_file_size, PdfExportConfig, PdfExportResult,
+    TEXT_FONT_SIZE,
+};
+use crate::drawings::EquipmentInput;
+use printpdf::{
+    Image, ImageTransform, ImageXObject, IndirectFontRef, PdfDocument, PdfLayerReference,
+};
+use serde::{Deserialize, Serialize};
+
+/// Share of the drawable height reserved for the product image
+const IMAGE_HEIGHT_RATIO: f64 = 0.45;
+
+/// Height of a specs table row in points
+const SPEC_ROW_HEIGHT: f64 = 16.0;
+
+/// Share of the specs table width taken by the label column
+const SPEC_LABEL_SHARE: f64 = 0.35;
+
+// ============================================================================
+// Spec Rows
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecRow {
+    pub label: String,
+    pub value: String,
+}
+
+/// Builds the specs table for a device, skipping values not on record
+pub fn datasheet_spec_rows(equipment: &EquipmentInput) -> Vec<SpecRow> {
+    let specs = &equipment.specs;
+    let mut rows = vec![
+        ("Manufacturer", equipment.manufacturer.clone()),
+        ("Model", equipment.model.clone()),
+    ];
+
+    if let (Some(h), Some(w), Some(d)) = (specs.height, specs.width, specs.depth) {
+        rows.push((
+            "Dimensions (H x W x D)",
+            format!("{} x {} x {} in", h, w, d),
+        ));
+    }
+    if let Some(weight) = equipment.weight {
+        rows.push(("Weight", format!("{} lb", weight)));
+    }
+    if let Some(wattage) = specs.wattage {
+        rows.push(("Power", format!("{} W", wattage)));
+    }
+    if let Some(voltage) = &specs.voltage {
+        rows.push(("Voltage", voltage.clone()));
+    }
+    if !equipment.certifications.is_empty() {
+        rows.push(("Certifications", equipment.certifications.join(", ")));
+    }
+
+    rows.into_iter()
+        .map(|(label, value)| SpecRow {
+            label: label.to_string(),
+            value,
+        })
+        .collect()
+}
+
+// ============================================================================
+// Datasheet Layout (internal)
+// ============================================================================
+
+#[derive(Debug)]
+struct DatasheetLayout {
+    image_source: Option<String>,
+    image_width: f64,
+    image_height: f64,
+    spec_rows: Vec<SpecRow>,
+    title_block_lines: Vec<String>,
+}
+
+/// Lays out the image box, specs table and title block on the configured page
+///
+/// The specs table must fit between the image box and the title block.
+fn layout_datasheet(
+    equipment: &EquipmentInput,
+    config: &PdfExportConfig,
+) -> Result<DatasheetLayout, String> {
+    let (draw_width, draw_height) = config.page_layout.drawable_area();
+    let image_height = draw_height * IMAGE_HEIGHT_RATIO;
+    let spec_rows = datasheet_spec_rows(equipment);
+
+    let title_block = config.title_block.normalized_contact()?;
+    let title_block_lines = title_block_lines(&title_block, config, &[]);
+    let has_logo = title_block
+        .logo_path
+        .as_deref()
+        .is_some_and(|p| !p.trim().is_empty());
+    let title_block_height = title_block_height(title_block_lines.len(), has_logo);
+
+    if (spec_rows.len() as f64) * SPEC_ROW_HEIGHT > draw_height - image_height - title_block_height
+    {
+        return Err("Page is too small to fit the datasheet specs table".to_string());
+    }
+
+    Ok(DatasheetLayout {
+        image_source: equipment
+            .specs
+            .image_url
+            .clone()
+            .filter(|url| !url.trim().is_empty()),
+        image_width: draw_width,
+        image_height,
+        spec_rows,
+        title_block_lines,
+    })
+}
+
+// ============================================================================
+// Datasheet Generator
+// ============================================================================
+
+/// Generates a one-page datasheet PDF for a device in the catalog and
+/// writes it to `output_path`
+///
+/// The product image is read from the device's `image_url` when it names a
+/// local PNG or JPEG file. Devices without an image on record get a
+/// placeholder; images that cannot be loaded, such as remote URLs, get a
+/// placeholder and a warning.
+pub fn generate_datasheet(
+    equipment_id: &str,
+    equipment_catalog: &[EquipmentInput],
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<PdfExportResult, String> {
+    if output_path.is_empty() {
+        return Err("Output path cannot be empty".to_string());
+    }
+
+    let equipment = equipment_catalog
+        .iter()
+        .find(|e| e.id == equipment_id)
+        .ok_or_else(|| format!("Equipment not found: {}", equipment_id))?;
+
+    let layout = layout_datasheet(equipment, config)?;
+    let warnings = write_datasheet(equipment, &layout, config, output_path)?;
+
+    Ok(PdfExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes: written_file_size(output_path)?,
+        page_count: 1,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings,
+    })
+}
+
+/// Draws an image scaled to fit and centered in a box given by its lower
+/// left corner, in page points
+fn draw_image(
+    layer: &PdfLayerReference,
+    image: ImageXObject,
+    (x, y): (f64, f64),
+    width: f64,
+    height: f64,
+) {
+    // At 72 dpi one image pixel is one point before scaling
+    let (image_width, image_height) = (image.width.0 as f64, image.height.0 as f64);
+    let scale = (width / image_width).min(height / image_height);
+    Image::from(image).add_to_layer(
+        layer.clone(),
+        ImageTransform {
+            translate_x: Some(pt(x + (width - image_width * scale) / 2.0)),
+            translate_y: Some(pt(y + (height - image_height * scale) / 2.0)),
+            scale_x: Some(scale as f32),
+            scale_y: Some(scale as f32),
+            dpi: Some(72.0),
+            ..Default::default()
+        },
+    );
+}
+
+/// Draws a crossed-out frame standing in for a missing product image
+fn draw_image_placeholder(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    (x, y): (f64, f64),
+    width: f64,
+    height: f64,
+) {
+    stroke_rect(layer, x, y, width, height);
+    stroke_line(layer, &[(x, y), (x + width, y + height)]);
+    stroke_line(layer, &[(x, y + height), (x + width, y)]);
+    layer.use_text(
+        "No image available",
+        TEXT_FONT_SIZE,
+        pt(x + 4.0),
+        pt(y + 4.0),
+        font,
+    );
+}
+
+/// Draws the specs table whose top edge is `top` points up the page
+fn draw_spec_table(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    left: f64,
+    top: f64,
+    width: f64,
+    rows: &[SpecRow],
+) {
+    let value_x = left + width * SPEC_LABEL_SHARE;
+
+    for (index, row) in rows.iter().enumerate() {
+        let y = top - (index as f64 + 1.0) * SPEC_ROW_HEIGHT;
+        stroke_rect(layer, left, y, width, SPEC_ROW_HEIGHT);
+        stroke_line(layer, &[(value_x, y), (value_x, y + SPEC_ROW_HEIGHT)]);
+        layer.use_text(
+            row.label.as_str(),
+            TEXT_FONT_SIZE,
+            pt(left + 4.0),
+            pt(y + 4.0),
+            font,
+        );
+        layer.use_text(
+            row.value.as_str(),
+            TEXT_FONT_SIZE,
+            pt(value_x + 4.0),
+            pt(y + 4.0),
+            font,
+        );
+    }
+}
+
+/// Renders the datasheet page and writes the PDF file
+///
+/// Returns a warning for a product image or title block logo that could
+/// not be loaded.
+fn write_datasheet(
+    equipment: &EquipmentInput,
+    layout: &DatasheetLayout,
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<Vec<String>, String> {
+    let page_layout = &config.page_layout;
+    let (page_width, page_height) = page_layout.effective_dimensions();
+    let (draw_width, draw_height) = page_layout.drawable_area();
+    let left = page_layout.margin_left;
+    let top = page_height - page_layout.margin_top;
+
+    let (doc, page, layer) = PdfDocument::new(
+        format!("{} {}", equipment.manufacturer, equipment.model),
+        pt(page_width),
+        pt(page_height),
+        "Datasheet",
+    );
+    let font = doc
+        .add_builtin_font(builtin_font(config.font_family.as_deref()))
+        .map_err(|e| format!("Failed to load font: {}", e))?;
+    let layer = doc.get_page(page).get_layer(layer);
+    let mut warnings = Vec::new();
+
+    stroke_rect(
+        &layer,
+        left,
+        page_layout.margin_bottom,
+        draw_width,
+        draw_height,
+    );
+
+    let image_corner = (left, top - layout.image_height);
+    let image = layout
+        .image_source
+        .as_deref()
+        .and_then(|path| match load_image(path) {
+            Ok(image) => Some(image),
+            Err(warning) => {
+                warnings.push(format!("Skipped product image {}", warning));
+                None
+            }
+        });
+    match image {
+        Some(image) => draw_image(
+            &layer,
+            image,
+            image_corner,
+            layout.image_width,
+            layout.image_height,
+        ),
+        None => draw_image_placeholder(
+            &layer,
+            &font,
+            image_corner,
+            layout.image_width,
+            layout.image_height,
+        ),
+    }
+
+    draw_spec_table(
+        &layer,
+        &font,
+        left,
+        image_corner.1,
+        draw_width,
+        &layout.spec_rows,
+    );
+
+    let logo_path = config
+        .title_block
+        .logo_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty());
+    let logo = logo_path.and_then(|path| match load_logo(path) {
+        Ok(logo) => Some(logo),
+        Err(warning) => {
+            warnings.push(warning);
+            None
+        }
+    });
+    draw_title_block(&layer, &font, page_layout, &layout.title_block_lines, logo);
+
+    save_pdf(doc, output_path)?;
+
+    Ok(warnings)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a submittal datasheet PDF for one device
+#[tauri::command]
+pub fn export_equipment_datasheet(
+    equipment_id: String,
+    equipment_catalog: Vec<EquipmentInput>,
+    config: PdfExportConfig,
+    output_path: String,
+) -> Result<PdfExportResult, String> {
+    generate_datasheet(&equipment_id, &equipment_catalog, &config, &output_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support;
+    use crate::drawings::{EquipmentCategory, EquipmentSpecs};
+    use crate::export::pdf::TitleBlock;
+
+    fn create_test_equipment(specs: EquipmentSpecs) -> EquipmentInput {
+        EquipmentInput {
+            manufacturer: "Poly".to_string(),
+            model: "Studio X50".to_string(),
+            weight: Some(6.5),
+            certifications: vec!["Microsoft Teams".to_string(), "Zoom Rooms".to_string()],
+            specs,
+            ..test_support::create_test_equipment("codec-1", EquipmentCategory::Video, "codecs")
+        }
+    }
+
+    fn create_test_config() -> PdfExportConfig {
+        PdfExportConfig::new(TitleBlock::new("Project", "Submittal"))
+    }
+
+    /// Returns an output path inside a fresh temp dir, kept alive by the guard
+    fn temp_pdf_path() -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("x50.pdf").to_string_lossy().into_owned();
+        (dir, path)
+    }
+
+    /// Writes a small RGB PNG product image into `dir`
+    fn write_test_image(dir: &tempfile::TempDir) -> String {
+        let path = dir.path().join("x50.png");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), 4, 2);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[90; 24]).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Collects the strings shown on the first page of a PDF
+    fn page_text(path: &str) -> Vec<String> {
+        let document = lopdf::Document::load(path).unwrap();
+        let page_id = *document.get_pages().values().next().unwrap();
+        let content = document.get_and_decode_page_content(page_id).unwrap();
+        content
+            .operations
+            .iter()
+            .filter(|op| op.operator == "Tj")
+            .filter_map(|op| op.operands.first()?.as_str().ok())
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_datasheet_produced_for_device() {
+        let (dir, path) = temp_pdf_path();
+        let catalog = vec![create_test_equipment(EquipmentSpecs {
+            image_url: Some(write_test_image(&dir)),
+            wattage: Some(45.0),
+            ..Default::default()
+        })];
+
+        let result = generate_datasheet("codec-1", &catalog, &create_test_config(), &path).unwrap();
+
+        assert_eq!(result.file_path, path);
+        assert_eq!(result.page_count, 1);
+        assert!(result.warnings.is_empty());
+        assert_eq!(
+            result.file_size_bytes,
+            std::fs::metadata(&path).unwrap().len()
+        );
+        let text = page_text(&path);
+        for shown in [
+            "Studio X50",
+            "45 W",
+            "Microsoft Teams, Zoom Rooms",
+            "Submittal",
+        ] {
+            assert!(text.iter().any(|t| t == shown), "missing {}", shown);
+        }
+        assert!(!text.iter().any(|t| t == "No image available"));
+    }
+
+    #[test]
+    fn test_remote_image_falls_back_to_placeholder() {
+        let (_dir, path) = temp_pdf_path();
+        let catalog = vec![create_test_equipment(EquipmentSpecs {
+            image_url: Some("https://images.poly.com/x50.png".to_string()),
+            ..Default::default()
+        })];
+
+        let result = generate_datasheet("codec-1", &catalog, &create_test_config(), &path).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("https://images.poly.com/x50.png"));
+        assert!(page_text(&path).iter().any(|t| t == "No image available"));
+    }
+
+    #[test]
+    fn test_spec_rows_include_fields_on_record() {
+        let equipment = create_test_equipment(EquipmentSpecs {
+            height: Some(5.0),
+            width: Some(29.0),
+            depth: Some(4.0),
+            wattage: Some(45.0),
+            ..Default::default()
+        });
+
+        let rows = datasheet_spec_rows(&equipment);
+
+        let labels: Vec<&str> = rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Manufacturer",
+                "Model",
+                "Dimensions (H x W x D)",
+                "Weight",
+                "Power",
+                "Certifications"
+            ]
+        );
+        assert_eq!(rows[2].value, "5 x 29 x 4 in");
+        assert_eq!(rows[5].value, "Microsoft Teams, Zoom Rooms");
+    }
+
+    #[test]
+    fn test_missing_image_uses_placeholder() {
+        let equipment = create_test_equipment(EquipmentSpecs::default());
+
+        let layout = layout_datasheet(&equipment, &create_test_config()).unwrap();
+
+        assert!(layout.image_source.is_none());
+        assert!(layout.image_height > 0.0);
+    }
+
+    #[test]
+    fn test_unknown_equipment_errors() {
+        let result = generate_datasheet("missing", &[], &create_test_config(), "/tmp/x.pdf");
+        assert!(result.unwrap_err().contains("missing"));
+    }
+}
@@ -0,0 +1,469 @@
+//! DXF Export
+//!
+//! Writes drawings as minimal ASCII DXF (AutoCAD R12) files so contractors
+//! can edit them in CAD. Each drawing layer becomes a DXF layer, equipment
+//! is inserted from a shared block and cables become LINE entities.
+
+use super::pdf::{
+    property_f64, DrawingElement, DrawingInput, ElementType, ELEMENT_BOX_HEIGHT, ELEMENT_BOX_WIDTH,
+    SYMBOL_RADIUS,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Block inserted for every equipment element
+const EQUIPMENT_BLOCK: &str = "EQUIPMENT";
+
+/// Height of TEXT entities, in drawing units
+const TEXT_HEIGHT: f64 = 10.0;
+
+/// Layer flag (group code 70) marking a locked layer
+const LAYER_LOCKED: i32 = 4;
+
+/// Default layer color; a negative color turns the layer off
+const LAYER_COLOR: i32 = 7;
+
+// ============================================================================
+// DXF Export Result
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DxfExportResult {
+    pub file_path: String,
+    pub file_size_bytes: u64,
+    pub layer_count: usize,
+    pub entity_count: usize,
+    pub generated_at: String,
+    /// Elements that could not be written and were left out of the file
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// DXF Writer (internal)
+// ============================================================================
+
+/// Appends one group code/value pair
+fn group(out: &mut String, code: i32, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "{:>3}\n{}", code, value);
+}
+
+/// Appends a point as x/y/z groups starting at `code` (10, 11, ...)
+fn point(out: &mut String, code: i32, (x, y): (f64, f64)) {
+    group(out, code, x);
+    group(out, code + 10, y);
+    group(out, code + 20, 0.0);
+}
+
+/// Replaces characters R12 does not allow in layer names
+///
+/// Only letters, digits, `$`, `-` and `_` are allowed; anything else
+/// becomes `_`.
+fn layer_name(name: &str) -> String {
+    let sanitized: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "$-_".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.is_empty() {
+        "UNNAMED".to_string()
+    } else {
+        sanitized
+    }
+}
+
+/// Sanitized DXF layer names, one per drawing layer in order
+///
+/// Layer names are compared ignoring case, as AutoCAD does, so a name that
+/// matches the default layer `0` or an earlier layer after sanitizing gets
+/// the first free `_2`, `_3`, … suffix.
+fn layer_names(drawing: &DrawingInput) -> Vec<String> {
+    let mut names = vec!["0".to_string()];
+    for layer in &drawing.layers {
+        let base = layer_name(&layer.name);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while names.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        names.push(name);
+    }
+    names.split_off(1)
+}
+
+/// Converts a drawing coordinate (y down) to DXF space (y up)
+fn to_dxf(x: f64, y: f64) -> (f64, f64) {
+    (x, -y)
+}
+
+fn write_header(out: &mut String) {
+    group(out, 0, "SECTION");
+    group(out, 2, "HEADER");
+    group(out, 9, "$ACADVER");
+    group(out, 1, "AC1009");
+    group(out, 0, "ENDSEC");
+}
+
+fn write_layer_table(out: &mut String, drawing: &DrawingInput, names: &[String]) {
+    group(out, 0, "SECTION");
+    group(out, 2, "TABLES");
+    group(out, 0, "TABLE");
+    group(out, 2, "LAYER");
+    group(out, 70, drawing.layers.len() + 1);
+
+    let layers = std::iter::once(("0", false, true)).chain(
+        drawing
+            .layers
+            .iter()
+            .zip(names)
+            .map(|(l, name)| (name.as_str(), l.is_locked, l.is_visible)),
+    );
+    for (name, is_locked, is_visible) in layers {
+        group(out, 0, "LAYER");
+        group(out, 2, name);
+        group(out, 70, if is_locked { LAYER_LOCKED } else { 0 });
+        group(
+            out,
+            62,
+            if is_visible {
+                LAYER_COLOR
+            } else {
+                -LAYER_COLOR
+            },
+        );
+        group(out, 6, "CONTINUOUS");
+    }
+
+    group(out, 0, "ENDTAB");
+    group(out, 0, "ENDSEC");
+}
+
+fn write_line(out: &mut String, layer: &str, start: (f64, f64), end: (f64, f64)) {
+    group(out, 0, "LINE");
+    group(out, 8, layer);
+    point(out, 10, start);
+    point(out, 11, end);
+}
+
+/// Defines the equipment block as a rectangle centered on its base point
+fn write_blocks(out: &mut String) {
+    let (w, h) = (ELEMENT_BOX_WIDTH / 2.0, ELEMENT_BOX_HEIGHT / 2.0);
+    let corners = [(-w, -h), (w, -h), (w, h), (-w, h), (-w, -h)];
+
+    group(out, 0, "SECTION");
+    group(out, 2, "BLOCKS");
+    group(out, 0, "BLOCK");
+    group(out, 8, "0");
+    group(out, 2, EQUIPMENT_BLOCK);
+    group(out, 70, 0);
+    point(out, 10, (0.0, 0.0));
+    group(out, 3, EQUIPMENT_BLOCK);
+    for pair in corners.windows(2) {
+        write_line(out, "0", pair[0], pair[1]);
+    }
+    group(out, 0, "ENDBLK");
+    group(out, 8, "0");
+    group(out, 0, "ENDSEC");
+}
+
+/// Writes one element as a DXF entity
+///
+/// Rotations are negated since drawing rotations are clockwise and DXF
+/// angles are counterclockwise. Returns a warning instead of writing a
+/// cable that is missing an endpoint coordinate.
+fn write_element(out: &mut String, layer: &str, element: &DrawingElement) -> Result<(), String> {
+    let position = to_dxf(element.x, element.y);
+    let coord = |key| property_f64(element, key);
+
+    match element.element_type {
+        ElementType::Equipment => {
+            group(out, 0, "INSERT");
+            group(out, 8, layer);
+            group(out, 2, EQUIPMENT_BLOCK);
+            point(out, 10, position);
+            group(out, 50, -element.rotation);
+        }
+        ElementType::Cable => match (coord("x1"), coord("y1"), coord("x2"), coord("y2")) {
+            (Some(x1), Some(y1), Some(x2), Some(y2)) => {
+                write_line(out, layer, to_dxf(x1, y1), to_dxf(x2, y2));
+            }
+            _ => {
+                return Err(format!(
+                    "Skipped cable {}: missing endpoint coordinates",
+                    element.id
+                ))
+            }
+        },
+        ElementType::Text => {
+            let text = ["text", "label"]
+                .iter()
+                .find_map(|key| element.properties.get(*key).and_then(|v| v.as_str()))
+                .unwrap_or(element.id.as_str());
+            group(out, 0, "TEXT");
+            group(out, 8, layer);
+            point(out, 10, position);
+            group(out, 40, TEXT_HEIGHT);
+            // TEXT entities are single-line
+            group(out, 1, text.replace(['\r', '\n'], " "));
+            group(out, 50, -element.rotation);
+        }
+        ElementType::Dimension => {
+            let end = to_dxf(
+                coord("x2").unwrap_or(element.x),
+                coord("y2").unwrap_or(element.y),
+            );
+            write_line(out, layer, position, end);
+        }
+        ElementType::Symbol => {
+            group(out, 0, "CIRCLE");
+            group(out, 8, layer);
+            point(out, 10, position);
+            group(out, 40, SYMBOL_RADIUS);
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a drawing to DXF text, returning the entity count and warnings
+fn render_dxf(drawing: &DrawingInput) -> (String, usize, Vec<String>) {
+    let mut out = String::new();
+    let mut entity_count = 0;
+    let mut warnings = Vec::new();
+
+    let names = layer_names(drawing);
+    write_header(&mut out);
+    write_layer_table(&mut out, drawing, &names);
+    write_blocks(&mut out);
+
+    group(&mut out, 0, "SECTION");
+    group(&mut out, 2, "ENTITIES");
+    for (layer, name) in drawing.layers.iter().zip(&names) {
+        for element in &layer.elements {
+            match write_element(&mut out, name, element) {
+                Ok(()) => entity_count += 1,
+                Err(warning) => warnings.push(warning),
+            }
+        }
+    }
+    group(&mut out, 0, "ENDSEC");
+    group(&mut out, 0, "EOF");
+
+    (out, entity_count, warnings)
+}
+
+// ============================================================================
+// DXF Export
+// ============================================================================
+
+/// Writes a drawing to `output_path` as a DXF file
+///
+/// All layers are written, including hidden and locked ones; hidden layers
+/// are turned off and locked layers keep their lock flag.
+pub fn export_to_dxf(drawing: &DrawingInput, output_path: &str) -> Result<DxfExportResult, String> {
+    if drawing.layers.is_empty() {
+        return Err("Drawing has no layers to export".to_string());
+    }
+    if output_path.is_empty() {
+        return Err("Output path cannot be empty".to_string());
+    }
+
+    let (dxf, entity_count, warnings) = render_dxf(drawing);
+    std::fs::write(output_path, &dxf).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(DxfExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes: dxf.len() as u64,
+        layer_count: drawing.layers.len(),
+        entity_count,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings,
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a drawing as a DXF file for CAD
+#[tauri::command]
+pub fn export_dxf(drawing: DrawingInput, output_path: String) -> Result<DxfExportResult, String> {
+    export_to_dxf(&drawing, &output_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingLayer, DrawingType, LayerType};
+
+    fn create_test_element(id: &str, element_type: ElementType) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type,
+            x: 100.0,
+            y: 100.0,
+            rotation: 0.0,
+            properties: serde_json::json!({}),
+        }
+    }
+
+    fn create_test_cable(id: &str) -> DrawingElement {
+        let mut cable = create_test_element(id, ElementType::Cable);
+        cable.properties = serde_json::json!({ "x1": 100.0, "y1": 100.0, "x2": 200.0, "y2": 50.0 });
+        cable
+    }
+
+    fn create_test_drawing() -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![
+                DrawingLayer {
+                    id: "layer-av".to_string(),
+                    name: "AV Elements".to_string(),
+                    layer_type: LayerType::AvElements,
+                    is_locked: false,
+                    is_visible: true,
+                    elements: vec![
+                        create_test_element("elem-1", ElementType::Equipment),
+                        create_test_element("elem-2", ElementType::Equipment),
+                        create_test_cable("cable-1"),
+                    ],
+                },
+                DrawingLayer {
+                    id: "layer-arch".to_string(),
+                    name: "Architectural".to_string(),
+                    layer_type: LayerType::Architectural,
+                    is_locked: true,
+                    is_visible: false,
+                    elements: vec![create_test_cable("wall-1")],
+                },
+            ],
+        }
+    }
+
+    /// Reads a DXF file back as (group code, value) pairs
+    fn read_groups(path: &str) -> Vec<(i32, String)> {
+        let text = std::fs::read_to_string(path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        lines
+            .chunks(2)
+            .map(|pair| (pair[0].trim().parse().unwrap(), pair[1].to_string()))
+            .collect()
+    }
+
+    /// Returns the groups between `2 <name>` and the following ENDSEC
+    fn section<'a>(groups: &'a [(i32, String)], name: &str) -> &'a [(i32, String)] {
+        let start = groups
+            .iter()
+            .position(|(c, v)| *c == 2 && v == name)
+            .unwrap();
+        let end = start
+            + groups[start..]
+                .iter()
+                .position(|(c, v)| *c == 0 && v == "ENDSEC")
+                .unwrap();
+        &groups[start + 1..end]
+    }
+
+    #[test]
+    fn test_dxf_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("drawing.dxf")
+            .to_string_lossy()
+            .into_owned();
+
+        let result = export_to_dxf(&create_test_drawing(), &path).unwrap();
+
+        let groups = read_groups(&path);
+        let header = section(&groups, "HEADER");
+        assert_eq!(header[0], (9, "$ACADVER".to_string()));
+        assert_eq!(header[1], (1, "AC1009".to_string()));
+
+        let entities: Vec<&str> = section(&groups, "ENTITIES")
+            .iter()
+            .filter(|(c, _)| *c == 0)
+            .map(|(_, v)| v.as_str())
+            .collect();
+        assert_eq!(entities, vec!["INSERT", "INSERT", "LINE", "LINE"]);
+        assert_eq!(result.entity_count, 4);
+        assert_eq!(groups.last().unwrap(), &(0, "EOF".to_string()));
+    }
+
+    #[test]
+    fn test_layer_flags_preserved() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("drawing.dxf")
+            .to_string_lossy()
+            .into_owned();
+        export_to_dxf(&create_test_drawing(), &path).unwrap();
+
+        let groups = read_groups(&path);
+        let tables = section(&groups, "TABLES");
+        let arch = tables
+            .iter()
+            .position(|(c, v)| *c == 2 && v == "Architectural")
+            .unwrap();
+
+        assert_eq!(tables[arch + 1], (70, LAYER_LOCKED.to_string()));
+        assert_eq!(tables[arch + 2], (62, (-LAYER_COLOR).to_string()));
+    }
+
+    #[test]
+    fn test_layer_names_sanitized_and_unique() {
+        let mut drawing = create_test_drawing();
+        let template = drawing.layers[1].clone();
+        drawing.layers = ["Cable/Tray", "Cable Tray", "cable_tray", "0", "Überputz"]
+            .iter()
+            .map(|name| DrawingLayer {
+                name: name.to_string(),
+                ..template.clone()
+            })
+            .collect();
+
+        assert_eq!(
+            layer_names(&drawing),
+            vec![
+                "Cable_Tray",
+                "Cable_Tray_2",
+                "cable_tray_3",
+                "0_2",
+                "_berputz"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cable_without_endpoints_skipped() {
+        let mut drawing = create_test_drawing();
+        drawing.layers[0].elements[2].properties = serde_json::json!({});
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("drawing.dxf")
+            .to_string_lossy()
+            .into_owned();
+
+        let result = export_to_dxf(&drawing, &path).unwrap();
+
+        assert_eq!(result.entity_count, 3);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("cable-1"));
+    }
+}
@@ -0,0 +1,31 @@
+//! Export Errors
+//!
+//! Error type shared by the file exporters.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can occur during export operations
+#[derive(Debug, Error, Serialize, Deserialize)]
+pub enum ExportError {
+    #[error("Drawing has no layers to export")]
+    NoLayers,
+
+    #[error("Drawing has no visible layers to export")]
+    NoVisibleLayers,
+
+    #[error("Output path cannot be empty")]
+    EmptyOutputPath,
+
+    #[error("Input path cannot be empty")]
+    EmptyInputPath,
+
+    #[error("Invalid export configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Failed to write file: {0}")]
+    WriteError(String),
+
+    #[error("Failed to read file: {0}")]
+    ReadError(String),
+}
@@ -0,0 +1,142 @@
+//! Estimate CSV Export
+//!
+//! Writes a bill of materials in the item/description/qty/rate/amount
+//! layout that QuickBooks and other accounting tools import as an estimate.
+
+use super::bom::BillOfMaterialsLine;
+use super::error::ExportError;
+use csv::Writer;
+
+/// Estimate columns, in the order accounting imports expect
+pub const ESTIMATE_COLUMNS: [&str; 5] = ["Item", "Description", "Qty", "Rate", "Amount"];
+
+/// Formats a currency value with two decimals, blank when unknown
+fn format_money(value: Option<f64>) -> String {
+    value.map(|v| format!("{:.2}", v)).unwrap_or_default()
+}
+
+/// Writes BOM lines to `output_path` as an estimate CSV
+///
+/// Each line's amount is recomputed as qty × rate. Lines without a unit
+/// cost are written with blank rate and amount.
+pub fn write_bom_estimate_csv(
+    bom: &[BillOfMaterialsLine],
+    output_path: &str,
+) -> Result<(), ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+
+    let mut writer =
+        Writer::from_path(output_path).map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    writer
+        .write_record(ESTIMATE_COLUMNS)
+        .map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    for line in bom {
+        let description = format!("{} {}", line.manufacturer, line.model);
+        let amount = line.unit_cost.map(|rate| rate * line.quantity as f64);
+        writer
+            .write_record([
+                line.model.as_str(),
+                description.trim(),
+                &line.quantity.to_string(),
+                &format_money(line.unit_cost),
+                &format_money(amount),
+            ])
+            .map_err(|e| ExportError::WriteError(e.to_string()))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| ExportError::WriteError(e.to_string()))
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a bill of materials as an accounting estimate CSV
+#[tauri::command]
+pub fn export_bom_estimate_csv(
+    bom: Vec<BillOfMaterialsLine>,
+    output_path: String,
+) -> Result<(), ExportError> {
+    write_bom_estimate_csv(&bom, &output_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::bom::BomItemKind;
+
+    fn create_test_line(model: &str, quantity: u32, unit_cost: Option<f64>) -> BillOfMaterialsLine {
+        BillOfMaterialsLine {
+            kind: BomItemKind::Equipment,
+            item_id: format!("eq-{}", model),
+            manufacturer: "Shure".to_string(),
+            model: model.to_string(),
+            quantity,
+            unit_cost,
+            unit_msrp: None,
+            extended_cost: unit_cost.map(|c| c * f64::from(quantity)),
+            extended_msrp: None,
+        }
+    }
+
+    fn write_and_read(bom: &[BillOfMaterialsLine]) -> Vec<Vec<String>> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("estimate.csv");
+        write_bom_estimate_csv(bom, path.to_str().unwrap()).unwrap();
+
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_path(&path)
+            .unwrap()
+            .records()
+            .map(|r| r.unwrap().iter().map(str::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_column_order() {
+        let rows = write_and_read(&[create_test_line("MXA920", 1, Some(4500.0))]);
+
+        assert_eq!(rows[0], ESTIMATE_COLUMNS);
+        assert_eq!(
+            rows[1],
+            ["MXA920", "Shure MXA920", "1", "4500.00", "4500.00"]
+        );
+    }
+
+    #[test]
+    fn test_amount_is_qty_times_rate() {
+        let bom = vec![
+            create_test_line("SM58", 4, Some(99.5)),
+            create_test_line("ULXD4", 2, Some(1249.99)),
+        ];
+
+        let rows = write_and_read(&bom);
+
+        for row in &rows[1..] {
+            let qty: f64 = row[2].parse().unwrap();
+            let rate: f64 = row[3].parse().unwrap();
+            let amount: f64 = row[4].parse().unwrap();
+            assert!((amount - qty * rate).abs() < 0.005);
+        }
+        assert_eq!(rows[1][4], "398.00");
+        assert_eq!(rows[2][4], "2499.98");
+    }
+
+    #[test]
+    fn test_unknown_cost_left_blank() {
+        let rows = write_and_read(&[create_test_line("TBD", 3, None)]);
+        assert_eq!(rows[1][3], "");
+        assert_eq!(rows[1][4], "");
+    }
+}
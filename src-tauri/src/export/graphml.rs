@@ -0,0 +1,206 @@
+//! GraphML Export Module
+//!
+//! Writes the signal graph of an electrical diagram as GraphML, with
+//! equipment as typed nodes and signal connections as edges.
+
+use super::error::ExportError;
+use super::svg::escape_xml;
+use crate::drawings::{ElectricalDiagram, ElementType};
+use std::fmt::Write;
+
+/// Renders the diagram's signal graph as a GraphML document
+///
+/// Nodes are keyed by equipment id so that connection endpoints resolve to
+/// them. Endpoints without a matching diagram element still get a node.
+pub fn render_graphml(diagram: &ElectricalDiagram) -> String {
+    let mut nodes: Vec<(String, String)> = Vec::new();
+
+    for element in diagram
+        .elements
+        .iter()
+        .filter(|e| e.element_type == ElementType::Equipment)
+    {
+        let equipment_id = element.properties["equipment_id"]
+            .as_str()
+            .unwrap_or(element.id.as_str())
+            .to_string();
+        if !nodes.iter().any(|(id, _)| *id == equipment_id) {
+            nodes.push((equipment_id, element.label.clone()));
+        }
+    }
+
+    for connection in &diagram.connections {
+        for endpoint in [&connection.from_equipment_id, &connection.to_equipment_id] {
+            if !nodes.iter().any(|(id, _)| id == endpoint) {
+                nodes.push((endpoint.clone(), endpoint.clone()));
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    out.push_str("  <key id=\"type\" for=\"node\" attr.name=\"type\" attr.type=\"string\"/>\n");
+    out.push_str("  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n");
+    out.push_str(
+        "  <key id=\"signal\" for=\"edge\" attr.name=\"signalType\" attr.type=\"string\"/>\n",
+    );
+    out.push_str(
+        "  <key id=\"cable\" for=\"edge\" attr.name=\"cableType\" attr.type=\"string\"/>\n",
+    );
+    let _ = writeln!(
+        out,
+        "  <graph id=\"{}\" edgedefault=\"directed\">",
+        escape_xml(&diagram.room_id)
+    );
+
+    for (id, label) in &nodes {
+        let _ = writeln!(
+            out,
+            "    <node id=\"{}\"><data key=\"type\">equipment</data><data key=\"label\">{}</data></node>",
+            escape_xml(id),
+            escape_xml(label)
+        );
+    }
+
+    for connection in &diagram.connections {
+        let signal = serde_json::to_value(connection.signal_type)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\"><data key=\"signal\">{}</data><data key=\"cable\">{}</data></edge>",
+            escape_xml(&connection.id),
+            escape_xml(&connection.from_equipment_id),
+            escape_xml(&connection.to_equipment_id),
+            signal,
+            escape_xml(&connection.cable_type)
+        );
+    }
+
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// Writes the diagram's signal graph to `output_path` as GraphML
+pub fn write_graphml(diagram: &ElectricalDiagram, output_path: &str) -> Result<(), ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+
+    std::fs::write(output_path, render_graphml(diagram))
+        .map_err(|e| ExportError::WriteError(e.to_string()))
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a diagram's signal graph as GraphML
+#[tauri::command]
+pub fn export_graphml(diagram: ElectricalDiagram, output_path: String) -> Result<(), ExportError> {
+    write_graphml(&diagram, &output_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{self, create_test_connection};
+    use crate::drawings::{DrawingElement, SignalType};
+
+    fn create_test_element(equipment_id: &str) -> DrawingElement {
+        DrawingElement {
+            id: format!("elem-{}", equipment_id),
+            element_type: ElementType::Equipment,
+            x: 100.0,
+            y: 100.0,
+            rotation: 0.0,
+            label: format!("Label {}", equipment_id),
+            properties: serde_json::json!({ "equipment_id": equipment_id }),
+        }
+    }
+
+    fn create_test_diagram() -> ElectricalDiagram {
+        ElectricalDiagram {
+            elements: vec![
+                create_test_element("camera-1"),
+                create_test_element("display-1"),
+                create_test_element("proc-1"),
+            ],
+            ..test_support::create_test_diagram(vec![
+                create_test_connection("camera-1", "display-1", SignalType::Video),
+                create_test_connection("proc-1", "camera-1", SignalType::Control),
+                create_test_connection("proc-1", "display-1", SignalType::Control),
+            ])
+        }
+    }
+
+    /// Checks that every opened tag is closed in order
+    fn is_well_formed(xml: &str) -> bool {
+        let mut stack: Vec<String> = Vec::new();
+        let mut rest = xml;
+
+        while let Some(start) = rest.find('<') {
+            let Some(end) = rest[start..].find('>') else {
+                return false;
+            };
+            let tag = &rest[start + 1..start + end];
+            rest = &rest[start + end + 1..];
+
+            if tag.starts_with('?') || tag.ends_with('/') {
+                continue;
+            }
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            if let Some(closing) = name.strip_prefix('/') {
+                if stack.pop().as_deref() != Some(closing) {
+                    return false;
+                }
+            } else {
+                stack.push(name.to_string());
+            }
+        }
+
+        stack.is_empty()
+    }
+
+    #[test]
+    fn test_graphml_well_formed_with_counts() {
+        let xml = render_graphml(&create_test_diagram());
+
+        assert!(is_well_formed(&xml));
+        assert_eq!(xml.matches("<node ").count(), 3);
+        assert_eq!(xml.matches("<edge ").count(), 3);
+        assert!(xml.contains("<data key=\"signal\">video</data>"));
+    }
+
+    #[test]
+    fn test_graphml_adds_nodes_for_unknown_endpoints() {
+        let mut diagram = create_test_diagram();
+        diagram.connections.push(create_test_connection(
+            "mic-1",
+            "display-1",
+            SignalType::Audio,
+        ));
+
+        let xml = render_graphml(&diagram);
+
+        assert_eq!(xml.matches("<node ").count(), 4);
+        assert!(xml.contains("<node id=\"mic-1\">"));
+    }
+
+    #[test]
+    fn test_write_graphml_creates_file() {
+        let file = tempfile::NamedTempFile::with_suffix(".graphml").unwrap();
+        let path = file.path().to_str().unwrap();
+
+        write_graphml(&create_test_diagram(), path).unwrap();
+
+        let written = std::fs::read_to_string(path).unwrap();
+        assert!(written.starts_with("<?xml"));
+    }
+}
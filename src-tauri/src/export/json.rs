@@ -0,0 +1,158 @@
+//! JSON Export Module
+//!
+//! Saves the raw drawing model as pretty-printed JSON for interchange with
+//! other tools, and reads it back.
+
+use super::error::ExportError;
+use super::pdf::DrawingInput;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// JSON Export Result
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonExportResult {
+    pub file_path: String,
+    pub file_size_bytes: u64,
+    pub generated_at: String,
+}
+
+// ============================================================================
+// JSON Export and Import
+// ============================================================================
+
+/// Writes a drawing's layers, elements and properties to `output_path` as
+/// pretty-printed JSON, in the same shape the frontend sends
+pub fn write_drawing_json(
+    drawing: &DrawingInput,
+    output_path: &str,
+) -> Result<JsonExportResult, ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+
+    let json = serde_json::to_string_pretty(drawing)
+        .map_err(|e| ExportError::WriteError(e.to_string()))?;
+    std::fs::write(output_path, &json).map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    Ok(JsonExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes: json.len() as u64,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Reads a drawing previously written by [`write_drawing_json`]
+pub fn read_drawing_json(input_path: &str) -> Result<DrawingInput, ExportError> {
+    if input_path.is_empty() {
+        return Err(ExportError::EmptyInputPath);
+    }
+
+    let json =
+        std::fs::read_to_string(input_path).map_err(|e| ExportError::ReadError(e.to_string()))?;
+    serde_json::from_str(&json).map_err(|e| ExportError::ReadError(e.to_string()))
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to save a drawing as a JSON file
+#[tauri::command]
+pub fn export_to_json(
+    drawing: DrawingInput,
+    output_path: String,
+) -> Result<JsonExportResult, ExportError> {
+    write_drawing_json(&drawing, &output_path)
+}
+
+/// Tauri command to load a drawing from a JSON file
+#[tauri::command]
+pub fn import_drawing_json(input_path: String) -> Result<DrawingInput, ExportError> {
+    read_drawing_json(&input_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingElement, DrawingLayer, DrawingType, ElementType, LayerType};
+
+    fn create_test_drawing() -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![DrawingLayer {
+                id: "layer-1".to_string(),
+                name: "AV Elements".to_string(),
+                layer_type: LayerType::AvElements,
+                is_locked: false,
+                is_visible: true,
+                elements: vec![DrawingElement {
+                    id: "cable-1".to_string(),
+                    element_type: ElementType::Cable,
+                    x: 10.0,
+                    y: 20.0,
+                    rotation: 90.0,
+                    properties: serde_json::json!({ "x2": 50.0, "signal_type": "video" }),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_drawing_json_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("drawing.json")
+            .to_string_lossy()
+            .into_owned();
+        let drawing = create_test_drawing();
+
+        let result = write_drawing_json(&drawing, &path).unwrap();
+        let loaded = read_drawing_json(&path).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(result.file_size_bytes, written.len() as u64);
+        assert!(written.contains("\n  \"roomId\": \"room-1\""));
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&drawing).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_paths_rejected() {
+        assert!(matches!(
+            write_drawing_json(&create_test_drawing(), ""),
+            Err(ExportError::EmptyOutputPath)
+        ));
+        assert!(matches!(
+            read_drawing_json(""),
+            Err(ExportError::EmptyInputPath)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_json_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("drawing.json")
+            .to_string_lossy()
+            .into_owned();
+        std::fs::write(&path, "{\"id\": \"drawing-1\"}").unwrap();
+
+        assert!(matches!(
+            read_drawing_json(&path),
+            Err(ExportError::ReadError(_))
+        ));
+    }
+}
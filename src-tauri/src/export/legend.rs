@@ -0,0 +1,384 @@
+//! Signal Legend Export
+//!
+//! Renders the signal-type color and line-style key as a small standalone
+//! PNG for use in documentation outside a drawing, and holds the cable color
+//! scheme and legend layout shared by the PDF and SVG exporters.
+
+use super::error::ExportError;
+use super::pdf::{DrawingLayer, ElementType};
+use super::raster::Canvas;
+use crate::drawings::SignalType;
+use serde::{Deserialize, Serialize};
+
+/// Supported output resolution range, in dots per inch
+const MIN_DPI: u32 = 36;
+const MAX_DPI: u32 = 1200;
+
+/// Legend layout in points (1/72 inch)
+pub(crate) const PADDING: f64 = 6.0;
+pub(crate) const ROW_HEIGHT: f64 = 14.0;
+pub(crate) const SWATCH_LENGTH: f64 = 36.0;
+const SWATCH_THICKNESS: f64 = 2.0;
+pub(crate) const LABEL_GAP: f64 = 6.0;
+
+/// Glyph cell of the built-in 5x7 label font, one point per font pixel
+pub(crate) const GLYPH_WIDTH: f64 = 6.0;
+const GLYPH_HEIGHT: f64 = 7.0;
+
+/// Signal types in legend order
+const LEGEND_SIGNALS: [SignalType; 5] = [
+    SignalType::Video,
+    SignalType::Audio,
+    SignalType::Control,
+    SignalType::Power,
+    SignalType::Network,
+];
+
+// ============================================================================
+// Signal Styles
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineStyle {
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalStyle {
+    /// RGB stroke color
+    pub color: [u8; 3],
+    pub line_style: LineStyle,
+}
+
+/// Returns the color and line style used to draw a signal type
+pub fn signal_style(signal_type: SignalType) -> SignalStyle {
+    let (color, line_style) = match signal_type {
+        SignalType::Video => ([0, 90, 200], LineStyle::Solid),
+        SignalType::Audio => ([0, 150, 70], LineStyle::Solid),
+        SignalType::Control => ([230, 120, 0], LineStyle::Dashed),
+        SignalType::Power => ([200, 30, 30], LineStyle::Solid),
+        SignalType::Network => ([120, 60, 180], LineStyle::Dotted),
+    };
+    SignalStyle { color, line_style }
+}
+
+/// Cable stroke colors by signal type, overridable per export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalColorScheme {
+    pub video: [u8; 3],
+    pub audio: [u8; 3],
+    pub control: [u8; 3],
+    pub power: [u8; 3],
+    pub network: [u8; 3],
+}
+
+impl Default for SignalColorScheme {
+    fn default() -> Self {
+        Self {
+            video: signal_style(SignalType::Video).color,
+            audio: signal_style(SignalType::Audio).color,
+            control: signal_style(SignalType::Control).color,
+            power: signal_style(SignalType::Power).color,
+            network: signal_style(SignalType::Network).color,
+        }
+    }
+}
+
+impl SignalColorScheme {
+    /// RGB stroke color for a signal type
+    pub fn color(&self, signal_type: SignalType) -> [u8; 3] {
+        match signal_type {
+            SignalType::Video => self.video,
+            SignalType::Audio => self.audio,
+            SignalType::Control => self.control,
+            SignalType::Power => self.power,
+            SignalType::Network => self.network,
+        }
+    }
+}
+
+/// Reads the signal type a cable element carries in its `signal_type`
+/// property, if any
+pub(crate) fn cable_signal_type(properties: &serde_json::Value) -> Option<SignalType> {
+    properties
+        .get("signal_type")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+/// Signal types of the cables in the given layers, in legend order
+pub(crate) fn signals_present(layers: &[&DrawingLayer]) -> Vec<SignalType> {
+    let present: Vec<SignalType> = layers
+        .iter()
+        .flat_map(|l| &l.elements)
+        .filter(|e| e.element_type == ElementType::Cable)
+        .filter_map(|e| cable_signal_type(&e.properties))
+        .collect();
+
+    LEGEND_SIGNALS
+        .into_iter()
+        .filter(|s| present.contains(s))
+        .collect()
+}
+
+/// Legend label for a signal type
+pub(crate) fn signal_label(signal_type: SignalType) -> &'static str {
+    match signal_type {
+        SignalType::Video => "VIDEO",
+        SignalType::Audio => "AUDIO",
+        SignalType::Control => "CONTROL",
+        SignalType::Power => "POWER",
+        SignalType::Network => "NETWORK",
+    }
+}
+
+/// 5x7 bitmap for an uppercase letter, one row per byte, high bit on the left
+///
+/// Only the letters used in legend labels are defined; others render blank.
+fn glyph(c: char) -> [u8; 7] {
+    match c {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'N' => [0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        _ => [0; 7],
+    }
+}
+
+// ============================================================================
+// Legend Rendering (internal)
+// ============================================================================
+
+/// Draws a horizontal swatch of a signal's line style centered on `y`
+fn draw_swatch(canvas: &mut Canvas, x: f64, y: f64, style: SignalStyle) {
+    let (dash, gap) = match style.line_style {
+        LineStyle::Solid => (SWATCH_LENGTH, 0.0),
+        LineStyle::Dashed => (6.0, 3.0),
+        LineStyle::Dotted => (SWATCH_THICKNESS, SWATCH_THICKNESS),
+    };
+
+    let mut offset = 0.0;
+    while offset < SWATCH_LENGTH {
+        let length = dash.min(SWATCH_LENGTH - offset);
+        canvas.fill_rect(
+            x + offset,
+            y - SWATCH_THICKNESS / 2.0,
+            length,
+            SWATCH_THICKNESS,
+            style.color,
+        );
+        offset += dash + gap;
+    }
+}
+
+/// Draws text in the built-in 5x7 font with its top left at (x, y)
+fn draw_text(canvas: &mut Canvas, x: f64, y: f64, text: &str) {
+    for (i, c) in text.chars().enumerate() {
+        let left = x + i as f64 * GLYPH_WIDTH;
+        for (row, bits) in glyph(c).iter().enumerate() {
+            for col in 0..5 {
+                if bits & (0x10 >> col) != 0 {
+                    canvas.fill_rect(left + col as f64, y + row as f64, 1.0, 1.0, [0, 0, 0]);
+                }
+            }
+        }
+    }
+}
+
+/// Draws the legend at the given resolution
+fn render_signal_legend(dpi: u32) -> Canvas {
+    let longest_label = LEGEND_SIGNALS
+        .iter()
+        .map(|s| signal_label(*s).len())
+        .max()
+        .unwrap_or_default();
+    let width = PADDING * 2.0 + SWATCH_LENGTH + LABEL_GAP + longest_label as f64 * GLYPH_WIDTH;
+    let height = PADDING * 2.0 + LEGEND_SIGNALS.len() as f64 * ROW_HEIGHT;
+
+    let mut canvas = Canvas::new(width, height, dpi);
+    for (row, signal_type) in LEGEND_SIGNALS.iter().enumerate() {
+        let center_y = PADDING + (row as f64 + 0.5) * ROW_HEIGHT;
+        draw_swatch(&mut canvas, PADDING, center_y, signal_style(*signal_type));
+        draw_text(
+            &mut canvas,
+            PADDING + SWATCH_LENGTH + LABEL_GAP,
+            center_y - GLYPH_HEIGHT / 2.0,
+            signal_label(*signal_type),
+        );
+    }
+
+    canvas
+}
+
+// ============================================================================
+// Legend Export
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegendExportResult {
+    pub file_path: String,
+    pub width_px: u32,
+    pub height_px: u32,
+    pub file_size_bytes: u64,
+    pub generated_at: String,
+}
+
+/// Renders the signal legend and writes it to `output_path` as a PNG
+///
+/// The image is sized in points, so its pixel dimensions scale with `dpi`,
+/// which is also recorded in the file's physical pixel size.
+pub fn write_signal_legend_png(
+    output_path: &str,
+    dpi: u32,
+) -> Result<LegendExportResult, ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+    if !(MIN_DPI..=MAX_DPI).contains(&dpi) {
+        return Err(ExportError::InvalidConfig(format!(
+            "DPI must be between {} and {}, got {}",
+            MIN_DPI, MAX_DPI, dpi
+        )));
+    }
+
+    let canvas = render_signal_legend(dpi);
+    let file_size_bytes = canvas.write_png(output_path)?;
+
+    Ok(LegendExportResult {
+        file_path: output_path.to_string(),
+        width_px: canvas.width,
+        height_px: canvas.height,
+        file_size_bytes,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export the signal-type legend as a standalone PNG
+#[tauri::command]
+pub fn export_signal_legend_png(
+    output_path: String,
+    dpi: u32,
+) -> Result<LegendExportResult, ExportError> {
+    write_signal_legend_png(&output_path, dpi)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingElement, LayerType};
+    use std::fs::File;
+
+    fn read_png_dimensions(path: &str) -> (u32, u32) {
+        let decoder = png::Decoder::new(File::open(path).unwrap());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        (info.width, info.height)
+    }
+
+    #[test]
+    fn test_legend_png_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("legend.png").to_string_lossy().into_owned();
+
+        let result = write_signal_legend_png(&path, 96).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+        assert_eq!(result.file_size_bytes, bytes.len() as u64);
+        assert_eq!(
+            read_png_dimensions(&path),
+            (result.width_px, result.height_px)
+        );
+    }
+
+    #[test]
+    fn test_legend_dimensions_scale_with_dpi() {
+        let dir = tempfile::tempdir().unwrap();
+        let low = dir.path().join("low.png").to_string_lossy().into_owned();
+        let high = dir.path().join("high.png").to_string_lossy().into_owned();
+
+        write_signal_legend_png(&low, 72).unwrap();
+        write_signal_legend_png(&high, 144).unwrap();
+
+        let (low_w, low_h) = read_png_dimensions(&low);
+        let (high_w, high_h) = read_png_dimensions(&high);
+        assert_eq!(high_w, low_w * 2);
+        assert_eq!(high_h, low_h * 2);
+    }
+
+    #[test]
+    fn test_color_scheme_override() {
+        let scheme: SignalColorScheme =
+            serde_json::from_str(r#"{"video":[1,2,3],"audio":[0,150,70],"control":[230,120,0],"power":[200,30,30],"network":[120,60,180]}"#)
+                .unwrap();
+
+        assert_eq!(scheme.color(SignalType::Video), [1, 2, 3]);
+        assert_eq!(
+            scheme.color(SignalType::Audio),
+            SignalColorScheme::default().audio
+        );
+    }
+
+    #[test]
+    fn test_signals_present_in_legend_order() {
+        let cable = |id: &str, signal_type: Option<&str>| DrawingElement {
+            id: id.to_string(),
+            element_type: ElementType::Cable,
+            x: 0.0,
+            y: 0.0,
+            rotation: 0.0,
+            properties: match signal_type {
+                Some(s) => serde_json::json!({ "signal_type": s }),
+                None => serde_json::json!({}),
+            },
+        };
+        let layer = DrawingLayer {
+            id: "layer-1".to_string(),
+            name: "AV Elements".to_string(),
+            layer_type: LayerType::AvElements,
+            is_locked: false,
+            is_visible: true,
+            elements: vec![
+                cable("c1", Some("power")),
+                cable("c2", Some("video")),
+                cable("c3", Some("power")),
+                cable("c4", None),
+            ],
+        };
+
+        assert_eq!(
+            signals_present(&[&layer]),
+            vec![SignalType::Video, SignalType::Power]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_dpi_rejected() {
+        let result = write_signal_legend_png("/tmp/legend.png", 0);
+        assert!(matches!(result, Err(ExportError::InvalidConfig(_))));
+    }
+}
@@ -0,0 +1,240 @@
+//! Connection Matrix Export
+//!
+//! Builds an N×N device connection table from an electrical diagram for
+//! documentation, and writes it out as CSV.
+
+use super::error::ExportError;
+use crate::drawings::{ElectricalDiagram, ElementType, EquipmentInput, SignalType};
+use csv::Writer;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Connection Matrix
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionMatrix {
+    /// Equipment ids, in row and column order
+    pub device_ids: Vec<String>,
+    /// Display labels for each device
+    pub labels: Vec<String>,
+    /// `cells[from][to]` lists the signals from one device to another,
+    /// joined with " / ", or is empty when they are not connected
+    pub cells: Vec<Vec<String>>,
+}
+
+/// Returns the display name of a signal type
+fn signal_name(signal_type: SignalType) -> &'static str {
+    match signal_type {
+        SignalType::Video => "Video",
+        SignalType::Audio => "Audio",
+        SignalType::Control => "Control",
+        SignalType::Power => "Power",
+        SignalType::Network => "Network",
+    }
+}
+
+/// Builds the device connection matrix for a diagram
+///
+/// Devices are the diagram's equipment elements followed by any connection
+/// endpoints without an element. Labels come from the catalog when the
+/// equipment is known, otherwise the equipment id is used.
+pub fn connection_matrix(
+    diagram: &ElectricalDiagram,
+    equipment_catalog: &[EquipmentInput],
+) -> ConnectionMatrix {
+    let mut device_ids: Vec<String> = Vec::new();
+
+    let element_ids = diagram
+        .elements
+        .iter()
+        .filter(|e| e.element_type == ElementType::Equipment)
+        .filter_map(|e| e.properties["equipment_id"].as_str());
+    let endpoint_ids = diagram
+        .connections
+        .iter()
+        .flat_map(|c| [c.from_equipment_id.as_str(), c.to_equipment_id.as_str()]);
+
+    for id in element_ids.chain(endpoint_ids) {
+        if !device_ids.iter().any(|d| d == id) {
+            device_ids.push(id.to_string());
+        }
+    }
+
+    let labels = device_ids
+        .iter()
+        .map(|id| {
+            equipment_catalog
+                .iter()
+                .find(|e| e.id == *id)
+                .map(|e| format!("{} {}", e.manufacturer, e.model))
+                .unwrap_or_else(|| id.clone())
+        })
+        .collect();
+
+    let mut cells = vec![vec![String::new(); device_ids.len()]; device_ids.len()];
+    let index_of = |id: &str| device_ids.iter().position(|d| d == id);
+
+    for connection in &diagram.connections {
+        let (Some(from), Some(to)) = (
+            index_of(&connection.from_equipment_id),
+            index_of(&connection.to_equipment_id),
+        ) else {
+            continue;
+        };
+
+        let name = signal_name(connection.signal_type);
+        let cell = &mut cells[from][to];
+        if cell.is_empty() {
+            cell.push_str(name);
+        } else if !cell.split(" / ").any(|s| s == name) {
+            cell.push_str(" / ");
+            cell.push_str(name);
+        }
+    }
+
+    ConnectionMatrix {
+        device_ids,
+        labels,
+        cells,
+    }
+}
+
+// ============================================================================
+// CSV Export
+// ============================================================================
+
+/// Writes the matrix to `output_path` as CSV
+///
+/// The header row and first column hold device labels; rows are sources
+/// and columns are destinations.
+pub fn write_matrix_csv(matrix: &ConnectionMatrix, output_path: &str) -> Result<(), ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+
+    let mut writer =
+        Writer::from_path(output_path).map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    let header = std::iter::once("").chain(matrix.labels.iter().map(String::as_str));
+    writer
+        .write_record(header)
+        .map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    for (label, row) in matrix.labels.iter().zip(&matrix.cells) {
+        let record = std::iter::once(label.as_str()).chain(row.iter().map(String::as_str));
+        writer
+            .write_record(record)
+            .map_err(|e| ExportError::WriteError(e.to_string()))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| ExportError::WriteError(e.to_string()))
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to compute a diagram's device connection matrix
+#[tauri::command]
+pub fn compute_connection_matrix(
+    diagram: ElectricalDiagram,
+    equipment_catalog: Vec<EquipmentInput>,
+) -> Result<ConnectionMatrix, String> {
+    Ok(connection_matrix(&diagram, &equipment_catalog))
+}
+
+/// Tauri command to export a diagram's device connection matrix as CSV
+#[tauri::command]
+pub fn export_connection_matrix_csv(
+    diagram: ElectricalDiagram,
+    equipment_catalog: Vec<EquipmentInput>,
+    output_path: String,
+) -> Result<(), ExportError> {
+    write_matrix_csv(
+        &connection_matrix(&diagram, &equipment_catalog),
+        &output_path,
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{self, create_test_connection, create_test_equipment};
+    use crate::drawings::{DrawingElement, EquipmentCategory};
+
+    fn create_test_element(equipment_id: &str) -> DrawingElement {
+        DrawingElement {
+            id: format!("elem-{}", equipment_id),
+            element_type: ElementType::Equipment,
+            x: 100.0,
+            y: 100.0,
+            rotation: 0.0,
+            label: format!("Label {}", equipment_id),
+            properties: serde_json::json!({ "equipment_id": equipment_id }),
+        }
+    }
+
+    fn create_test_diagram() -> ElectricalDiagram {
+        ElectricalDiagram {
+            elements: vec![
+                create_test_element("camera-1"),
+                create_test_element("display-1"),
+                create_test_element("proc-1"),
+            ],
+            ..test_support::create_test_diagram(vec![
+                create_test_connection("camera-1", "display-1", SignalType::Video),
+                create_test_connection("proc-1", "display-1", SignalType::Control),
+            ])
+        }
+    }
+
+    fn create_test_catalog() -> Vec<EquipmentInput> {
+        vec![EquipmentInput {
+            manufacturer: "Poly".to_string(),
+            model: "EagleEye".to_string(),
+            ..create_test_equipment("camera-1", EquipmentCategory::Video, "cameras")
+        }]
+    }
+
+    #[test]
+    fn test_matrix_marks_camera_to_display_video() {
+        let matrix = connection_matrix(&create_test_diagram(), &create_test_catalog());
+
+        assert_eq!(matrix.device_ids, vec!["camera-1", "display-1", "proc-1"]);
+        assert_eq!(matrix.labels[0], "Poly EagleEye");
+        assert_eq!(matrix.labels[1], "display-1");
+        assert_eq!(matrix.cells[0][1], "Video");
+        assert_eq!(matrix.cells[2][1], "Control");
+    }
+
+    #[test]
+    fn test_matrix_unconnected_cells_blank() {
+        let matrix = connection_matrix(&create_test_diagram(), &create_test_catalog());
+
+        assert!(matrix.cells[1][0].is_empty());
+        assert!(matrix.cells[0][2].is_empty());
+        assert!(matrix.cells[0][0].is_empty());
+    }
+
+    #[test]
+    fn test_write_matrix_csv() {
+        let file = tempfile::NamedTempFile::with_suffix(".csv").unwrap();
+        let path = file.path().to_str().unwrap();
+        let matrix = connection_matrix(&create_test_diagram(), &create_test_catalog());
+
+        write_matrix_csv(&matrix, path).unwrap();
+
+        let written = std::fs::read_to_string(path).unwrap();
+        let lines: Vec<&str> = written.lines().collect();
+        assert_eq!(lines[0], ",Poly EagleEye,display-1,proc-1");
+        assert_eq!(lines[1], "Poly EagleEye,,Video,");
+    }
+}
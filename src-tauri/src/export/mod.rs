@@ -1,8 +1,44 @@
 //! Export Module
 //!
 //! This module handles exporting drawings to various formats.
-//! Currently supports PDF export with title block and page layout configuration.
+//! Currently supports PDF export with title block and page layout configuration,
+//! SVG export for files or the clipboard, DXF export for CAD, GraphML export
+//! of the signal graph, JSON export and import of the raw drawing model,
+//! device connection matrices and BOM estimates as CSV,
+//! one-page room summary and equipment datasheet PDFs, PNG rasters of
+//! drawings and a standalone signal legend, and re-keying drawing ids for
+//! import into another project.
 
+pub mod bom;
+pub mod config_check;
+pub mod datasheet;
+pub mod dxf;
+pub mod error;
+pub mod estimate;
+pub mod graphml;
+pub mod json;
+pub mod legend;
+pub mod matrix;
 pub mod pdf;
+pub mod printer;
+pub mod raster;
+pub mod rekey;
+pub mod room_summary;
+pub mod svg;
 
+pub use bom::*;
+pub use config_check::*;
+pub use datasheet::*;
+pub use dxf::*;
+pub use error::*;
+pub use estimate::*;
+pub use graphml::*;
+pub use json::*;
+pub use legend::*;
+pub use matrix::*;
 pub use pdf::*;
+pub use printer::*;
+pub use raster::*;
+pub use rekey::*;
+pub use room_summary::*;
+pub use svg::*;
@@ -3,7 +3,20 @@
 //! Generates PDF documents from drawing data with configurable
 //! title blocks and page layouts.
 
+use super::legend::{self, SignalColorScheme};
+use crate::drawings::SignalType;
+use printpdf::path::{PaintMode, WindingOrder};
+use printpdf::utils::calculate_points_for_circle;
+use printpdf::{
+    BuiltinFont, Color, ColorBits, ColorSpace, CurTransMat, Image, ImageFilter, ImageTransform,
+    ImageXObject, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference,
+    PdfPageReference, Point, Polygon, Pt, Px, Rgb,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufWriter;
+use std::time::Duration;
 
 // ============================================================================
 // Page Size Constants
@@ -111,6 +124,43 @@ pub struct TitleBlock {
     pub scale: String,
     pub sheet_number: u32,
     pub total_sheets: u32,
+    /// Firm contact details shown in the title block, if provided
+    #[serde(default)]
+    pub company_phone: Option<String>,
+    #[serde(default)]
+    pub company_email: Option<String>,
+    #[serde(default)]
+    pub company_url: Option<String>,
+    /// Firm logo image (PNG or JPEG) drawn at the top of the title block
+    #[serde(default)]
+    pub logo_path: Option<String>,
+    /// Earlier revisions, oldest first, shown in the revision table
+    #[serde(default)]
+    pub revisions: Vec<RevisionEntry>,
+}
+
+/// One row of a drawing's revision history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionEntry {
+    /// Revision letter or number, e.g. "B"
+    pub revision: String,
+    pub date: String,
+    pub description: String,
+    /// Initials of whoever made the revision
+    pub revised_by: String,
+}
+
+impl RevisionEntry {
+    /// Cell values in revision table column order
+    pub(crate) fn cells(&self) -> [&str; 4] {
+        [
+            &self.revision,
+            &self.date,
+            &self.description,
+            &self.revised_by,
+        ]
+    }
 }
 
 impl TitleBlock {
@@ -127,8 +177,102 @@ impl TitleBlock {
             scale: "NTS".to_string(), // Not To Scale
             sheet_number: 1,
             total_sheets: 1,
+            company_phone: None,
+            company_email: None,
+            company_url: None,
+            logo_path: None,
+            revisions: Vec::new(),
+        }
+    }
+
+    /// Rows of the revision table, oldest first
+    ///
+    /// The current `revision` is the last row, taking the title block date
+    /// and drafter, unless the history already lists it.
+    pub fn revision_rows(&self) -> Vec<RevisionEntry> {
+        let mut rows = self.revisions.clone();
+        let current = self.revision.trim();
+        if !current.is_empty() && !rows.iter().any(|r| r.revision.trim() == current) {
+            rows.push(RevisionEntry {
+                revision: current.to_string(),
+                date: self.date.clone(),
+                description: String::new(),
+                revised_by: self.drawn_by.clone(),
+            });
         }
+        rows
+    }
+
+    /// Returns a copy with the contact fields validated and normalized
+    ///
+    /// Blank values are dropped, emails are lowercased and URLs without a
+    /// scheme get `https://`. Clearly malformed values are rejected.
+    pub fn normalized_contact(&self) -> Result<Self, String> {
+        let mut normalized = self.clone();
+        normalized.company_phone = normalize_contact_field(&self.company_phone, normalize_phone)
+            .map_err(|v| format!("Invalid company phone: '{}'", v))?;
+        normalized.company_email = normalize_contact_field(&self.company_email, normalize_email)
+            .map_err(|v| format!("Invalid company email: '{}'", v))?;
+        normalized.company_url = normalize_contact_field(&self.company_url, normalize_url)
+            .map_err(|v| format!("Invalid company URL: '{}'", v))?;
+        Ok(normalized)
+    }
+}
+
+/// Applies a normalizer to an optional contact value, treating blank as unset
+///
+/// On failure, returns the original value for the error message.
+fn normalize_contact_field(
+    value: &Option<String>,
+    normalize: fn(&str) -> Option<String>,
+) -> Result<Option<String>, String> {
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(v) => normalize(v).map(Some).ok_or_else(|| v.to_string()),
+    }
+}
+
+/// Collapses whitespace in a phone number with 7 to 15 digits
+fn normalize_phone(value: &str) -> Option<String> {
+    let allowed = |c: char| c.is_ascii_digit() || c.is_whitespace() || "+-().".contains(c);
+    let digits = value.chars().filter(char::is_ascii_digit).count();
+    if !value.chars().all(allowed) || !(7..=15).contains(&digits) {
+        return None;
     }
+    Some(value.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Lowercases an email with a single `@` and a dotted domain
+fn normalize_email(value: &str) -> Option<String> {
+    let (local, domain) = value.split_once('@')?;
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !value.chars().any(char::is_whitespace);
+    valid.then(|| value.to_lowercase())
+}
+
+/// Adds `https://` to a bare host and checks the host looks like a domain
+fn normalize_url(value: &str) -> Option<String> {
+    let lower = value.to_lowercase();
+    let url = if lower.starts_with("http://") || lower.starts_with("https://") {
+        value.to_string()
+    } else {
+        format!("https://{}", value)
+    };
+
+    let rest = url.split_once("://")?.1;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let valid = host.contains('.')
+        && !host.starts_with(['.', '-'])
+        && !host.ends_with('.')
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+        && !url.chars().any(char::is_whitespace);
+    valid.then_some(url)
 }
 
 // ============================================================================
@@ -211,6 +355,18 @@ pub struct PdfExportConfig {
     pub title_block: TitleBlock,
     pub include_layer_info: bool,
     pub include_timestamp: bool,
+    /// Reject drawings whose element ids are not unique across layers
+    #[serde(default)]
+    pub reject_duplicate_ids: bool,
+    /// Font family for labels and the title block; Helvetica when unset
+    #[serde(default)]
+    pub font_family: Option<String>,
+    /// Cable colors by signal type
+    #[serde(default)]
+    pub signal_colors: SignalColorScheme,
+    /// Layer types to render; every visible layer when unset
+    #[serde(default)]
+    pub include_layers: Option<Vec<LayerType>>,
 }
 
 impl PdfExportConfig {
@@ -220,8 +376,22 @@ impl PdfExportConfig {
             title_block,
             include_layer_info: true,
             include_timestamp: true,
+            reject_duplicate_ids: false,
+            font_family: None,
+            signal_colors: SignalColorScheme::default(),
+            include_layers: None,
         }
     }
+
+    /// Whether a layer is rendered: it must be visible and, when
+    /// `include_layers` is set, of an included type
+    pub fn renders_layer(&self, layer: &DrawingLayer) -> bool {
+        layer.is_visible
+            && match &self.include_layers {
+                Some(types) => types.contains(&layer.layer_type),
+                None => true,
+            }
+    }
 }
 
 // ============================================================================
@@ -235,112 +405,1055 @@ pub struct PdfExportResult {
     pub file_size_bytes: u64,
     pub page_count: u32,
     pub generated_at: String,
+    /// Elements that could not be rendered and were left out of the file
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 // ============================================================================
 // PDF Generator
 // ============================================================================
 
-/// Generates a PDF document from drawing data
+/// Generates a PDF document from drawing data and writes it to `output_path`
 ///
-/// Note: This is a structural implementation that creates PDF metadata
-/// and would normally integrate with a PDF generation library like
-/// `printpdf` or `lopdf`. The actual binary PDF generation is stubbed
-/// for the MVP, returning file metadata only.
+/// The drawing is laid out on a single page at the configured page size,
+/// with each visible drawing layer rendered as a PDF layer and the title
+/// block in the lower right corner of the drawable area. When the config
+/// lists `include_layers`, layers of other types are left out. Cables without
+/// endpoint coordinates and unreadable logo images are skipped and
+/// reported in `warnings`.
 pub fn generate_pdf(
     drawing: &DrawingInput,
     config: &PdfExportConfig,
     output_path: &str,
 ) -> Result<PdfExportResult, String> {
-    // Validate input
+    let sheet = drawing_sheet(drawing, config)?;
+
+    if output_path.is_empty() {
+        return Err("Output path cannot be empty".to_string());
+    }
+
+    let warnings = write_pdf_document(&[sheet], config, output_path)?;
+
+    let file_size_bytes = written_file_size(output_path)?;
+
+    Ok(PdfExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes,
+        page_count: 1,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings,
+    })
+}
+
+/// Validates a drawing for export and collects its visible layers into a sheet
+fn drawing_sheet<'a>(
+    drawing: &'a DrawingInput,
+    config: &PdfExportConfig,
+) -> Result<Sheet<'a>, String> {
     if drawing.layers.is_empty() {
         return Err("Drawing has no layers to export".to_string());
     }
 
+    if !drawing.layers.iter().any(|l| l.is_visible) {
+        return Err("Drawing has no visible layers to export".to_string());
+    }
+
+    let layers: Vec<&DrawingLayer> = drawing
+        .layers
+        .iter()
+        .filter(|l| config.renders_layer(l))
+        .collect();
+
+    if layers.is_empty() {
+        return Err("Drawing has no visible layers of the included types to export".to_string());
+    }
+
+    if config.reject_duplicate_ids {
+        if let Err(duplicates) = validate_element_ids(drawing) {
+            return Err(format!(
+                "Drawing has duplicate element ids: {}",
+                duplicates.join(", ")
+            ));
+        }
+    }
+
+    Ok(Sheet {
+        layers,
+        title_block: config.title_block.normalized_contact()?,
+    })
+}
+
+/// Generates one PDF with a page per drawing and writes it to `output_path`
+///
+/// Pages follow the order of `drawings`, and each title block is numbered
+/// as a sheet of the set. Drawings with no visible layers to render, or with
+/// duplicate element ids when those are rejected, are left out and
+/// reported in `warnings` rather than failing the whole set.
+pub fn generate_pdf_set(
+    drawings: &[DrawingInput],
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<PdfExportResult, String> {
+    if drawings.is_empty() {
+        return Err("No drawings to export".to_string());
+    }
+
     if output_path.is_empty() {
         return Err("Output path cannot be empty".to_string());
     }
 
-    // Count visible layers and elements
-    let visible_layers: Vec<&DrawingLayer> =
-        drawing.layers.iter().filter(|l| l.is_visible).collect();
+    let title_block = config.title_block.normalized_contact()?;
+    let mut warnings = Vec::new();
+    let mut sheets = Vec::new();
 
-    if visible_layers.is_empty() {
-        return Err("Drawing has no visible layers to export".to_string());
+    for drawing in drawings {
+        let layers: Vec<&DrawingLayer> = drawing
+            .layers
+            .iter()
+            .filter(|l| config.renders_layer(l))
+            .collect();
+
+        if layers.is_empty() {
+            let missing = match config.include_layers {
+                Some(_) => "no visible layers of the included types",
+                None => "no visible layers",
+            };
+            warnings.push(format!(
+                "Skipped drawing {}: {} to export",
+                drawing.id, missing
+            ));
+            continue;
+        }
+
+        if config.reject_duplicate_ids {
+            if let Err(duplicates) = validate_element_ids(drawing) {
+                warnings.push(format!(
+                    "Skipped drawing {}: duplicate element ids: {}",
+                    drawing.id,
+                    duplicates.join(", ")
+                ));
+                continue;
+            }
+        }
+
+        sheets.push(Sheet {
+            layers,
+            title_block: title_block.clone(),
+        });
     }
 
-    let element_count: usize = visible_layers.iter().map(|l| l.elements.len()).sum();
-
-    // Calculate page dimensions
-    let (page_width, page_height) = config.page_layout.effective_dimensions();
-    let (draw_width, draw_height) = config.page_layout.drawable_area();
-
-    // Generate PDF structure (actual PDF bytes would be created here)
-    let pdf_metadata = PdfMetadata {
-        title: config.title_block.drawing_title.clone(),
-        project: config.title_block.project_name.clone(),
-        drawing_number: config.title_block.drawing_number.clone(),
-        revision: config.title_block.revision.clone(),
-        created_date: config.title_block.date.clone(),
-        page_width,
-        page_height,
-        drawable_width: draw_width,
-        drawable_height: draw_height,
-        layer_count: visible_layers.len(),
-        element_count,
-    };
+    if sheets.is_empty() {
+        return Err("No drawings with visible layers to export".to_string());
+    }
+
+    let total_sheets = sheets.len() as u32;
+    for (sheet_number, sheet) in (1..).zip(&mut sheets) {
+        sheet.title_block.sheet_number = sheet_number;
+        sheet.title_block.total_sheets = total_sheets;
+    }
+
+    warnings.extend(write_pdf_document(&sheets, config, output_path)?);
 
-    // For MVP, we simulate file creation by calculating expected size
-    // In production, this would use printpdf or similar library
-    let estimated_size = estimate_pdf_size(&pdf_metadata);
+    let file_size_bytes = written_file_size(output_path)?;
 
     Ok(PdfExportResult {
         file_path: output_path.to_string(),
-        file_size_bytes: estimated_size,
-        page_count: 1, // Single page for now
+        file_size_bytes,
+        page_count: total_sheets,
         generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings,
+    })
+}
+
+// ============================================================================
+// Export Plan - pagination preview without writing a file
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PagePlan {
+    /// 1-indexed page number within the file
+    pub page_number: u32,
+    /// Elements on the page's visible layers
+    pub element_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfExportPlan {
+    pub page_count: u32,
+    pub pages: Vec<PagePlan>,
+}
+
+/// Projects the pages `generate_pdf` would write for a drawing, without
+/// rendering or touching disk
+///
+/// Lays the drawing out into a sheet the same way as the export and fails
+/// with the same validation errors, so empty or hidden layers are reported
+/// before the export is started.
+pub fn plan_pdf_export(
+    drawing: &DrawingInput,
+    config: &PdfExportConfig,
+) -> Result<PdfExportPlan, String> {
+    let sheet = drawing_sheet(drawing, config)?;
+
+    // A drawing is laid out on a single page
+    let pages = vec![PagePlan {
+        page_number: 1,
+        element_count: sheet.layers.iter().map(|l| l.elements.len()).sum(),
+    }];
+
+    Ok(PdfExportPlan {
+        page_count: pages.len() as u32,
+        pages,
     })
 }
 
 // ============================================================================
-// PDF Metadata (internal)
+// Drawing Validation
+// ============================================================================
+
+/// Checks that element ids are unique across all layers of a drawing
+///
+/// Returns the duplicated ids, each listed once in order of first repeat.
+pub fn validate_element_ids(drawing: &DrawingInput) -> Result<(), Vec<String>> {
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates: Vec<String> = Vec::new();
+
+    for element in drawing.layers.iter().flat_map(|l| &l.elements) {
+        if !seen.insert(element.id.as_str()) && !duplicates.contains(&element.id) {
+            duplicates.push(element.id.clone());
+        }
+    }
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(duplicates)
+    }
+}
+
+/// Returns the required layer types that the drawing does not contain
+pub fn validate_layers(drawing: &DrawingInput, required: &[LayerType]) -> Vec<LayerType> {
+    let mut missing: Vec<LayerType> = Vec::new();
+
+    for layer_type in required {
+        let present = drawing.layers.iter().any(|l| l.layer_type == *layer_type);
+        if !present && !missing.contains(layer_type) {
+            missing.push(*layer_type);
+        }
+    }
+
+    missing
+}
+
+// ============================================================================
+// Layer Bounds
 // ============================================================================
 
-#[derive(Debug)]
-#[allow(dead_code)] // Fields stored for future PDF binary generation
-struct PdfMetadata {
-    title: String,
-    project: String,
-    drawing_number: String,
-    revision: String,
-    created_date: String,
-    page_width: f64,
+/// Bounding box of a layer's element positions
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayerBounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+/// Computes the bounding box of each layer's element positions
+///
+/// Returns the bounds keyed by layer id. Layers without elements are omitted.
+pub fn layer_bounds(drawing: &DrawingInput) -> HashMap<String, LayerBounds> {
+    drawing
+        .layers
+        .iter()
+        .filter(|layer| !layer.elements.is_empty())
+        .map(|layer| {
+            let empty = LayerBounds {
+                min_x: f64::MAX,
+                min_y: f64::MAX,
+                max_x: f64::MIN,
+                max_y: f64::MIN,
+            };
+            let bounds = layer.elements.iter().fold(empty, |b, e| LayerBounds {
+                min_x: b.min_x.min(e.x),
+                min_y: b.min_y.min(e.y),
+                max_x: b.max_x.max(e.x),
+                max_y: b.max_y.max(e.y),
+            });
+            (layer.id.clone(), bounds)
+        })
+        .collect()
+}
+
+// ============================================================================
+// PDF Rendering (internal)
+// ============================================================================
+
+/// Size of the labeled box drawn for equipment, in points
+///
+/// Element shape sizes are shared with the raster exporter.
+pub(crate) const ELEMENT_BOX_WIDTH: f64 = 48.0;
+pub(crate) const ELEMENT_BOX_HEIGHT: f64 = 24.0;
+
+/// Radius of the placeholder glyph drawn for symbols, in points
+pub(crate) const SYMBOL_RADIUS: f64 = 6.0;
+
+/// Length and half-angle of dimension line arrowheads
+pub(crate) const ARROW_LENGTH: f64 = 6.0;
+pub(crate) const ARROW_HALF_ANGLE_DEG: f64 = 25.0;
+
+/// Title block size and text metrics, in points
+pub(crate) const TITLE_BLOCK_WIDTH: f64 = 252.0;
+const TITLE_BLOCK_ROW_HEIGHT: f64 = 12.0;
+const TITLE_BLOCK_FONT_SIZE: f32 = 8.0;
+/// Revision table columns and their widths, spanning the title block width
+pub(crate) const REVISION_COLUMNS: [(&str, f64); 4] = [
+    ("REV", 30.0),
+    ("DATE", 60.0),
+    ("DESCRIPTION", 122.0),
+    ("BY", 40.0),
+];
+pub(crate) const REVISION_ROW_HEIGHT: f64 = 12.0;
+/// Height of the logo band above the title block text, and its inner padding
+const LOGO_BAND_HEIGHT: f64 = 48.0;
+const LOGO_PADDING: f64 = 4.0;
+const LABEL_FONT_SIZE: f32 = 6.0;
+pub(crate) const TEXT_FONT_SIZE: f32 = 10.0;
+
+/// Converts a length in points to printpdf's millimeter unit
+pub(crate) fn pt(value: f64) -> Mm {
+    Mm::from(Pt(value as f32))
+}
+
+/// Maps the configured font family onto a standard PDF font
+pub(crate) fn builtin_font(font_family: Option<&str>) -> BuiltinFont {
+    match font_family.map(str::to_lowercase).as_deref() {
+        Some("times-roman") => BuiltinFont::TimesRoman,
+        Some("courier") => BuiltinFont::Courier,
+        _ => BuiltinFont::Helvetica,
+    }
+}
+
+/// Page geometry shared by the drawing helpers
+///
+/// Drawing coordinates have their origin at the top left of the drawable
+/// area with y pointing down; PDF coordinates start at the bottom left of
+/// the page with y pointing up.
+struct PageFrame {
     page_height: f64,
-    drawable_width: f64,
-    drawable_height: f64,
-    layer_count: usize,
-    element_count: usize,
+    margin_left: f64,
+    margin_top: f64,
+}
+
+impl PageFrame {
+    fn new(layout: &PageLayout) -> Self {
+        Self {
+            page_height: layout.effective_dimensions().1,
+            margin_left: layout.margin_left,
+            margin_top: layout.margin_top,
+        }
+    }
+
+    /// Converts a drawing coordinate to a PDF page coordinate in points
+    fn to_page(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.margin_left + x, self.page_height - self.margin_top - y)
+    }
+}
+
+/// Draws an outlined rectangle from its lower left corner, in page points
+pub(crate) fn stroke_rect(layer: &PdfLayerReference, x: f64, y: f64, width: f64, height: f64) {
+    layer.add_rect(
+        printpdf::Rect::new(pt(x), pt(y), pt(x + width), pt(y + height))
+            .with_mode(PaintMode::Stroke),
+    );
+}
+
+/// Sets the stroke color for the lines drawn after it
+fn set_stroke_color(layer: &PdfLayerReference, [r, g, b]: [u8; 3]) {
+    layer.set_outline_color(Color::Rgb(Rgb::new(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        None,
+    )));
+}
+
+/// Draws an open line through points given in page points
+pub(crate) fn stroke_line(layer: &PdfLayerReference, points: &[(f64, f64)]) {
+    layer.add_line(Line {
+        points: points
+            .iter()
+            .map(|&(x, y)| (Point::new(pt(x), pt(y)), false))
+            .collect(),
+        is_closed: false,
+    });
+}
+
+/// Reads a numeric property from an element, if present
+pub(crate) fn property_f64(element: &DrawingElement, key: &str) -> Option<f64> {
+    element.properties.get(key).and_then(|v| v.as_f64())
+}
+
+/// Reads a string property from an element, if present
+fn property_str<'a>(element: &'a DrawingElement, key: &str) -> Option<&'a str> {
+    element.properties.get(key).and_then(|v| v.as_str())
+}
+
+/// Draws a dimension line from the origin to `end` with an arrowhead at
+/// each end and its label at the midpoint
+fn draw_dimension(layer: &PdfLayerReference, font: &IndirectFontRef, end: (f64, f64), label: &str) {
+    stroke_line(layer, &[(0.0, 0.0), end]);
+
+    let angle = end.1.atan2(end.0);
+    let spread = ARROW_HALF_ANGLE_DEG.to_radians();
+    for (tip, direction) in [((0.0, 0.0), angle), (end, angle + std::f64::consts::PI)] {
+        let barb = |offset: f64| {
+            (
+                tip.0 + ARROW_LENGTH * (direction + offset).cos(),
+                tip.1 + ARROW_LENGTH * (direction + offset).sin(),
+            )
+        };
+        stroke_line(layer, &[barb(spread), tip, barb(-spread)]);
+    }
+
+    layer.use_text(
+        label,
+        LABEL_FONT_SIZE,
+        pt(end.0 / 2.0),
+        pt(end.1 / 2.0 + 2.0),
+        font,
+    );
+}
+
+/// Draws an element according to its type, rotated about its position
+///
+/// Equipment is a labeled box, cables a line between the `x1`/`y1` and
+/// `x2`/`y2` drawing coordinates in their properties, colored by signal
+/// type, text its `text` property, dimensions a line to `x2`/`y2` with
+/// arrowheads, and symbols a placeholder circle. Returns a warning instead
+/// of drawing a cable that is missing an endpoint coordinate.
+fn draw_element(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    frame: &PageFrame,
+    colors: &SignalColorScheme,
+    element: &DrawingElement,
+) -> Result<(), String> {
+    // Offset of a drawing coordinate from the element origin, in page space
+    let local = |x: f64, y: f64| (x - element.x, element.y - y);
+
+    let cable_ends = if element.element_type == ElementType::Cable {
+        let coord = |key| property_f64(element, key);
+        match (coord("x1"), coord("y1"), coord("x2"), coord("y2")) {
+            (Some(x1), Some(y1), Some(x2), Some(y2)) => Some((local(x1, y1), local(x2, y2))),
+            _ => {
+                return Err(format!(
+                    "Skipped cable {}: missing endpoint coordinates",
+                    element.id
+                ))
+            }
+        }
+    } else {
+        None
+    };
+
+    let (x, y) = frame.to_page(element.x, element.y);
+    layer.save_graphics_state();
+    layer.set_ctm(CurTransMat::Translate(Pt(x as f32), Pt(y as f32)));
+    // Rotation is clockwise, as in the raster and DXF exports; printpdf's
+    // rotation turns counter-clockwise on the y-up page
+    if element.rotation != 0.0 {
+        layer.set_ctm(CurTransMat::Rotate(-element.rotation as f32));
+    }
+
+    let label = property_str(element, "label").unwrap_or(element.id.as_str());
+    match element.element_type {
+        ElementType::Equipment => {
+            stroke_rect(
+                layer,
+                -ELEMENT_BOX_WIDTH / 2.0,
+                -ELEMENT_BOX_HEIGHT / 2.0,
+                ELEMENT_BOX_WIDTH,
+                ELEMENT_BOX_HEIGHT,
+            );
+            layer.use_text(
+                label,
+                LABEL_FONT_SIZE,
+                pt(-ELEMENT_BOX_WIDTH / 2.0 + 2.0),
+                pt(-2.0),
+                font,
+            );
+        }
+        ElementType::Cable => {
+            if let Some(signal_type) = legend::cable_signal_type(&element.properties) {
+                set_stroke_color(layer, colors.color(signal_type));
+            }
+            if let Some((start, end)) = cable_ends {
+                stroke_line(layer, &[start, end]);
+            }
+        }
+        ElementType::Text => {
+            let text = property_str(element, "text").unwrap_or(label);
+            layer.use_text(text, TEXT_FONT_SIZE, pt(0.0), pt(0.0), font);
+        }
+        ElementType::Dimension => {
+            let end = local(
+                property_f64(element, "x2").unwrap_or(element.x),
+                property_f64(element, "y2").unwrap_or(element.y),
+            );
+            let label = property_str(element, "text")
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{:.1}", end.0.hypot(end.1)));
+            draw_dimension(layer, font, end, &label);
+        }
+        ElementType::Symbol => {
+            layer.add_polygon(Polygon {
+                rings: vec![calculate_points_for_circle(
+                    Pt(SYMBOL_RADIUS as f32),
+                    Pt(0.0),
+                    Pt(0.0),
+                )],
+                mode: PaintMode::Stroke,
+                winding_order: WindingOrder::NonZero,
+            });
+        }
+    }
+
+    layer.restore_graphics_state();
+    Ok(())
+}
+
+/// Draws a key of the given signal types' cable colors in the lower left
+/// corner of the drawable area
+fn draw_signal_legend(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    layout: &PageLayout,
+    colors: &SignalColorScheme,
+    signals: &[SignalType],
+) {
+    if signals.is_empty() {
+        return;
+    }
+
+    let longest_label = signals
+        .iter()
+        .map(|s| legend::signal_label(*s).len())
+        .max()
+        .unwrap_or_default();
+    // Helvetica capitals average about 0.7em wide
+    let label_width = longest_label as f64 * f64::from(LABEL_FONT_SIZE) * 0.7;
+    let width = legend::PADDING * 2.0 + legend::SWATCH_LENGTH + legend::LABEL_GAP + label_width;
+    let height = legend::PADDING * 2.0 + signals.len() as f64 * legend::ROW_HEIGHT;
+    let (x, y) = (layout.margin_left, layout.margin_bottom);
+    stroke_rect(layer, x, y, width, height);
+
+    // Top row first, matching the order of the standalone legend
+    for (row, signal_type) in signals.iter().enumerate() {
+        let center_y = y + height - legend::PADDING - (row as f64 + 0.5) * legend::ROW_HEIGHT;
+        let swatch_x = x + legend::PADDING;
+
+        layer.save_graphics_state();
+        set_stroke_color(layer, colors.color(*signal_type));
+        stroke_line(
+            layer,
+            &[
+                (swatch_x, center_y),
+                (swatch_x + legend::SWATCH_LENGTH, center_y),
+            ],
+        );
+        layer.restore_graphics_state();
+
+        layer.use_text(
+            legend::signal_label(*signal_type),
+            LABEL_FONT_SIZE,
+            pt(swatch_x + legend::SWATCH_LENGTH + legend::LABEL_GAP),
+            pt(center_y - 2.0),
+            font,
+        );
+    }
+}
+
+/// Draws the title block in the lower right corner of the drawable area,
+/// returning its height
+///
+/// A logo, when given, is scaled to fit a band above the text rows.
+pub(crate) fn draw_title_block(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    layout: &PageLayout,
+    lines: &[String],
+    logo: Option<ImageXObject>,
+) -> f64 {
+    let (page_width, _) = layout.effective_dimensions();
+    let text_height = title_block_height(lines.len(), false);
+    let logo_height = if logo.is_some() {
+        LOGO_BAND_HEIGHT
+    } else {
+        0.0
+    };
+    let x = page_width - layout.margin_right - TITLE_BLOCK_WIDTH;
+    let y = layout.margin_bottom;
+
+    stroke_rect(layer, x, y, TITLE_BLOCK_WIDTH, text_height + logo_height);
+
+    for (row, line) in lines.iter().enumerate() {
+        let baseline = y + text_height - (row as f64 + 1.0) * TITLE_BLOCK_ROW_HEIGHT;
+        layer.use_text(
+            line.as_str(),
+            TITLE_BLOCK_FONT_SIZE,
+            pt(x + 4.0),
+            pt(baseline),
+            font,
+        );
+    }
+
+    if let Some(logo) = logo {
+        // At 72 dpi one image pixel is one point before scaling
+        let scale = ((TITLE_BLOCK_WIDTH - 2.0 * LOGO_PADDING) / logo.width.0 as f64)
+            .min((LOGO_BAND_HEIGHT - 2.0 * LOGO_PADDING) / logo.height.0 as f64);
+        Image::from(logo).add_to_layer(
+            layer.clone(),
+            ImageTransform {
+                translate_x: Some(pt(x + LOGO_PADDING)),
+                translate_y: Some(pt(y + text_height + LOGO_PADDING)),
+                scale_x: Some(scale as f32),
+                scale_y: Some(scale as f32),
+                dpi: Some(72.0),
+                ..Default::default()
+            },
+        );
+    }
+
+    text_height + logo_height
+}
+
+/// Height in points of a title block with `rows` text rows, and a logo band
+/// when `has_logo` is set
+pub(crate) fn title_block_height(rows: usize, has_logo: bool) -> f64 {
+    let logo_height = if has_logo { LOGO_BAND_HEIGHT } else { 0.0 };
+    rows as f64 * TITLE_BLOCK_ROW_HEIGHT + 4.0 + logo_height
+}
+
+/// Draws the revision table stacked on the title block, whose top edge is
+/// `bottom` points up the page
+///
+/// The header row sits on top and the newest revision nearest the title
+/// block. Nothing is drawn when there are no revisions.
+fn draw_revision_table(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    layout: &PageLayout,
+    rows: &[RevisionEntry],
+    bottom: f64,
+) {
+    if rows.is_empty() {
+        return;
+    }
+
+    let (page_width, _) = layout.effective_dimensions();
+    let x = page_width - layout.margin_right - TITLE_BLOCK_WIDTH;
+    let header = REVISION_COLUMNS.map(|(name, _)| name);
+    let cells = rows.iter().rev().map(RevisionEntry::cells);
+
+    for (index, row) in cells.chain([header]).enumerate() {
+        let y = bottom + index as f64 * REVISION_ROW_HEIGHT;
+        stroke_rect(layer, x, y, TITLE_BLOCK_WIDTH, REVISION_ROW_HEIGHT);
+
+        let mut cell_x = x;
+        for ((_, width), text) in REVISION_COLUMNS.iter().zip(row) {
+            layer.use_text(
+                text,
+                TITLE_BLOCK_FONT_SIZE,
+                pt(cell_x + 2.0),
+                pt(y + 3.0),
+                font,
+            );
+            cell_x += width;
+        }
+    }
+}
+
+/// Loads a title block logo, returning a warning when it cannot be used
+pub(crate) fn load_logo(path: &str) -> Result<ImageXObject, String> {
+    load_image(path).map_err(|e| format!("Skipped logo {}", e))
+}
+
+/// Loads a PNG or JPEG file as a PDF image
+///
+/// PNGs are decoded to 8-bit RGB with any transparency blended onto white;
+/// JPEGs are embedded as-is. Returns a message naming the file and why it
+/// could not be used.
+pub(crate) fn load_image(path: &str) -> Result<ImageXObject, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("{}: {}", path, e))?;
+
+    let logo = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        png_logo(&bytes)
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg_logo(bytes)
+    } else {
+        None
+    };
+    logo.ok_or_else(|| format!("{}: not a PNG or JPEG image", path))
+}
+
+/// Decodes a PNG into an RGB image XObject
+fn png_logo(bytes: &[u8]) -> Option<ImageXObject> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut buffer).ok()?;
+    let pixels = &buffer[..frame.buffer_size()];
+
+    // Blend a channel onto a white background
+    let blend = |value: u8, alpha: u8| {
+        let (value, alpha) = (value as u32, alpha as u32);
+        ((value * alpha + 255 * (255 - alpha)) / 255) as u8
+    };
+    let rgb: Vec<u8> = match frame.color_type {
+        png::ColorType::Rgb => pixels.to_vec(),
+        png::ColorType::Rgba => pixels
+            .chunks_exact(4)
+            .flat_map(|p| [blend(p[0], p[3]), blend(p[1], p[3]), blend(p[2], p[3])])
+            .collect(),
+        png::ColorType::Grayscale => pixels.iter().flat_map(|&g| [g, g, g]).collect(),
+        png::ColorType::GrayscaleAlpha => pixels
+            .chunks_exact(2)
+            .flat_map(|p| [blend(p[0], p[1]); 3])
+            .collect(),
+        png::ColorType::Indexed => return None,
+    };
+
+    Some(ImageXObject {
+        width: Px(frame.width as usize),
+        height: Px(frame.height as usize),
+        color_space: ColorSpace::Rgb,
+        bits_per_component: ColorBits::Bit8,
+        interpolate: true,
+        image_data: rgb,
+        image_filter: None,
+        smask: None,
+        clipping_bbox: None,
+    })
+}
+
+/// Wraps JPEG data as a DCT-encoded image XObject, reading its size and
+/// color components from the start-of-frame segment
+fn jpeg_logo(bytes: Vec<u8>) -> Option<ImageXObject> {
+    let read_u16 = |at: usize| Some(u16::from_be_bytes([*bytes.get(at)?, *bytes.get(at + 1)?]));
+
+    // Skip the SOI marker, then walk segments until a start-of-frame
+    let mut pos = 2;
+    let (width, height, components) = loop {
+        if *bytes.get(pos)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(pos + 1)?;
+        match marker {
+            0xFF => pos += 1,
+            0x01 | 0xD0..=0xD7 => pos += 2,
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                break (read_u16(pos + 7)?, read_u16(pos + 5)?, *bytes.get(pos + 9)?);
+            }
+            _ => pos += 2 + read_u16(pos + 2)? as usize,
+        }
+    };
+
+    let color_space = match components {
+        1 => ColorSpace::Greyscale,
+        3 => ColorSpace::Rgb,
+        4 => ColorSpace::Cmyk,
+        _ => return None,
+    };
+
+    Some(ImageXObject {
+        width: Px(width as usize),
+        height: Px(height as usize),
+        color_space,
+        bits_per_component: ColorBits::Bit8,
+        interpolate: true,
+        image_data: bytes,
+        image_filter: Some(ImageFilter::DCT),
+        smask: None,
+        clipping_bbox: None,
+    })
+}
+
+/// Lists the title block rows in display order, skipping empty values
+///
+/// The layer list is left out when `layers` is empty.
+pub(crate) fn title_block_lines(
+    title_block: &TitleBlock,
+    config: &PdfExportConfig,
+    layers: &[&DrawingLayer],
+) -> Vec<String> {
+    let mut lines = vec![
+        title_block.project_name.clone(),
+        title_block.drawing_title.clone(),
+        format!(
+            "Dwg No: {}  Rev: {}",
+            title_block.drawing_number, title_block.revision
+        ),
+        format!("Date: {}  Scale: {}", title_block.date, title_block.scale),
+        format!(
+            "Sheet {} of {}",
+            title_block.sheet_number, title_block.total_sheets
+        ),
+    ];
+
+    let optional = [
+        ("Drawn", Some(&title_block.drawn_by)),
+        ("Checked", title_block.checked_by.as_ref()),
+        ("Approved", title_block.approved_by.as_ref()),
+        ("Phone", title_block.company_phone.as_ref()),
+        ("Email", title_block.company_email.as_ref()),
+        ("Web", title_block.company_url.as_ref()),
+    ];
+    lines.extend(
+        optional
+            .into_iter()
+            .filter_map(|(label, value)| value.filter(|v| !v.is_empty()).map(|v| (label, v)))
+            .map(|(label, value)| format!("{}: {}", label, value)),
+    );
+
+    if config.include_layer_info && !layers.is_empty() {
+        let names: Vec<&str> = layers.iter().map(|l| l.name.as_str()).collect();
+        lines.push(format!("Layers: {}", names.join(", ")));
+    }
+    if config.include_timestamp {
+        lines.push(format!("Generated: {}", chrono::Utc::now().to_rfc3339()));
+    }
+
+    lines
 }
 
-/// Estimates PDF file size based on content complexity
-fn estimate_pdf_size(metadata: &PdfMetadata) -> u64 {
-    // Base PDF overhead (headers, fonts, etc.)
-    let base_size: u64 = 2048;
+/// One page of a PDF document: the layers drawn on it and its title block
+struct Sheet<'a> {
+    layers: Vec<&'a DrawingLayer>,
+    title_block: TitleBlock,
+}
+
+/// Renders a sheet's layers, border, title block, revision table and
+/// signal legend onto a page, collecting a warning for each element that
+/// could not be drawn
+fn draw_sheet(
+    page: &PdfPageReference,
+    title_layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    config: &PdfExportConfig,
+    sheet: &Sheet,
+    logo: Option<ImageXObject>,
+    warnings: &mut Vec<String>,
+) {
+    let layout = &config.page_layout;
+    let frame = PageFrame::new(layout);
+
+    for layer in &sheet.layers {
+        let pdf_layer = page.add_layer(layer.name.as_str());
+        for element in &layer.elements {
+            if let Err(warning) =
+                draw_element(&pdf_layer, font, &frame, &config.signal_colors, element)
+            {
+                warnings.push(warning);
+            }
+        }
+    }
+
+    let (draw_width, draw_height) = layout.drawable_area();
+    stroke_rect(
+        title_layer,
+        layout.margin_left,
+        layout.margin_bottom,
+        draw_width,
+        draw_height,
+    );
+    let title_block_height = draw_title_block(
+        title_layer,
+        font,
+        layout,
+        &title_block_lines(&sheet.title_block, config, &sheet.layers),
+        logo,
+    );
+    draw_revision_table(
+        title_layer,
+        font,
+        layout,
+        &sheet.title_block.revision_rows(),
+        layout.margin_bottom + title_block_height,
+    );
+    draw_signal_legend(
+        title_layer,
+        font,
+        layout,
+        &config.signal_colors,
+        &legend::signals_present(&sheet.layers),
+    );
+}
+
+/// Renders each sheet on its own page and writes the PDF file
+///
+/// Returns a warning for each element that could not be drawn, and for a
+/// title block logo that could not be loaded. The logo is loaded once and
+/// drawn on every page.
+fn write_pdf_document(
+    sheets: &[Sheet],
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<Vec<String>, String> {
+    let first = sheets
+        .first()
+        .ok_or_else(|| "No sheets to export".to_string())?;
+    let layout = &config.page_layout;
+    let (page_width, page_height) = layout.effective_dimensions();
+    let (doc, first_page, first_title_layer) = PdfDocument::new(
+        first.title_block.drawing_title.as_str(),
+        pt(page_width),
+        pt(page_height),
+        "Title Block",
+    );
+
+    let font = doc
+        .add_builtin_font(builtin_font(config.font_family.as_deref()))
+        .map_err(|e| format!("Failed to load font: {}", e))?;
+
+    let mut warnings = Vec::new();
+    let logo_path = first
+        .title_block
+        .logo_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty());
+    let logo = logo_path.and_then(|path| match load_logo(path) {
+        Ok(logo) => Some(logo),
+        Err(warning) => {
+            warnings.push(warning);
+            None
+        }
+    });
+
+    for (index, sheet) in sheets.iter().enumerate() {
+        let (page_index, title_layer_index) = if index == 0 {
+            (first_page, first_title_layer)
+        } else {
+            doc.add_page(pt(page_width), pt(page_height), "Title Block")
+        };
+        let page = doc.get_page(page_index);
+        let title_layer = page.get_layer(title_layer_index);
+        draw_sheet(
+            &page,
+            &title_layer,
+            &font,
+            config,
+            sheet,
+            logo.clone(),
+            &mut warnings,
+        );
+    }
+
+    save_pdf(doc, output_path)?;
+
+    Ok(warnings)
+}
+
+/// Writes a finished PDF document to `output_path`
+pub(crate) fn save_pdf(doc: PdfDocumentReference, output_path: &str) -> Result<(), String> {
+    let file = File::create(output_path).map_err(|e| format!("Failed to write file: {}", e))?;
+    doc.save(&mut BufWriter::new(file))
+        .map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Reads the size of a PDF written to `output_path`
+pub(crate) fn written_file_size(output_path: &str) -> Result<u64, String> {
+    std::fs::metadata(output_path)
+        .map(|m| m.len())
+        .map_err(|e| format!("Failed to read written PDF: {}", e))
+}
+
+// ============================================================================
+// Render Time Estimate
+// ============================================================================
+
+/// Tunable costs used to estimate PDF render time, in milliseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RenderCostModel {
+    pub base_ms: f64,
+    pub per_page_ms: f64,
+    pub per_element_ms: f64,
+    pub per_cable_ms: f64,
+}
 
-    // Size per element (vector graphics, text labels)
-    let element_size: u64 = 256;
+impl Default for RenderCostModel {
+    fn default() -> Self {
+        Self {
+            base_ms: 150.0,
+            per_page_ms: 80.0,
+            per_element_ms: 2.0,
+            per_cable_ms: 3.5, // Cables carry routing and labels
+        }
+    }
+}
+
+impl RenderCostModel {
+    /// Checks that every cost is a finite, non-negative number of milliseconds
+    pub fn validate(&self) -> Result<(), String> {
+        let costs = [
+            ("baseMs", self.base_ms),
+            ("perPageMs", self.per_page_ms),
+            ("perElementMs", self.per_element_ms),
+            ("perCableMs", self.per_cable_ms),
+        ];
+        match costs
+            .iter()
+            .find(|(_, cost)| !cost.is_finite() || *cost < 0.0)
+        {
+            Some((name, cost)) => Err(format!(
+                "Render cost {} must be a finite, non-negative number, got {}",
+                name, cost
+            )),
+            None => Ok(()),
+        }
+    }
+}
 
-    // Title block contribution
-    let title_block_size: u64 = 512;
+/// Estimates how long rendering a drawing to PDF will take
+///
+/// The estimate is not exact but grows with the number of elements, cables
+/// and pages that `generate_pdf` would render, so hidden layers and layers
+/// left out by `include_layers` cost nothing. Drawings that cannot be
+/// exported are rejected with the same error as the export, and an estimate
+/// too large to represent saturates at `Duration::MAX`.
+pub fn estimate_render_time(
+    drawing: &DrawingInput,
+    config: &PdfExportConfig,
+    model: &RenderCostModel,
+) -> Result<Duration, String> {
+    model.validate()?;
+    let plan = plan_pdf_export(drawing, config)?;
+
+    let elements = drawing
+        .layers
+        .iter()
+        .filter(|l| config.renders_layer(l))
+        .flat_map(|l| &l.elements);
+
+    let (cables, others) = elements.fold((0usize, 0usize), |(cables, others), e| {
+        if e.element_type == ElementType::Cable {
+            (cables + 1, others)
+        } else {
+            (cables, others + 1)
+        }
+    });
 
-    // Metadata contribution
-    let metadata_size: u64 = (metadata.title.len()
-        + metadata.project.len()
-        + metadata.drawing_number.len()
-        + metadata.revision.len()
-        + metadata.created_date.len()) as u64;
+    let millis = model.base_ms
+        + f64::from(plan.page_count) * model.per_page_ms
+        + others as f64 * model.per_element_ms
+        + cables as f64 * model.per_cable_ms;
 
-    base_size + (metadata.element_count as u64 * element_size) + title_block_size + metadata_size
+    Ok(Duration::try_from_secs_f64(millis / 1000.0).unwrap_or(Duration::MAX))
 }
 
 // ============================================================================
@@ -357,6 +1470,63 @@ pub fn export_to_pdf(
     generate_pdf(&drawing, &config, &output_path)
 }
 
+/// Tauri command to export a set of drawings to one multi-page PDF
+#[tauri::command]
+pub fn export_drawings_to_pdf(
+    drawings: Vec<DrawingInput>,
+    config: PdfExportConfig,
+    output_path: String,
+) -> Result<PdfExportResult, String> {
+    generate_pdf_set(&drawings, &config, &output_path)
+}
+
+/// Tauri command to preview how many pages a PDF export will have
+#[tauri::command]
+pub fn preview_pdf_pagination(
+    drawing: DrawingInput,
+    config: PdfExportConfig,
+) -> Result<PdfExportPlan, String> {
+    plan_pdf_export(&drawing, &config)
+}
+
+/// Tauri command to validate and normalize title block contact fields
+#[tauri::command]
+pub fn normalize_title_block_contact(title_block: TitleBlock) -> Result<TitleBlock, String> {
+    title_block.normalized_contact()
+}
+
+/// Tauri command to check a drawing for duplicate element ids
+#[tauri::command]
+pub fn validate_drawing_element_ids(drawing: DrawingInput) -> Result<(), Vec<String>> {
+    validate_element_ids(&drawing)
+}
+
+/// Tauri command to list required layer types missing from a drawing
+#[tauri::command]
+pub fn validate_drawing_layers(
+    drawing: DrawingInput,
+    required: Vec<LayerType>,
+) -> Result<Vec<LayerType>, String> {
+    Ok(validate_layers(&drawing, &required))
+}
+
+/// Tauri command to compute per-layer bounding boxes for zoom-to-fit
+#[tauri::command]
+pub fn compute_layer_bounds(drawing: DrawingInput) -> Result<HashMap<String, LayerBounds>, String> {
+    Ok(layer_bounds(&drawing))
+}
+
+/// Tauri command to estimate PDF render time in milliseconds
+#[tauri::command]
+pub fn estimate_pdf_render_time(
+    drawing: DrawingInput,
+    config: PdfExportConfig,
+    model: Option<RenderCostModel>,
+) -> Result<u64, String> {
+    let estimate = estimate_render_time(&drawing, &config, &model.unwrap_or_default())?;
+    Ok(u64::try_from(estimate.as_millis()).unwrap_or(u64::MAX))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -405,6 +1575,13 @@ mod tests {
         PdfExportConfig::new(title_block)
     }
 
+    /// Returns an output path inside a fresh temp dir, kept alive by the guard
+    fn temp_pdf_path(file_name: &str) -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(file_name).to_string_lossy().into_owned();
+        (dir, path)
+    }
+
     // ========================================================================
     // PageSize Tests
     // ========================================================================
@@ -569,6 +1746,99 @@ mod tests {
         assert!(json.contains("\"revision\":\"A\""));
     }
 
+    #[test]
+    fn test_revision_entry_serialization() {
+        let entry = RevisionEntry {
+            revision: "B".to_string(),
+            date: "2024-03-01".to_string(),
+            description: "Added ceiling speakers".to_string(),
+            revised_by: "JD".to_string(),
+        };
+
+        let json = serde_json::to_string(&entry).unwrap();
+
+        assert!(json.contains("\"revision\":\"B\""));
+        assert!(json.contains("\"description\":\"Added ceiling speakers\""));
+        assert!(json.contains("\"revisedBy\":\"JD\""));
+        assert_eq!(serde_json::from_str::<RevisionEntry>(&json).unwrap(), entry);
+    }
+
+    #[test]
+    fn test_title_block_without_revisions_deserializes() {
+        let json = serde_json::to_value(TitleBlock::new("Project", "Drawing")).unwrap();
+        let mut object = json.as_object().unwrap().clone();
+        object.remove("revisions");
+
+        let tb: TitleBlock = serde_json::from_value(object.into()).unwrap();
+
+        assert!(tb.revisions.is_empty());
+        assert_eq!(tb.revision_rows().len(), 1);
+    }
+
+    #[test]
+    fn test_revision_rows_append_current_revision() {
+        let mut tb = TitleBlock::new("Project", "Drawing");
+        tb.revision = "B".to_string();
+        tb.drawn_by = "JD".to_string();
+        tb.revisions = vec![RevisionEntry {
+            revision: "A".to_string(),
+            date: "2024-01-15".to_string(),
+            description: "Issued for review".to_string(),
+            revised_by: "JD".to_string(),
+        }];
+
+        let rows = tb.revision_rows();
+
+        let revisions: Vec<&str> = rows.iter().map(|r| r.revision.as_str()).collect();
+        assert_eq!(revisions, vec!["A", "B"]);
+        assert_eq!(rows[1].date, tb.date);
+
+        tb.revision = "A".to_string();
+        assert_eq!(tb.revision_rows().len(), 1);
+    }
+
+    #[test]
+    fn test_title_block_valid_contact_normalized() {
+        let mut tb = TitleBlock::new("Project", "Drawing");
+        tb.company_phone = Some(" +1 (555)  123-4567 ".to_string());
+        tb.company_email = Some("Design@Example.COM".to_string());
+        tb.company_url = Some("www.example.com/av".to_string());
+
+        let normalized = tb.normalized_contact().unwrap();
+
+        assert_eq!(
+            normalized.company_phone.as_deref(),
+            Some("+1 (555) 123-4567")
+        );
+        assert_eq!(
+            normalized.company_email.as_deref(),
+            Some("design@example.com")
+        );
+        assert_eq!(
+            normalized.company_url.as_deref(),
+            Some("https://www.example.com/av")
+        );
+    }
+
+    #[test]
+    fn test_title_block_malformed_email_rejected() {
+        let mut tb = TitleBlock::new("Project", "Drawing");
+        tb.company_email = Some("design@example".to_string());
+
+        let err = tb.normalized_contact().unwrap_err();
+        assert!(err.contains("Invalid company email"));
+    }
+
+    #[test]
+    fn test_generate_pdf_malformed_contact_error() {
+        let drawing = create_test_drawing();
+        let mut config = create_test_config();
+        config.title_block.company_phone = Some("call us".to_string());
+
+        let result = generate_pdf(&drawing, &config, "/tmp/contact.pdf");
+        assert!(result.unwrap_err().contains("Invalid company phone"));
+    }
+
     // ========================================================================
     // DrawingType Tests
     // ========================================================================
@@ -637,11 +1907,12 @@ mod tests {
         let drawing = create_test_drawing();
         let config = create_test_config();
 
-        let result = generate_pdf(&drawing, &config, "/tmp/test.pdf");
+        let (_dir, path) = temp_pdf_path("test.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
 
         let pdf_result = result.unwrap();
-        assert_eq!(pdf_result.file_path, "/tmp/test.pdf");
+        assert_eq!(pdf_result.file_path, path);
         assert!(pdf_result.file_size_bytes > 0);
         assert_eq!(pdf_result.page_count, 1);
         assert!(!pdf_result.generated_at.is_empty());
@@ -693,7 +1964,8 @@ mod tests {
             .push(create_test_layer("layer-3", LayerType::Dimensions, true));
         let config = create_test_config();
 
-        let result = generate_pdf(&drawing, &config, "/tmp/test.pdf");
+        let (_dir, path) = temp_pdf_path("test.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
     }
 
@@ -708,7 +1980,8 @@ mod tests {
             .push(create_test_layer("layer-3", LayerType::Dimensions, true));
         let config = create_test_config();
 
-        let result = generate_pdf(&drawing, &config, "/tmp/test.pdf");
+        let (_dir, path) = temp_pdf_path("test.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
     }
 
@@ -717,7 +1990,8 @@ mod tests {
         let drawing = create_test_drawing();
         let config = create_test_config();
 
-        let result = generate_pdf(&drawing, &config, "/tmp/test.pdf").unwrap();
+        let (_dir, path) = temp_pdf_path("test.pdf");
+        let result = generate_pdf(&drawing, &config, &path).unwrap();
         assert!(result.generated_at.contains("T")); // RFC3339 format
     }
 
@@ -726,7 +2000,8 @@ mod tests {
         // Create drawing with one element
         let drawing_small = create_test_drawing();
         let config = create_test_config();
-        let result_small = generate_pdf(&drawing_small, &config, "/tmp/small.pdf").unwrap();
+        let (_small_dir, small_path) = temp_pdf_path("small.pdf");
+        let result_small = generate_pdf(&drawing_small, &config, &small_path).unwrap();
 
         // Create drawing with many elements
         let mut drawing_large = create_test_drawing();
@@ -736,7 +2011,8 @@ mod tests {
                 ElementType::Equipment,
             ));
         }
-        let result_large = generate_pdf(&drawing_large, &config, "/tmp/large.pdf").unwrap();
+        let (_large_dir, large_path) = temp_pdf_path("large.pdf");
+        let result_large = generate_pdf(&drawing_large, &config, &large_path).unwrap();
 
         assert!(result_large.file_size_bytes > result_small.file_size_bytes);
     }
@@ -752,7 +2028,8 @@ mod tests {
         config.page_layout.size = PageSize::ArchD;
         config.page_layout.orientation = PageOrientation::Landscape;
 
-        let result = generate_pdf(&drawing, &config, "/tmp/archd.pdf");
+        let (_dir, path) = temp_pdf_path("archd.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
     }
 
@@ -765,7 +2042,8 @@ mod tests {
         config.page_layout.margin_left = 72.0;
         config.page_layout.margin_right = 72.0;
 
-        let result = generate_pdf(&drawing, &config, "/tmp/margins.pdf");
+        let (_dir, path) = temp_pdf_path("margins.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
     }
 
@@ -786,7 +2064,8 @@ mod tests {
         config.title_block.sheet_number = 1;
         config.title_block.total_sheets = 3;
 
-        let result = generate_pdf(&drawing, &config, "/tmp/full_title.pdf");
+        let (_dir, path) = temp_pdf_path("full_title.pdf");
+        let result = generate_pdf(&drawing, &config, &path);
         assert!(result.is_ok());
     }
 
@@ -888,6 +2167,7 @@ mod tests {
             file_size_bytes: 12345,
             page_count: 1,
             generated_at: "2026-01-18T12:00:00Z".to_string(),
+            warnings: Vec::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -897,69 +2177,573 @@ mod tests {
     }
 
     // ========================================================================
-    // Estimate PDF Size Tests
+    // Element Id Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn test_validate_element_ids_unique() {
+        let mut drawing = create_test_drawing();
+        drawing.layers[0]
+            .elements
+            .push(create_test_element("elem-2", ElementType::Cable));
+
+        assert!(validate_element_ids(&drawing).is_ok());
+    }
+
+    #[test]
+    fn test_validate_element_ids_duplicate_across_layers() {
+        let mut drawing = create_test_drawing();
+        drawing
+            .layers
+            .push(create_test_layer("layer-2", LayerType::Annotations, true));
+
+        let result = validate_element_ids(&drawing);
+        assert_eq!(result.unwrap_err(), vec!["elem-1".to_string()]);
+    }
+
+    #[test]
+    fn test_generate_pdf_rejects_duplicate_ids_when_enabled() {
+        let mut drawing = create_test_drawing();
+        drawing
+            .layers
+            .push(create_test_layer("layer-2", LayerType::Annotations, true));
+        let mut config = create_test_config();
+
+        let (_dir, path) = temp_pdf_path("test.pdf");
+        assert!(generate_pdf(&drawing, &config, &path).is_ok());
+
+        config.reject_duplicate_ids = true;
+        let result = generate_pdf(&drawing, &config, "/tmp/test.pdf");
+        assert!(result.unwrap_err().contains("elem-1"));
+    }
+
+    // ========================================================================
+    // Layer Coverage Tests
+    // ========================================================================
+
+    #[test]
+    fn test_validate_layers_missing_title_block() {
+        let drawing = create_test_drawing();
+        let required = [LayerType::TitleBlock, LayerType::AvElements];
+
+        let missing = validate_layers(&drawing, &required);
+
+        assert_eq!(missing, vec![LayerType::TitleBlock]);
+    }
+
+    #[test]
+    fn test_validate_layers_complete_drawing() {
+        let mut drawing = create_test_drawing();
+        drawing
+            .layers
+            .push(create_test_layer("layer-2", LayerType::TitleBlock, true));
+        let required = [LayerType::TitleBlock, LayerType::AvElements];
+
+        assert!(validate_layers(&drawing, &required).is_empty());
+    }
+
+    // ========================================================================
+    // Layer Bounds Tests
+    // ========================================================================
+
+    #[test]
+    fn test_layer_bounds_spans_elements() {
+        let mut drawing = create_test_drawing();
+        let mut second = create_test_element("elem-2", ElementType::Cable);
+        second.x = 300.0;
+        second.y = 50.0;
+        drawing.layers[0].elements.push(second);
+
+        let bounds = layer_bounds(&drawing);
+
+        assert_eq!(
+            bounds["layer-1"],
+            LayerBounds {
+                min_x: 100.0,
+                min_y: 50.0,
+                max_x: 300.0,
+                max_y: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_layer_bounds_skips_empty_layers() {
+        let mut drawing = create_test_drawing();
+        let mut empty = create_test_layer("layer-2", LayerType::Annotations, true);
+        empty.elements.clear();
+        drawing.layers.push(empty);
+
+        let bounds = layer_bounds(&drawing);
+
+        assert_eq!(bounds.len(), 1);
+        assert!(!bounds.contains_key("layer-2"));
+    }
+
+    // ========================================================================
+    // Render Time Estimate Tests
     // ========================================================================
 
     #[test]
-    fn test_estimate_pdf_size_base_size() {
-        let metadata = PdfMetadata {
-            title: "".to_string(),
-            project: "".to_string(),
-            drawing_number: "".to_string(),
-            revision: "".to_string(),
-            created_date: "".to_string(),
-            page_width: 612.0,
-            page_height: 792.0,
-            drawable_width: 540.0,
-            drawable_height: 720.0,
-            layer_count: 0,
-            element_count: 0,
+    fn test_estimate_render_time_grows_with_elements() {
+        let config = create_test_config();
+        let model = RenderCostModel::default();
+        let small = create_test_drawing();
+
+        let mut large = create_test_drawing();
+        for i in 0..50 {
+            large.layers[0].elements.push(create_test_element(
+                &format!("cable-{}", i),
+                ElementType::Cable,
+            ));
+        }
+
+        let small_estimate = estimate_render_time(&small, &config, &model).unwrap();
+        let large_estimate = estimate_render_time(&large, &config, &model).unwrap();
+
+        assert!(large_estimate > small_estimate);
+    }
+
+    #[test]
+    fn test_estimate_render_time_skips_unrendered_layers() {
+        let model = RenderCostModel::default();
+        let mut drawing = create_test_drawing();
+        let baseline = estimate_render_time(&drawing, &create_test_config(), &model).unwrap();
+
+        let mut hidden = create_test_layer("layer-2", LayerType::AvElements, false);
+        let mut excluded = create_test_layer("layer-3", LayerType::Dimensions, true);
+        for i in 0..50 {
+            hidden.elements.push(create_test_element(
+                &format!("hidden-{}", i),
+                ElementType::Cable,
+            ));
+            excluded.elements.push(create_test_element(
+                &format!("dim-{}", i),
+                ElementType::Dimension,
+            ));
+        }
+        drawing.layers.push(hidden);
+        drawing.layers.push(excluded);
+        let mut config = create_test_config();
+        config.include_layers = Some(vec![LayerType::AvElements]);
+        config.title_block.total_sheets = 4;
+
+        // One page is rendered whatever the title block's sheet count says
+        assert_eq!(
+            estimate_render_time(&drawing, &config, &model).unwrap(),
+            baseline
+        );
+    }
+
+    #[test]
+    fn test_estimate_render_time_rejects_invalid_costs() {
+        let drawing = create_test_drawing();
+        let config = create_test_config();
+
+        for cost in [f64::NAN, f64::INFINITY, -1.0] {
+            let model = RenderCostModel {
+                per_element_ms: cost,
+                ..RenderCostModel::default()
+            };
+            assert!(estimate_render_time(&drawing, &config, &model).is_err());
+        }
+    }
+
+    #[test]
+    fn test_estimate_render_time_saturates() {
+        let drawing = create_test_drawing();
+        let config = create_test_config();
+        let model = RenderCostModel {
+            base_ms: f64::MAX,
+            per_page_ms: f64::MAX,
+            ..RenderCostModel::default()
         };
 
-        let size = estimate_pdf_size(&metadata);
-        // Base size (2048) + title block (512)
-        assert_eq!(size, 2560);
-    }
-
-    #[test]
-    fn test_estimate_pdf_size_with_elements() {
-        let metadata = PdfMetadata {
-            title: "".to_string(),
-            project: "".to_string(),
-            drawing_number: "".to_string(),
-            revision: "".to_string(),
-            created_date: "".to_string(),
-            page_width: 612.0,
-            page_height: 792.0,
-            drawable_width: 540.0,
-            drawable_height: 720.0,
-            layer_count: 1,
-            element_count: 10,
+        assert_eq!(
+            estimate_render_time(&drawing, &config, &model).unwrap(),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn test_generate_pdf_writes_real_pdf_file() {
+        let drawing = create_test_drawing();
+        let config = create_test_config();
+        let (_dir, path) = temp_pdf_path("real.pdf");
+
+        let result = generate_pdf(&drawing, &config, &path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+        assert_eq!(result.file_size_bytes, bytes.len() as u64);
+    }
+
+    #[test]
+    fn test_generate_pdf_unwritable_path_error() {
+        let drawing = create_test_drawing();
+        let config = create_test_config();
+
+        let result = generate_pdf(&drawing, &config, "/nonexistent/dir/out.pdf");
+        assert!(result.unwrap_err().contains("Failed to write file"));
+    }
+
+    #[test]
+    fn test_generate_pdf_renders_every_element_type() {
+        let mut drawing = create_test_drawing();
+        let mut cable = create_test_element("cable-1", ElementType::Cable);
+        cable.properties =
+            serde_json::json!({ "x1": 100.0, "y1": 100.0, "x2": 300.0, "y2": 150.0 });
+        let mut text = create_test_element("text-1", ElementType::Text);
+        text.properties = serde_json::json!({ "text": "Conference Room A" });
+        text.rotation = 90.0;
+        let mut dimension = create_test_element("dim-1", ElementType::Dimension);
+        dimension.properties = serde_json::json!({ "x2": 250.0, "y2": 100.0 });
+        let mut equipment = create_test_element("elem-2", ElementType::Equipment);
+        equipment.rotation = 45.0;
+        drawing.layers[0].elements.extend([
+            cable,
+            text,
+            dimension,
+            equipment,
+            create_test_element("sym-1", ElementType::Symbol),
+        ]);
+        let (_dir, path) = temp_pdf_path("types.pdf");
+
+        let result = generate_pdf(&drawing, &create_test_config(), &path).unwrap();
+
+        assert!(result.warnings.is_empty());
+        assert!(std::fs::read(&path).unwrap().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_rotation_matches_png_export() {
+        let mut drawing = create_test_drawing();
+        drawing.layers[0].elements[0].rotation = 30.0;
+        let config = create_test_config();
+        let (_dir, pdf_path) = temp_pdf_path("rotated.pdf");
+        let png_path = pdf_path.replace(".pdf", ".png");
+
+        // Top right corner of the equipment box, turned 30 degrees clockwise
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let (dx, dy) = (ELEMENT_BOX_WIDTH / 2.0, -ELEMENT_BOX_HEIGHT / 2.0);
+        let corner = (100.0 + dx * cos - dy * sin, 100.0 + dx * sin + dy * cos);
+
+        generate_pdf(&drawing, &config, &pdf_path).unwrap();
+        let document = lopdf::Document::load(&pdf_path).unwrap();
+        let page_id = *document.get_pages().values().next().unwrap();
+        let content = document.get_and_decode_page_content(page_id).unwrap();
+        let matrices: Vec<Vec<f64>> = content
+            .operations
+            .iter()
+            .filter(|op| op.operator == "cm")
+            .map(|op| {
+                op.operands
+                    .iter()
+                    .map(|o| f64::from(o.as_float().unwrap()))
+                    .collect()
+            })
+            .collect();
+        let frame = PageFrame::new(&config.page_layout);
+        let origin = frame.to_page(100.0, 100.0);
+        let at_element = matrices
+            .iter()
+            .position(|m| (m[4] - origin.0).abs() < 0.01 && (m[5] - origin.1).abs() < 0.01)
+            .unwrap();
+        let rotation = &matrices[at_element + 1];
+        // Box corners are drawn in y-up page space around the element origin
+        let (lx, ly) = (dx, -dy);
+        let pdf_corner = (
+            origin.0 + rotation[0] * lx + rotation[2] * ly,
+            origin.1 + rotation[1] * lx + rotation[3] * ly,
+        );
+        let expected = frame.to_page(corner.0, corner.1);
+        assert!((pdf_corner.0 - expected.0).abs() < 0.01, "{:?}", pdf_corner);
+        assert!((pdf_corner.1 - expected.1).abs() < 0.01, "{:?}", pdf_corner);
+
+        crate::export::raster::export_png(&drawing, &config, &png_path, 72).unwrap();
+        let mut reader = png::Decoder::new(File::open(&png_path).unwrap())
+            .read_info()
+            .unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        let layout = &config.page_layout;
+        let is_ink = |x: f64, y: f64| {
+            let (px, py) = (x.round() as usize, y.round() as usize);
+            (py - 1..=py + 1).any(|row| {
+                (px - 1..=px + 1).any(|col| pixels[(row * info.width as usize + col) * 3] < 128)
+            })
         };
+        assert!(is_ink(
+            layout.margin_left + corner.0,
+            layout.margin_top + corner.1
+        ));
+        // The corner of a counter-clockwise turn stays blank
+        let mirrored = (100.0 + dx * cos + dy * sin, 100.0 - dx * sin + dy * cos);
+        assert!(!is_ink(
+            layout.margin_left + mirrored.0,
+            layout.margin_top + mirrored.1
+        ));
+    }
+
+    #[test]
+    fn test_generate_pdf_skips_cable_without_endpoints() {
+        let mut drawing = create_test_drawing();
+        let mut cable = create_test_element("cable-1", ElementType::Cable);
+        cable.properties = serde_json::json!({ "x1": 100.0, "y1": 100.0 });
+        drawing.layers[0].elements.push(cable);
+        let (_dir, path) = temp_pdf_path("cables.pdf");
+
+        let result = generate_pdf(&drawing, &create_test_config(), &path).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("cable-1"));
+        assert!(std::path::Path::new(&path).exists());
+    }
+
+    // ========================================================================
+    // Title Block Logo Tests
+    // ========================================================================
+
+    /// Writes a small RGBA PNG with a transparent border into `dir`
+    fn write_test_logo(dir: &tempfile::TempDir) -> String {
+        let path = dir.path().join("logo.png");
+        let file = File::create(&path).unwrap();
+        let mut encoder = png::Encoder::new(BufWriter::new(file), 4, 2);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        let pixels: Vec<u8> = (0..8)
+            .flat_map(|i| [200, 30, 30, if i % 4 == 0 { 0 } else { 255 }])
+            .collect();
+        writer.write_image_data(&pixels).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_generate_pdf_embeds_logo() {
+        let drawing = create_test_drawing();
+        let (_plain_dir, plain_path) = temp_pdf_path("plain.pdf");
+        let plain = generate_pdf(&drawing, &create_test_config(), &plain_path).unwrap();
+
+        let (logo_dir, logo_pdf_path) = temp_pdf_path("logo.pdf");
+        let mut config = create_test_config();
+        config.title_block.logo_path = Some(write_test_logo(&logo_dir));
+        let with_logo = generate_pdf(&drawing, &config, &logo_pdf_path).unwrap();
+
+        assert!(with_logo.warnings.is_empty());
+        assert!(with_logo.file_size_bytes > plain.file_size_bytes);
+    }
+
+    #[test]
+    fn test_generate_pdf_missing_logo_warns() {
+        let (_dir, path) = temp_pdf_path("missing-logo.pdf");
+        let mut config = create_test_config();
+        config.title_block.logo_path = Some("/nonexistent/logo.png".to_string());
+
+        let result = generate_pdf(&create_test_drawing(), &config, &path).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("logo"));
+    }
+
+    #[test]
+    fn test_generate_pdf_non_image_logo_warns() {
+        let (dir, path) = temp_pdf_path("text-logo.pdf");
+        let logo_path = dir.path().join("logo.png");
+        std::fs::write(&logo_path, "not an image").unwrap();
+        let mut config = create_test_config();
+        config.title_block.logo_path = Some(logo_path.to_string_lossy().into_owned());
+
+        let result = generate_pdf(&create_test_drawing(), &config, &path).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("not a PNG or JPEG"));
+        assert!(std::path::Path::new(&path).exists());
+    }
+
+    #[test]
+    fn test_jpeg_logo_reads_frame_header() {
+        // SOI, an empty APP0 segment, then a baseline SOF0 for 640x480 RGB
+        let bytes = vec![
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x02, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01, 0xE0, 0x02,
+            0x80, 0x03,
+        ];
+
+        let logo = jpeg_logo(bytes).unwrap();
 
-        let size = estimate_pdf_size(&metadata);
-        // Base (2048) + elements (10 * 256) + title block (512)
-        assert_eq!(size, 5120);
-    }
-
-    #[test]
-    fn test_estimate_pdf_size_with_metadata() {
-        let metadata = PdfMetadata {
-            title: "Test".to_string(),              // 4 bytes
-            project: "Project".to_string(),         // 7 bytes
-            drawing_number: "001".to_string(),      // 3 bytes
-            revision: "A".to_string(),              // 1 byte
-            created_date: "2026-01-18".to_string(), // 10 bytes
-            page_width: 612.0,
-            page_height: 792.0,
-            drawable_width: 540.0,
-            drawable_height: 720.0,
-            layer_count: 0,
-            element_count: 0,
+        assert_eq!((logo.width.0, logo.height.0), (640, 480));
+        assert!(matches!(logo.color_space, ColorSpace::Rgb));
+    }
+
+    #[test]
+    fn test_generate_pdf_revision_table_grows_output() {
+        let drawing = create_test_drawing();
+        let (_dir, plain_path) = temp_pdf_path("plain.pdf");
+        let plain = generate_pdf(&drawing, &create_test_config(), &plain_path).unwrap();
+
+        let mut config = create_test_config();
+        config.title_block.revisions = (0..3)
+            .map(|i| RevisionEntry {
+                revision: i.to_string(),
+                date: "2024-01-15".to_string(),
+                description: format!("Revision {}", i),
+                revised_by: "JD".to_string(),
+            })
+            .collect();
+        let (_revisions_dir, revisions_path) = temp_pdf_path("revisions.pdf");
+        let with_revisions = generate_pdf(&drawing, &config, &revisions_path).unwrap();
+
+        assert!(with_revisions.file_size_bytes > plain.file_size_bytes);
+    }
+
+    #[test]
+    fn test_generate_pdf_signal_legend_grows_output() {
+        let cable = |signal_type: Option<&str>| {
+            let mut cable = create_test_element("cable-1", ElementType::Cable);
+            cable.properties = serde_json::json!({
+                "x1": 10.0, "y1": 10.0, "x2": 200.0, "y2": 10.0,
+                "signal_type": signal_type,
+            });
+            cable
         };
 
-        let size = estimate_pdf_size(&metadata);
-        // Base (2048) + title block (512) + metadata (25)
-        assert_eq!(size, 2585);
+        let mut plain = create_test_drawing();
+        plain.layers[0].elements = vec![cable(None)];
+        let (_plain_dir, plain_path) = temp_pdf_path("plain.pdf");
+        let plain = generate_pdf(&plain, &create_test_config(), &plain_path).unwrap();
+
+        let mut typed = create_test_drawing();
+        typed.layers[0].elements = vec![cable(Some("video"))];
+        let (_typed_dir, typed_path) = temp_pdf_path("typed.pdf");
+        let typed = generate_pdf(&typed, &create_test_config(), &typed_path).unwrap();
+
+        assert!(plain.warnings.is_empty() && typed.warnings.is_empty());
+        assert!(typed.file_size_bytes > plain.file_size_bytes);
+    }
+
+    #[test]
+    fn test_generate_pdf_set_one_page_per_drawing() {
+        let drawings = vec![
+            create_test_drawing(),
+            create_test_drawing(),
+            create_test_drawing(),
+        ];
+        let (_dir, path) = temp_pdf_path("set.pdf");
+
+        let result = generate_pdf_set(&drawings, &create_test_config(), &path).unwrap();
+
+        assert_eq!(result.page_count, 3);
+        assert!(result.warnings.is_empty());
+        assert_eq!(lopdf::Document::load(&path).unwrap().get_pages().len(), 3);
+    }
+
+    #[test]
+    fn test_generate_pdf_set_skips_hidden_drawings() {
+        let mut hidden = create_test_drawing();
+        hidden.id = "drawing-hidden".to_string();
+        hidden.layers[0].is_visible = false;
+        let drawings = vec![create_test_drawing(), hidden];
+        let (_dir, path) = temp_pdf_path("set.pdf");
+
+        let result = generate_pdf_set(&drawings, &create_test_config(), &path).unwrap();
+
+        assert_eq!(result.page_count, 1);
+        assert_eq!(
+            result.warnings,
+            vec!["Skipped drawing drawing-hidden: no visible layers to export"]
+        );
+    }
+
+    #[test]
+    fn test_generate_pdf_set_empty_rejected() {
+        let (_dir, path) = temp_pdf_path("set.pdf");
+        let result = generate_pdf_set(&[], &create_test_config(), &path);
+        assert_eq!(result.unwrap_err(), "No drawings to export");
+    }
+
+    // ========================================================================
+    // Export Plan Tests
+    // ========================================================================
+
+    #[test]
+    fn test_plan_pdf_export_counts_visible_elements() {
+        let mut drawing = create_test_drawing();
+        drawing.layers[0]
+            .elements
+            .push(create_test_element("elem-2", ElementType::Cable));
+        drawing.layers.push(create_test_layer(
+            "layer-hidden",
+            LayerType::Annotations,
+            false,
+        ));
+
+        let plan = plan_pdf_export(&drawing, &create_test_config()).unwrap();
+
+        assert_eq!(plan.page_count, 1);
+        assert_eq!(plan.pages.len(), 1);
+        assert_eq!(plan.pages[0].page_number, 1);
+        assert_eq!(plan.pages[0].element_count, 2);
+    }
+
+    #[test]
+    fn test_plan_pdf_export_matches_generate_pdf_errors() {
+        let mut empty = create_test_drawing();
+        empty.layers.clear();
+        let mut hidden = create_test_drawing();
+        hidden.layers[0].is_visible = false;
+        let config = create_test_config();
+        let (_dir, path) = temp_pdf_path("never-written.pdf");
+
+        for drawing in [empty, hidden] {
+            let planned = plan_pdf_export(&drawing, &config).unwrap_err();
+            assert_eq!(planned, generate_pdf(&drawing, &config, &path).unwrap_err());
+        }
+        assert!(!std::path::Path::new(&path).exists());
+    }
+
+    // ========================================================================
+    // Layer Filter Tests
+    // ========================================================================
+
+    #[test]
+    fn test_include_layers_renders_only_listed_types() {
+        let mut drawing = create_test_drawing();
+        let mut annotations = create_test_layer("layer-notes", LayerType::Annotations, true);
+        annotations
+            .elements
+            .push(create_test_element("elem-2", ElementType::Text));
+        drawing.layers.push(annotations);
+        let mut hidden = create_test_layer("layer-hidden", LayerType::AvElements, false);
+        hidden.elements[0].id = "elem-3".to_string();
+        drawing.layers.push(hidden);
+        let mut config = create_test_config();
+
+        assert_eq!(
+            plan_pdf_export(&drawing, &config).unwrap().pages[0].element_count,
+            3
+        );
+
+        config.include_layers = Some(vec![LayerType::AvElements]);
+        assert_eq!(
+            plan_pdf_export(&drawing, &config).unwrap().pages[0].element_count,
+            1
+        );
+
+        let (_dir, path) = temp_pdf_path("filtered.pdf");
+        assert!(generate_pdf(&drawing, &config, &path).is_ok());
+    }
+
+    #[test]
+    fn test_include_layers_leaving_nothing_is_error() {
+        let mut config = create_test_config();
+        config.include_layers = Some(vec![LayerType::Dimensions]);
+        let (_dir, path) = temp_pdf_path("filtered.pdf");
+
+        let result = generate_pdf(&create_test_drawing(), &config, &path);
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Drawing has no visible layers of the included types to export"
+        );
     }
 }
@@ -0,0 +1,152 @@
+//! Printer Compatibility
+//!
+//! Checks an export configuration against the media limits of a target
+//! printer or plotter before a drawing is sent to it.
+
+use super::pdf::PdfExportConfig;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Printer Profile
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrinterProfile {
+    pub name: String,
+    /// Maximum media width in points (1/72 inch)
+    pub max_media_width: f64,
+    /// Maximum media height in points (1/72 inch)
+    pub max_media_height: f64,
+    /// Unprintable border on each edge in points
+    pub min_margin: f64,
+}
+
+// ============================================================================
+// Compatibility Check
+// ============================================================================
+
+/// Checks that the configured page fits the printer's media
+///
+/// The page may be fed in either orientation. Returns an error if the page
+/// is larger than the printer's media, otherwise a list of warnings for
+/// margins that fall inside the printer's unprintable border.
+pub fn printer_compatibility(
+    config: &PdfExportConfig,
+    printer: &PrinterProfile,
+) -> Result<Vec<String>, String> {
+    let (page_width, page_height) = config.page_layout.size.dimensions();
+    let fits = |w: f64, h: f64| w <= printer.max_media_width && h <= printer.max_media_height;
+
+    if !fits(page_width, page_height) && !fits(page_height, page_width) {
+        return Err(format!(
+            "Page size {:?} ({} x {} pt) exceeds {} media limit ({} x {} pt)",
+            config.page_layout.size,
+            page_width,
+            page_height,
+            printer.name,
+            printer.max_media_width,
+            printer.max_media_height
+        ));
+    }
+
+    let layout = &config.page_layout;
+    let margins = [
+        ("Top", layout.margin_top),
+        ("Bottom", layout.margin_bottom),
+        ("Left", layout.margin_left),
+        ("Right", layout.margin_right),
+    ];
+
+    let warnings = margins
+        .iter()
+        .filter(|(_, margin)| *margin < printer.min_margin)
+        .map(|(side, margin)| {
+            format!(
+                "{} margin ({} pt) is inside {}'s unprintable area ({} pt); content may be clipped",
+                side, margin, printer.name, printer.min_margin
+            )
+        })
+        .collect();
+
+    Ok(warnings)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to check an export configuration against a printer
+#[tauri::command]
+pub fn check_printer_compatibility(
+    config: PdfExportConfig,
+    printer: PrinterProfile,
+) -> Result<Vec<String>, String> {
+    printer_compatibility(&config, &printer)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{PageSize, TitleBlock};
+
+    fn create_test_config(size: PageSize) -> PdfExportConfig {
+        let mut config = PdfExportConfig::new(TitleBlock::new("Project", "Drawing"));
+        config.page_layout.size = size;
+        config
+    }
+
+    fn a4_printer() -> PrinterProfile {
+        PrinterProfile {
+            name: "Office Laser".to_string(),
+            max_media_width: 595.0,
+            max_media_height: 842.0,
+            min_margin: 12.0,
+        }
+    }
+
+    fn wide_format_printer() -> PrinterProfile {
+        PrinterProfile {
+            name: "36in Plotter".to_string(),
+            max_media_width: 2592.0,
+            max_media_height: 6480.0,
+            min_margin: 12.0,
+        }
+    }
+
+    #[test]
+    fn test_archd_on_a4_printer_fails() {
+        let config = create_test_config(PageSize::ArchD);
+        let result = printer_compatibility(&config, &a4_printer());
+        assert!(result.unwrap_err().contains("Office Laser"));
+    }
+
+    #[test]
+    fn test_archd_on_wide_format_printer_passes() {
+        let config = create_test_config(PageSize::ArchD);
+        let warnings = printer_compatibility(&config, &wide_format_printer()).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_landscape_fits_when_fed_rotated() {
+        let mut config = create_test_config(PageSize::A4);
+        config.page_layout.orientation = crate::export::pdf::PageOrientation::Landscape;
+        assert!(printer_compatibility(&config, &a4_printer()).is_ok());
+    }
+
+    #[test]
+    fn test_small_margin_warns() {
+        let mut config = create_test_config(PageSize::A4);
+        config.page_layout.margin_left = 6.0;
+
+        let warnings = printer_compatibility(&config, &a4_printer()).unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Left margin"));
+    }
+}
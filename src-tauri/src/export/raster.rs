@@ -0,0 +1,438 @@
+//! Raster Export
+//!
+//! Rasterizes drawings to PNG at a configurable DPI for thumbnails and
+//! email previews, and provides the pixel canvas shared by other PNG
+//! exporters.
+
+use super::error::ExportError;
+use super::pdf::{
+    property_f64, DrawingElement, DrawingInput, DrawingLayer, ElementType, PdfExportConfig,
+    ARROW_HALF_ANGLE_DEG, ARROW_LENGTH, ELEMENT_BOX_HEIGHT, ELEMENT_BOX_WIDTH, SYMBOL_RADIUS,
+};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufWriter;
+
+/// Largest width or height of a raster export, in pixels
+pub const MAX_RASTER_DIMENSION: u32 = 10_000;
+
+/// Stroke width for drawing lines, in points
+const LINE_WIDTH: f64 = 1.0;
+
+/// Segments used to approximate circles
+const CIRCLE_SEGMENTS: usize = 24;
+
+const BLACK: [u8; 3] = [0, 0, 0];
+
+// ============================================================================
+// Canvas (shared with other PNG exporters)
+// ============================================================================
+
+/// Converts a size in points to pixels at the given DPI
+pub(crate) fn points_to_pixels(points: f64, dpi: u32) -> u32 {
+    (points * dpi as f64 / 72.0).round() as u32
+}
+
+/// White RGB pixel buffer addressed in points, scaled to the output DPI
+pub(crate) struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    dpi: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Creates a white canvas of the given size in points
+    pub fn new(width_pt: f64, height_pt: f64, dpi: u32) -> Self {
+        let width = points_to_pixels(width_pt, dpi);
+        let height = points_to_pixels(height_pt, dpi);
+        Self {
+            width,
+            height,
+            dpi,
+            pixels: vec![255; width as usize * height as usize * 3],
+        }
+    }
+
+    fn scale(&self) -> f64 {
+        self.dpi as f64 / 72.0
+    }
+
+    /// Fills the pixels in `[x0, x1) x [y0, y1)`, clipped to the canvas
+    fn fill_pixels(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, color: [u8; 3]) {
+        let clip = |v: f64, max: u32| (v.max(0.0) as u32).min(max);
+        let (x0, x1) = (clip(x0, self.width), clip(x1, self.width));
+        let (y0, y1) = (clip(y0, self.height), clip(y1, self.height));
+
+        for py in y0..y1 {
+            for px in x0..x1 {
+                let i = (py as usize * self.width as usize + px as usize) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    /// Fills a rectangle given in points
+    pub fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64, color: [u8; 3]) {
+        let scale = self.scale();
+        self.fill_pixels(
+            (x * scale).round(),
+            (y * scale).round(),
+            ((x + width) * scale).round(),
+            ((y + height) * scale).round(),
+            color,
+        );
+    }
+
+    /// Draws a straight line between two points given in points
+    ///
+    /// Lines are never thinner than one pixel so they survive low DPIs.
+    pub fn stroke_line(&mut self, from: (f64, f64), to: (f64, f64), width: f64, color: [u8; 3]) {
+        let scale = self.scale();
+        let pen = (width * scale).round().max(1.0);
+        let length = (to.0 - from.0).hypot(to.1 - from.1);
+        let steps = (length * scale).ceil().max(1.0) as usize;
+
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let x = ((from.0 + (to.0 - from.0) * t) * scale - pen / 2.0).round();
+            let y = ((from.1 + (to.1 - from.1) * t) * scale - pen / 2.0).round();
+            self.fill_pixels(x, y, x + pen, y + pen, color);
+        }
+    }
+
+    /// Draws an open line through a sequence of points
+    pub fn stroke_polyline(&mut self, points: &[(f64, f64)], width: f64, color: [u8; 3]) {
+        for pair in points.windows(2) {
+            self.stroke_line(pair[0], pair[1], width, color);
+        }
+    }
+
+    /// Encodes the canvas as an RGB PNG, recording the DPI in the file
+    ///
+    /// Returns the size of the written file in bytes.
+    pub fn write_png(&self, output_path: &str) -> Result<u64, ExportError> {
+        let write_error = |e: &dyn std::fmt::Display| ExportError::WriteError(e.to_string());
+
+        let file = File::create(output_path).map_err(|e| write_error(&e))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let pixels_per_meter = (self.dpi as f64 / 0.0254).round() as u32;
+        encoder.set_pixel_dims(Some(png::PixelDimensions {
+            xppu: pixels_per_meter,
+            yppu: pixels_per_meter,
+            unit: png::Unit::Meter,
+        }));
+
+        let mut writer = encoder.write_header().map_err(|e| write_error(&e))?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(|e| write_error(&e))?;
+        writer.finish().map_err(|e| write_error(&e))?;
+
+        Ok(std::fs::metadata(output_path)
+            .map_err(|e| write_error(&e))?
+            .len())
+    }
+}
+
+// ============================================================================
+// Drawing Rasterization (internal)
+// ============================================================================
+
+/// Rotates a point about the origin, clockwise on screen for positive degrees
+fn rotate((x, y): (f64, f64), degrees: f64) -> (f64, f64) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    (x * cos - y * sin, x * sin + y * cos)
+}
+
+/// Draws an element with the same shapes as the PDF exporter
+///
+/// Text is not rasterized. Returns a warning instead of drawing a cable
+/// that is missing an endpoint coordinate.
+fn draw_element(
+    canvas: &mut Canvas,
+    origin: (f64, f64),
+    element: &DrawingElement,
+) -> Result<(), String> {
+    // Maps an offset from the element position to canvas points
+    let place = |offset: (f64, f64)| {
+        let (dx, dy) = rotate(offset, element.rotation);
+        (origin.0 + element.x + dx, origin.1 + element.y + dy)
+    };
+    let offset_to = |key_x: &str, key_y: &str| {
+        Some((
+            property_f64(element, key_x)? - element.x,
+            property_f64(element, key_y)? - element.y,
+        ))
+    };
+
+    match element.element_type {
+        ElementType::Equipment => {
+            let (w, h) = (ELEMENT_BOX_WIDTH / 2.0, ELEMENT_BOX_HEIGHT / 2.0);
+            let corners = [(-w, -h), (w, -h), (w, h), (-w, h), (-w, -h)].map(place);
+            canvas.stroke_polyline(&corners, LINE_WIDTH, BLACK);
+        }
+        ElementType::Cable => match (offset_to("x1", "y1"), offset_to("x2", "y2")) {
+            (Some(start), Some(end)) => {
+                canvas.stroke_line(place(start), place(end), LINE_WIDTH, BLACK);
+            }
+            _ => {
+                return Err(format!(
+                    "Skipped cable {}: missing endpoint coordinates",
+                    element.id
+                ))
+            }
+        },
+        ElementType::Text => {}
+        ElementType::Dimension => {
+            let end = offset_to("x2", "y2").unwrap_or((0.0, 0.0));
+            canvas.stroke_line(place((0.0, 0.0)), place(end), LINE_WIDTH, BLACK);
+
+            let angle = end.1.atan2(end.0);
+            let spread = ARROW_HALF_ANGLE_DEG.to_radians();
+            for (tip, direction) in [((0.0, 0.0), angle), (end, angle + std::f64::consts::PI)] {
+                let barb = |offset: f64| {
+                    place((
+                        tip.0 + ARROW_LENGTH * (direction + offset).cos(),
+                        tip.1 + ARROW_LENGTH * (direction + offset).sin(),
+                    ))
+                };
+                canvas.stroke_polyline(
+                    &[barb(spread), place(tip), barb(-spread)],
+                    LINE_WIDTH,
+                    BLACK,
+                );
+            }
+        }
+        ElementType::Symbol => {
+            let points: Vec<(f64, f64)> = (0..=CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let theta = i as f64 / CIRCLE_SEGMENTS as f64 * std::f64::consts::TAU;
+                    place((SYMBOL_RADIUS * theta.cos(), SYMBOL_RADIUS * theta.sin()))
+                })
+                .collect();
+            canvas.stroke_polyline(&points, LINE_WIDTH, BLACK);
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Raster Export
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RasterExportResult {
+    pub file_path: String,
+    pub width_px: u32,
+    pub height_px: u32,
+    pub file_size_bytes: u64,
+    pub generated_at: String,
+    /// Elements that could not be rendered and were left out of the image
+    pub warnings: Vec<String>,
+}
+
+/// Rasterizes a drawing's visible layers to a PNG at `dpi`
+///
+/// The page is sized from the export configuration's page layout, scaled
+/// from points to pixels by `dpi / 72`. Images larger than
+/// [`MAX_RASTER_DIMENSION`] on either side are rejected before any pixels
+/// are allocated.
+pub fn export_png(
+    drawing: &DrawingInput,
+    config: &PdfExportConfig,
+    output_path: &str,
+    dpi: u32,
+) -> Result<RasterExportResult, ExportError> {
+    if drawing.layers.is_empty() {
+        return Err(ExportError::NoLayers);
+    }
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+    if dpi == 0 {
+        return Err(ExportError::InvalidConfig(
+            "DPI must be greater than zero".to_string(),
+        ));
+    }
+
+    let layers: Vec<&DrawingLayer> = drawing.layers.iter().filter(|l| l.is_visible).collect();
+    if layers.is_empty() {
+        return Err(ExportError::NoVisibleLayers);
+    }
+
+    let layout = &config.page_layout;
+    let (page_width, page_height) = layout.effective_dimensions();
+    let (width_px, height_px) = (
+        points_to_pixels(page_width, dpi),
+        points_to_pixels(page_height, dpi),
+    );
+    if width_px > MAX_RASTER_DIMENSION || height_px > MAX_RASTER_DIMENSION {
+        return Err(ExportError::InvalidConfig(format!(
+            "{}x{} px at {} DPI exceeds the {} px limit per side",
+            width_px, height_px, dpi, MAX_RASTER_DIMENSION
+        )));
+    }
+
+    let mut canvas = Canvas::new(page_width, page_height, dpi);
+    let origin = (layout.margin_left, layout.margin_top);
+    let (draw_width, draw_height) = layout.drawable_area();
+    canvas.stroke_polyline(
+        &[
+            origin,
+            (origin.0 + draw_width, origin.1),
+            (origin.0 + draw_width, origin.1 + draw_height),
+            (origin.0, origin.1 + draw_height),
+            origin,
+        ],
+        LINE_WIDTH,
+        BLACK,
+    );
+
+    let mut warnings = Vec::new();
+    for element in layers.iter().flat_map(|l| &l.elements) {
+        if let Err(warning) = draw_element(&mut canvas, origin, element) {
+            warnings.push(warning);
+        }
+    }
+
+    let file_size_bytes = canvas.write_png(output_path)?;
+
+    Ok(RasterExportResult {
+        file_path: output_path.to_string(),
+        width_px: canvas.width,
+        height_px: canvas.height,
+        file_size_bytes,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings,
+    })
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a drawing as a PNG image at the given DPI
+#[tauri::command]
+pub fn export_to_png(
+    drawing: DrawingInput,
+    config: PdfExportConfig,
+    output_path: String,
+    dpi: u32,
+) -> Result<RasterExportResult, ExportError> {
+    export_png(&drawing, &config, &output_path, dpi)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingType, LayerType, TitleBlock};
+
+    fn create_test_element(id: &str, element_type: ElementType) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type,
+            x: 100.0,
+            y: 100.0,
+            rotation: 0.0,
+            properties: serde_json::json!({}),
+        }
+    }
+
+    fn create_test_drawing(elements: Vec<DrawingElement>) -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![DrawingLayer {
+                id: "layer-1".to_string(),
+                name: "AV".to_string(),
+                layer_type: LayerType::AvElements,
+                is_locked: false,
+                is_visible: true,
+                elements,
+            }],
+        }
+    }
+
+    fn create_test_config() -> PdfExportConfig {
+        PdfExportConfig::new(TitleBlock::new("Project", "Drawing"))
+    }
+
+    fn temp_png_path(file_name: &str) -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(file_name).to_string_lossy().into_owned();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_png_dimensions_follow_dpi() {
+        let drawing =
+            create_test_drawing(vec![create_test_element("elem-1", ElementType::Equipment)]);
+        let (_dir, path) = temp_png_path("drawing.png");
+
+        // Letter landscape is 792 x 612 points
+        let result = export_png(&drawing, &create_test_config(), &path, 144).unwrap();
+
+        assert_eq!((result.width_px, result.height_px), (1584, 1224));
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+        assert_eq!(result.file_size_bytes, bytes.len() as u64);
+    }
+
+    #[test]
+    fn test_every_element_type_rasterized() {
+        let mut cable = create_test_element("cable-1", ElementType::Cable);
+        cable.properties =
+            serde_json::json!({ "x1": 100.0, "y1": 100.0, "x2": 300.0, "y2": 150.0 });
+        let mut dimension = create_test_element("dim-1", ElementType::Dimension);
+        dimension.properties = serde_json::json!({ "x2": 250.0, "y2": 100.0 });
+        let mut equipment = create_test_element("elem-1", ElementType::Equipment);
+        equipment.rotation = 30.0;
+        let mut broken_cable = create_test_element("cable-2", ElementType::Cable);
+        broken_cable.properties = serde_json::json!({ "x1": 100.0 });
+        let drawing = create_test_drawing(vec![
+            equipment,
+            cable,
+            dimension,
+            create_test_element("text-1", ElementType::Text),
+            create_test_element("sym-1", ElementType::Symbol),
+            broken_cable,
+        ]);
+        let (_dir, path) = temp_png_path("types.png");
+
+        let result = export_png(&drawing, &create_test_config(), &path, 72).unwrap();
+
+        assert_eq!(
+            result.warnings,
+            vec!["Skipped cable cable-2: missing endpoint coordinates"]
+        );
+    }
+
+    #[test]
+    fn test_zero_dpi_rejected() {
+        let drawing = create_test_drawing(Vec::new());
+        let result = export_png(&drawing, &create_test_config(), "/tmp/out.png", 0);
+        assert!(matches!(result, Err(ExportError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_oversized_output_rejected() {
+        let drawing = create_test_drawing(Vec::new());
+        let mut config = create_test_config();
+        config.page_layout.size = crate::export::pdf::PageSize::ArchD;
+
+        // 36" at 600 DPI is 21600 px
+        let result = export_png(&drawing, &config, "/tmp/out.png", 600);
+
+        assert!(matches!(result, Err(ExportError::InvalidConfig(msg)) if msg.contains("10000")));
+    }
+}
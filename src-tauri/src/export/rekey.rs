@@ -0,0 +1,141 @@
+//! Drawing Re-keying
+//!
+//! Rewrites a drawing's layer and element ids with a prefix so it can be
+//! imported into a project without colliding with existing ids.
+
+use super::pdf::DrawingInput;
+use std::collections::HashSet;
+
+/// Returns a copy of the drawing with every layer and element id prefixed
+///
+/// String properties that reference another id in the same drawing (for
+/// example a cable's endpoint element) are rewritten to match, so internal
+/// references stay consistent. Other property values are left untouched.
+pub fn rekey_ids(drawing: &DrawingInput, prefix: &str) -> DrawingInput {
+    let ids: HashSet<&str> = drawing
+        .layers
+        .iter()
+        .flat_map(|l| {
+            std::iter::once(l.id.as_str()).chain(l.elements.iter().map(|e| e.id.as_str()))
+        })
+        .collect();
+
+    let rekey = |id: &str| format!("{}{}", prefix, id);
+
+    let mut rekeyed = drawing.clone();
+    for layer in &mut rekeyed.layers {
+        layer.id = rekey(&layer.id);
+        for element in &mut layer.elements {
+            element.id = rekey(&element.id);
+            if let Some(properties) = element.properties.as_object_mut() {
+                for value in properties.values_mut() {
+                    if let Some(reference) = value.as_str().filter(|v| ids.contains(v)) {
+                        *value = serde_json::Value::String(rekey(reference));
+                    }
+                }
+            }
+        }
+    }
+
+    rekeyed
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to prefix a drawing's ids before importing it into a project
+#[tauri::command]
+pub fn rekey_drawing(drawing: DrawingInput, prefix: String) -> Result<DrawingInput, String> {
+    if prefix.is_empty() {
+        return Err("Prefix cannot be empty".to_string());
+    }
+    Ok(rekey_ids(&drawing, &prefix))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingElement, DrawingLayer, DrawingType, ElementType, LayerType};
+
+    fn create_test_element(
+        id: &str,
+        element_type: ElementType,
+        properties: serde_json::Value,
+    ) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type,
+            x: 0.0,
+            y: 0.0,
+            rotation: 0.0,
+            properties,
+        }
+    }
+
+    fn create_test_drawing() -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![DrawingLayer {
+                id: "layer-1".to_string(),
+                name: "AV".to_string(),
+                layer_type: LayerType::AvElements,
+                is_locked: false,
+                is_visible: true,
+                elements: vec![
+                    create_test_element(
+                        "elem-1",
+                        ElementType::Equipment,
+                        serde_json::json!({ "label": "Display" }),
+                    ),
+                    create_test_element(
+                        "elem-2",
+                        ElementType::Equipment,
+                        serde_json::json!({ "label": "Codec" }),
+                    ),
+                    create_test_element(
+                        "cable-1",
+                        ElementType::Cable,
+                        serde_json::json!({
+                            "fromElementId": "elem-2",
+                            "toElementId": "elem-1",
+                            "label": "HDMI",
+                        }),
+                    ),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_rekey_prefixes_all_ids() {
+        let rekeyed = rekey_ids(&create_test_drawing(), "imp-");
+
+        let layer = &rekeyed.layers[0];
+        assert_eq!(layer.id, "imp-layer-1");
+        let ids: Vec<&str> = layer.elements.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["imp-elem-1", "imp-elem-2", "imp-cable-1"]);
+        assert_eq!(rekeyed.id, "drawing-1");
+    }
+
+    #[test]
+    fn test_rekey_preserves_references() {
+        let rekeyed = rekey_ids(&create_test_drawing(), "imp-");
+
+        let cable = &rekeyed.layers[0].elements[2].properties;
+        assert_eq!(cable["fromElementId"], "imp-elem-2");
+        assert_eq!(cable["toElementId"], "imp-elem-1");
+        assert_eq!(cable["label"], "HDMI");
+    }
+
+    #[test]
+    fn test_rekey_drawing_empty_prefix_errors() {
+        assert!(rekey_drawing(create_test_drawing(), String::new()).is_err());
+    }
+}
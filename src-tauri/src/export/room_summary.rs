@@ -0,0 +1,367 @@
+//! Room Summary Export
+//!
+//! Composes a one-page room overview for sales: a floor plan thumbnail,
+//! key stats and the bill of materials. The BOM spills onto continuation
+//! pages only when it does not fit below the thumbnail.
+
+use super::bom::{equipment_lines, BillOfMaterialsLine};
+use super::pdf::{
+    builtin_font, pt, save_pdf, stroke_rect, written_file_size, PdfExportConfig, PdfExportResult,
+    TEXT_FONT_SIZE,
+};
+use crate::database::Equipment;
+use crate::drawings::RoomInput;
+use printpdf::{IndirectFontRef, PdfDocument, PdfLayerReference};
+
+/// Share of the drawable height reserved for the floor plan thumbnail
+const THUMBNAIL_HEIGHT_RATIO: f64 = 0.4;
+
+/// Height of the key stats block in points
+const STATS_HEIGHT: f64 = 72.0;
+
+/// Spacing between the lines of the key stats block in points
+const STATS_LINE_HEIGHT: f64 = 14.0;
+
+/// Height of a BOM table row (and the header row) in points
+const BOM_ROW_HEIGHT: f64 = 14.0;
+
+/// BOM table columns and their share of the drawable width
+const BOM_COLUMNS: [(&str, f64); 5] = [
+    ("MANUFACTURER", 0.25),
+    ("MODEL", 0.3),
+    ("QTY", 0.1),
+    ("UNIT COST", 0.15),
+    ("EXTENDED", 0.2),
+];
+
+/// Side length of the marker drawn for each placed device, in points
+const MARKER_SIZE: f64 = 4.0;
+
+// ============================================================================
+// Summary Layout (internal)
+// ============================================================================
+
+#[derive(Debug)]
+struct RoomSummaryLayout {
+    thumbnail_width: f64,
+    thumbnail_height: f64,
+    thumbnail_scale: f64,
+    first_page_rows: usize,
+    continuation_page_rows: usize,
+    page_count: u32,
+}
+
+/// Lays out the thumbnail, stats and BOM table on the configured page
+fn layout_room_summary(
+    room: &RoomInput,
+    config: &PdfExportConfig,
+    bom_rows: usize,
+) -> Result<RoomSummaryLayout, String> {
+    let (draw_width, draw_height) = config.page_layout.drawable_area();
+
+    // Fit the room into the thumbnail box, preserving aspect ratio
+    let box_height = draw_height * THUMBNAIL_HEIGHT_RATIO;
+    let thumbnail_scale = (draw_width / room.width).min(box_height / room.length);
+
+    // Each page repeats the table header row
+    let rows_in = |height: f64| ((height / BOM_ROW_HEIGHT).floor() as usize).saturating_sub(1);
+    let first_page_rows = rows_in(draw_height - box_height - STATS_HEIGHT);
+    let continuation_page_rows = rows_in(draw_height);
+
+    if continuation_page_rows == 0 {
+        return Err("Page is too small to fit the bill of materials".to_string());
+    }
+
+    let overflow = bom_rows.saturating_sub(first_page_rows);
+    let page_count = 1 + overflow.div_ceil(continuation_page_rows) as u32;
+
+    Ok(RoomSummaryLayout {
+        thumbnail_width: room.width * thumbnail_scale,
+        thumbnail_height: room.length * thumbnail_scale,
+        thumbnail_scale,
+        first_page_rows,
+        continuation_page_rows,
+        page_count,
+    })
+}
+
+// ============================================================================
+// Room Summary Generator
+// ============================================================================
+
+/// Generates a room summary PDF and writes it to `output_path`
+///
+/// The first page shows the floor plan thumbnail with a marker per placed
+/// device, the key stats and as many BOM rows as fit below them; the rest
+/// of the BOM continues on further pages.
+pub fn generate_room_summary(
+    room: &RoomInput,
+    catalog: &[Equipment],
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<PdfExportResult, String> {
+    if output_path.is_empty() {
+        return Err("Output path cannot be empty".to_string());
+    }
+
+    if room.width <= 0.0 || room.length <= 0.0 {
+        return Err("Room dimensions must be greater than zero".to_string());
+    }
+
+    let bom = equipment_lines(room, catalog);
+    let layout = layout_room_summary(room, config, bom.len())?;
+
+    write_room_summary(room, &bom, &layout, config, output_path)?;
+
+    Ok(PdfExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes: written_file_size(output_path)?,
+        page_count: layout.page_count,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        warnings: Vec::new(),
+    })
+}
+
+/// Lists the key stats shown below the thumbnail
+fn summary_stats(room: &RoomInput, bom: &[BillOfMaterialsLine]) -> Vec<String> {
+    let device_count: u32 = bom.iter().map(|l| l.quantity).sum();
+    let mut lines = vec![
+        room.name.clone(),
+        format!(
+            "Dimensions: {} x {} ft, {} ft ceiling",
+            room.width, room.length, room.ceiling_height
+        ),
+        format!(
+            "Equipment: {} devices, {} line items",
+            device_count,
+            bom.len()
+        ),
+    ];
+
+    let costs: Vec<f64> = bom.iter().filter_map(|l| l.extended_cost).collect();
+    if !costs.is_empty() {
+        lines.push(format!("Total cost: {:.2}", costs.iter().sum::<f64>()));
+    }
+
+    lines
+}
+
+/// Draws a BOM table whose top edge is `top` points up the page, header
+/// row first
+fn draw_bom_table(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    left: f64,
+    top: f64,
+    width: f64,
+    lines: &[BillOfMaterialsLine],
+) {
+    let money = |value: Option<f64>| value.map(|v| format!("{:.2}", v)).unwrap_or_default();
+    let header = BOM_COLUMNS.map(|(name, _)| name.to_string());
+    let rows = lines.iter().map(|line| {
+        [
+            line.manufacturer.clone(),
+            line.model.clone(),
+            line.quantity.to_string(),
+            money(line.unit_cost),
+            money(line.extended_cost),
+        ]
+    });
+
+    for (index, row) in [header].into_iter().chain(rows).enumerate() {
+        let y = top - (index as f64 + 1.0) * BOM_ROW_HEIGHT;
+        stroke_rect(layer, left, y, width, BOM_ROW_HEIGHT);
+
+        let mut cell_x = left;
+        for ((_, share), text) in BOM_COLUMNS.iter().zip(&row) {
+            layer.use_text(
+                text.as_str(),
+                TEXT_FONT_SIZE,
+                pt(cell_x + 2.0),
+                pt(y + 3.0),
+                font,
+            );
+            cell_x += width * share;
+        }
+    }
+}
+
+/// Renders the summary pages and writes the PDF file
+fn write_room_summary(
+    room: &RoomInput,
+    bom: &[BillOfMaterialsLine],
+    layout: &RoomSummaryLayout,
+    config: &PdfExportConfig,
+    output_path: &str,
+) -> Result<(), String> {
+    let page_layout = &config.page_layout;
+    let (page_width, page_height) = page_layout.effective_dimensions();
+    let (draw_width, draw_height) = page_layout.drawable_area();
+    let left = page_layout.margin_left;
+    let top = page_height - page_layout.margin_top;
+
+    let (doc, first_page, first_layer) = PdfDocument::new(
+        room.name.as_str(),
+        pt(page_width),
+        pt(page_height),
+        "Room Summary",
+    );
+    let font = doc
+        .add_builtin_font(builtin_font(config.font_family.as_deref()))
+        .map_err(|e| format!("Failed to load font: {}", e))?;
+    let layer = doc.get_page(first_page).get_layer(first_layer);
+
+    // Thumbnail centered at the top of the drawable area, y pointing down
+    let thumbnail_left = left + (draw_width - layout.thumbnail_width) / 2.0;
+    stroke_rect(
+        &layer,
+        thumbnail_left,
+        top - layout.thumbnail_height,
+        layout.thumbnail_width,
+        layout.thumbnail_height,
+    );
+    for placed in &room.placed_equipment {
+        stroke_rect(
+            &layer,
+            thumbnail_left + placed.x * layout.thumbnail_scale - MARKER_SIZE / 2.0,
+            top - placed.y * layout.thumbnail_scale - MARKER_SIZE / 2.0,
+            MARKER_SIZE,
+            MARKER_SIZE,
+        );
+    }
+
+    let stats_top = top - draw_height * THUMBNAIL_HEIGHT_RATIO;
+    for (row, line) in summary_stats(room, bom).iter().enumerate() {
+        layer.use_text(
+            line.as_str(),
+            TEXT_FONT_SIZE,
+            pt(left),
+            pt(stats_top - (row as f64 + 1.0) * STATS_LINE_HEIGHT),
+            &font,
+        );
+    }
+
+    let (first_rows, overflow) = bom.split_at(layout.first_page_rows.min(bom.len()));
+    let table_top = stats_top - STATS_HEIGHT;
+    draw_bom_table(&layer, &font, left, table_top, draw_width, first_rows);
+
+    for rows in overflow.chunks(layout.continuation_page_rows) {
+        let (page, page_layer) = doc.add_page(pt(page_width), pt(page_height), "Room Summary");
+        let layer = doc.get_page(page).get_layer(page_layer);
+        draw_bom_table(&layer, &font, left, top, draw_width, rows);
+    }
+
+    save_pdf(doc, output_path)
+}
+
+// ============================================================================
+// Tauri Command
+// ============================================================================
+
+/// Tauri command to export a one-page room summary PDF
+#[tauri::command]
+pub fn export_room_summary(
+    room: RoomInput,
+    catalog: Vec<Equipment>,
+    config: PdfExportConfig,
+    output_path: String,
+) -> Result<PdfExportResult, String> {
+    generate_room_summary(&room, &catalog, &config, &output_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{self, create_test_placed_equipment};
+    use crate::drawings::{EquipmentCategory, PlacedEquipmentInput};
+    use crate::export::pdf::TitleBlock;
+
+    fn create_test_equipment(id: &str, cost: f64) -> Equipment {
+        Equipment {
+            cost: Some(cost),
+            ..test_support::create_test_catalog_equipment(id, EquipmentCategory::Video, "displays")
+        }
+    }
+
+    fn create_test_placed(id: &str, equipment_id: &str) -> PlacedEquipmentInput {
+        PlacedEquipmentInput {
+            x: 5.0,
+            y: 5.0,
+            ..create_test_placed_equipment(id, equipment_id)
+        }
+    }
+
+    /// Builds a room with `distinct` different equipment ids placed once each
+    fn create_test_room(distinct: usize) -> (RoomInput, Vec<Equipment>) {
+        let catalog: Vec<Equipment> = (0..distinct)
+            .map(|i| create_test_equipment(&format!("eq-{}", i), 100.0))
+            .collect();
+        let placed_equipment = catalog
+            .iter()
+            .enumerate()
+            .map(|(i, e)| create_test_placed(&format!("placed-{}", i), &e.id))
+            .collect();
+
+        let room = RoomInput {
+            length: 30.0,
+            ..test_support::create_test_room(placed_equipment)
+        };
+
+        (room, catalog)
+    }
+
+    fn create_test_config() -> PdfExportConfig {
+        PdfExportConfig::new(TitleBlock::new("Test Project", "Room Summary"))
+    }
+
+    /// Returns an output path inside a fresh temp dir, kept alive by the guard
+    fn temp_pdf_path() -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join("summary.pdf")
+            .to_string_lossy()
+            .into_owned();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_short_bom_renders_single_page() {
+        let (room, catalog) = create_test_room(5);
+        let config = create_test_config();
+        let (_dir, path) = temp_pdf_path();
+
+        let result = generate_room_summary(&room, &catalog, &config, &path).unwrap();
+
+        assert_eq!(result.page_count, 1);
+        assert_eq!(
+            result.file_size_bytes,
+            std::fs::metadata(&path).unwrap().len()
+        );
+        assert_eq!(lopdf::Document::load(&path).unwrap().get_pages().len(), 1);
+    }
+
+    #[test]
+    fn test_long_bom_spills_to_second_page() {
+        let (room, catalog) = create_test_room(40);
+        let config = create_test_config();
+        let (_dir, path) = temp_pdf_path();
+
+        let result = generate_room_summary(&room, &catalog, &config, &path).unwrap();
+
+        assert_eq!(result.page_count, 2);
+        assert_eq!(lopdf::Document::load(&path).unwrap().get_pages().len(), 2);
+    }
+
+    #[test]
+    fn test_room_summary_empty_output_path() {
+        let (room, catalog) = create_test_room(1);
+        let config = create_test_config();
+
+        assert!(generate_room_summary(&room, &catalog, &config, "").is_err());
+    }
+}
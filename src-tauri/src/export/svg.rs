@@ -0,0 +1,497 @@
+//! SVG Export Module
+//!
+//! Renders drawing layers to SVG markup, either as a string for the
+//! clipboard or written to a file.
+
+use super::error::ExportError;
+use super::legend::{self, SignalColorScheme};
+use super::pdf::{
+    DrawingInput, DrawingLayer, ElementType, PageLayout, RevisionEntry, TitleBlock,
+    REVISION_COLUMNS, REVISION_ROW_HEIGHT, TITLE_BLOCK_WIDTH,
+};
+use crate::drawings::SignalType;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+// ============================================================================
+// SVG Export Configuration
+// ============================================================================
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvgExportConfig {
+    pub page_layout: PageLayout,
+    #[serde(default)]
+    pub include_hidden_layers: bool,
+    /// Title block whose revision table is drawn in the lower right corner
+    #[serde(default)]
+    pub title_block: Option<TitleBlock>,
+    /// Cable colors by signal type
+    #[serde(default)]
+    pub signal_colors: SignalColorScheme,
+}
+
+// ============================================================================
+// SVG Export Result
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvgExportResult {
+    pub file_path: String,
+    pub file_size_bytes: u64,
+    pub generated_at: String,
+}
+
+// ============================================================================
+// SVG Rendering
+// ============================================================================
+
+/// Equipment block size in points
+const EQUIPMENT_WIDTH: f64 = 48.0;
+const EQUIPMENT_HEIGHT: f64 = 24.0;
+
+/// Escapes text for use in SVG content and attribute values
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Reads a string property from an element, if present
+fn property_str<'a>(properties: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    properties.get(key).and_then(|v| v.as_str())
+}
+
+/// Reads a numeric property from an element, if present
+fn property_f64(properties: &serde_json::Value, key: &str) -> Option<f64> {
+    properties.get(key).and_then(|v| v.as_f64())
+}
+
+/// SVG color value for an RGB triple
+fn rgb([r, g, b]: [u8; 3]) -> String {
+    format!("rgb({},{},{})", r, g, b)
+}
+
+/// Renders a single layer as an SVG group, coloring cables by signal type
+fn render_layer(out: &mut String, layer: &DrawingLayer, colors: &SignalColorScheme) {
+    let layer_type = serde_json::to_value(layer.layer_type)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        out,
+        r#"<g id="{}" data-layer-type="{}">"#,
+        escape_xml(&layer.id),
+        layer_type
+    );
+
+    for element in &layer.elements {
+        let label = property_str(&element.properties, "label").unwrap_or(element.id.as_str());
+        let transform = format!(
+            r#"transform="rotate({} {} {})""#,
+            element.rotation, element.x, element.y
+        );
+
+        match element.element_type {
+            ElementType::Equipment => {
+                let _ = writeln!(
+                    out,
+                    r#"<g id="{}" {}><rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="black"/><text x="{}" y="{}" text-anchor="middle" font-size="8">{}</text></g>"#,
+                    escape_xml(&element.id),
+                    transform,
+                    element.x - EQUIPMENT_WIDTH / 2.0,
+                    element.y - EQUIPMENT_HEIGHT / 2.0,
+                    EQUIPMENT_WIDTH,
+                    EQUIPMENT_HEIGHT,
+                    element.x,
+                    element.y + 3.0,
+                    escape_xml(label)
+                );
+            }
+            ElementType::Cable | ElementType::Dimension => {
+                let x2 = property_f64(&element.properties, "x2").unwrap_or(element.x);
+                let y2 = property_f64(&element.properties, "y2").unwrap_or(element.y);
+                let dash = if element.element_type == ElementType::Dimension {
+                    r#" stroke-dasharray="4 2""#
+                } else {
+                    ""
+                };
+                let stroke = match element.element_type {
+                    ElementType::Cable => {
+                        legend::cable_signal_type(&element.properties).map(|s| rgb(colors.color(s)))
+                    }
+                    _ => None,
+                };
+                let _ = writeln!(
+                    out,
+                    r#"<line id="{}" x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}"{}/>"#,
+                    escape_xml(&element.id),
+                    element.x,
+                    element.y,
+                    x2,
+                    y2,
+                    stroke.as_deref().unwrap_or("black"),
+                    dash
+                );
+            }
+            ElementType::Text => {
+                let text = property_str(&element.properties, "text").unwrap_or(label);
+                let _ = writeln!(
+                    out,
+                    r#"<text id="{}" x="{}" y="{}" font-size="10" {}>{}</text>"#,
+                    escape_xml(&element.id),
+                    element.x,
+                    element.y,
+                    transform,
+                    escape_xml(text)
+                );
+            }
+            ElementType::Symbol => {
+                let _ = writeln!(
+                    out,
+                    r#"<circle id="{}" cx="{}" cy="{}" r="6" fill="none" stroke="black"/>"#,
+                    escape_xml(&element.id),
+                    element.x,
+                    element.y
+                );
+            }
+        }
+    }
+
+    out.push_str("</g>\n");
+}
+
+/// Renders the revision table into the lower right corner of the drawable
+/// area, header on top and the newest revision at the bottom
+fn render_revision_table(out: &mut String, rows: &[RevisionEntry], layout: &PageLayout) {
+    if rows.is_empty() {
+        return;
+    }
+
+    let (width, height) = layout.drawable_area();
+    let x = width - TITLE_BLOCK_WIDTH;
+    let header = REVISION_COLUMNS.map(|(name, _)| name);
+    let cells = rows.iter().rev().map(RevisionEntry::cells);
+
+    out.push_str("<g id=\"revisions\">\n");
+    for (index, row) in cells.chain([header]).enumerate() {
+        let y = height - (index as f64 + 1.0) * REVISION_ROW_HEIGHT;
+        let _ = writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="black"/>"#,
+            x, y, TITLE_BLOCK_WIDTH, REVISION_ROW_HEIGHT
+        );
+
+        let mut cell_x = x;
+        for ((_, column_width), text) in REVISION_COLUMNS.iter().zip(row) {
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" font-size="8">{}</text>"#,
+                cell_x + 2.0,
+                y + REVISION_ROW_HEIGHT - 3.0,
+                escape_xml(text)
+            );
+            cell_x += column_width;
+        }
+    }
+    out.push_str("</g>\n");
+}
+
+/// Renders a key of the given signal types' cable colors into the lower
+/// left corner of the drawable area
+fn render_signal_legend(
+    out: &mut String,
+    signals: &[SignalType],
+    colors: &SignalColorScheme,
+    layout: &PageLayout,
+) {
+    if signals.is_empty() {
+        return;
+    }
+
+    let longest_label = signals
+        .iter()
+        .map(|s| legend::signal_label(*s).len())
+        .max()
+        .unwrap_or_default();
+    let width = legend::PADDING * 2.0
+        + legend::SWATCH_LENGTH
+        + legend::LABEL_GAP
+        + longest_label as f64 * legend::GLYPH_WIDTH;
+    let height = legend::PADDING * 2.0 + signals.len() as f64 * legend::ROW_HEIGHT;
+    let top = layout.drawable_area().1 - height;
+
+    out.push_str("<g id=\"signal-legend\">\n");
+    let _ = writeln!(
+        out,
+        r#"<rect x="0" y="{}" width="{}" height="{}" fill="white" stroke="black"/>"#,
+        top, width, height
+    );
+    for (row, signal_type) in signals.iter().enumerate() {
+        let center_y = top + legend::PADDING + (row as f64 + 0.5) * legend::ROW_HEIGHT;
+        let _ = writeln!(
+            out,
+            r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke="{}" stroke-width="2"/>"#,
+            legend::PADDING,
+            legend::PADDING + legend::SWATCH_LENGTH,
+            rgb(colors.color(*signal_type)),
+            y = center_y
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" font-size="8">{}</text>"#,
+            legend::PADDING + legend::SWATCH_LENGTH + legend::LABEL_GAP,
+            center_y + 3.0,
+            legend::signal_label(*signal_type)
+        );
+    }
+    out.push_str("</g>\n");
+}
+
+/// Renders a drawing to SVG markup
+///
+/// Each rendered layer becomes a `<g>` group carrying the layer id and
+/// type, drawn inside the page margins. Cables are colored by signal type,
+/// with a legend of the signal types present in the lower left corner. When
+/// a title block is configured, its revision table is drawn in the lower
+/// right corner.
+pub fn render_svg(drawing: &DrawingInput, config: &SvgExportConfig) -> Result<String, ExportError> {
+    if drawing.layers.is_empty() {
+        return Err(ExportError::NoLayers);
+    }
+
+    let layers: Vec<&DrawingLayer> = drawing
+        .layers
+        .iter()
+        .filter(|l| l.is_visible || config.include_hidden_layers)
+        .collect();
+
+    if layers.is_empty() {
+        return Err(ExportError::NoVisibleLayers);
+    }
+
+    let (width, height) = config.page_layout.effective_dimensions();
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}pt" height="{h}pt" viewBox="0 0 {w} {h}" data-drawing-id="{id}">"#,
+        w = width,
+        h = height,
+        id = escape_xml(&drawing.id)
+    );
+    let _ = writeln!(
+        out,
+        r#"<g transform="translate({} {})">"#,
+        config.page_layout.margin_left, config.page_layout.margin_top
+    );
+
+    for layer in &layers {
+        render_layer(&mut out, layer, &config.signal_colors);
+    }
+    render_signal_legend(
+        &mut out,
+        &legend::signals_present(&layers),
+        &config.signal_colors,
+        &config.page_layout,
+    );
+    if let Some(title_block) = &config.title_block {
+        render_revision_table(&mut out, &title_block.revision_rows(), &config.page_layout);
+    }
+
+    out.push_str("</g>\n</svg>\n");
+
+    Ok(out)
+}
+
+/// Renders a drawing to SVG and writes it to `output_path`
+pub fn write_svg(
+    drawing: &DrawingInput,
+    config: &SvgExportConfig,
+    output_path: &str,
+) -> Result<SvgExportResult, ExportError> {
+    if output_path.is_empty() {
+        return Err(ExportError::EmptyOutputPath);
+    }
+
+    let svg = render_svg(drawing, config)?;
+    std::fs::write(output_path, &svg).map_err(|e| ExportError::WriteError(e.to_string()))?;
+
+    Ok(SvgExportResult {
+        file_path: output_path.to_string(),
+        file_size_bytes: svg.len() as u64,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Tauri command to export a drawing to an SVG file
+#[tauri::command]
+pub fn export_to_svg(
+    drawing: DrawingInput,
+    config: SvgExportConfig,
+    output_path: String,
+) -> Result<SvgExportResult, ExportError> {
+    write_svg(&drawing, &config, &output_path)
+}
+
+/// Tauri command returning SVG markup for copying to the clipboard
+#[tauri::command]
+pub fn diagram_to_svg_string(
+    drawing: DrawingInput,
+    config: SvgExportConfig,
+) -> Result<String, ExportError> {
+    render_svg(&drawing, &config)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::pdf::{DrawingElement, DrawingType, LayerType};
+
+    fn create_test_element(id: &str, element_type: ElementType) -> DrawingElement {
+        DrawingElement {
+            id: id.to_string(),
+            element_type,
+            x: 100.0,
+            y: 100.0,
+            rotation: 0.0,
+            properties: serde_json::json!({}),
+        }
+    }
+
+    fn create_test_layer(id: &str, layer_type: LayerType, visible: bool) -> DrawingLayer {
+        DrawingLayer {
+            id: id.to_string(),
+            name: format!("Layer {}", id),
+            layer_type,
+            is_locked: false,
+            is_visible: visible,
+            elements: vec![create_test_element("elem-1", ElementType::Equipment)],
+        }
+    }
+
+    fn create_test_drawing() -> DrawingInput {
+        DrawingInput {
+            id: "drawing-1".to_string(),
+            room_id: "room-1".to_string(),
+            drawing_type: DrawingType::Electrical,
+            layers: vec![
+                create_test_layer("layer-1", LayerType::AvElements, true),
+                create_test_layer("layer-2", LayerType::Annotations, true),
+                create_test_layer("layer-3", LayerType::Dimensions, false),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_svg_string_contains_layer_groups() {
+        let drawing = create_test_drawing();
+
+        let svg = render_svg(&drawing, &SvgExportConfig::default()).unwrap();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r#"<g id="layer-1" data-layer-type="av_elements">"#));
+        assert!(svg.contains(r#"<g id="layer-2" data-layer-type="annotations">"#));
+        assert!(!svg.contains("layer-3"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_svg_escapes_labels() {
+        let mut drawing = create_test_drawing();
+        drawing.layers[0].elements[0].properties = serde_json::json!({ "label": "A&B <Rack>" });
+
+        let svg = render_svg(&drawing, &SvgExportConfig::default()).unwrap();
+
+        assert!(svg.contains("A&amp;B &lt;Rack&gt;"));
+    }
+
+    #[test]
+    fn test_svg_renders_revision_table() {
+        let mut title_block = TitleBlock::new("Project", "Drawing");
+        title_block.revision = "B".to_string();
+        title_block.revisions = vec![RevisionEntry {
+            revision: "A".to_string(),
+            date: "2024-01-15".to_string(),
+            description: "Issued <for> review".to_string(),
+            revised_by: "JD".to_string(),
+        }];
+        let config = SvgExportConfig {
+            title_block: Some(title_block),
+            ..Default::default()
+        };
+
+        let svg = render_svg(&create_test_drawing(), &config).unwrap();
+
+        assert!(svg.contains(r#"<g id="revisions">"#));
+        assert!(svg.contains("DESCRIPTION"));
+        assert!(svg.contains("Issued &lt;for&gt; review"));
+        // Header plus the history row and the current revision
+        let table = &svg[svg.find(r#"<g id="revisions">"#).unwrap()..];
+        assert_eq!(table.matches("<rect").count(), 3);
+    }
+
+    #[test]
+    fn test_svg_cables_colored_by_signal_type() {
+        let cable = |id: &str, signal_type: &str| {
+            let mut cable = create_test_element(id, ElementType::Cable);
+            cable.properties =
+                serde_json::json!({ "x2": 50.0, "y2": 50.0, "signal_type": signal_type });
+            cable
+        };
+        let mut drawing = create_test_drawing();
+        drawing.layers[0].elements = vec![cable("c1", "audio"), cable("c2", "network")];
+        let mut config = SvgExportConfig::default();
+        config.signal_colors.network = [1, 2, 3];
+
+        let svg = render_svg(&drawing, &config).unwrap();
+
+        assert!(svg.contains(r#"id="c1" x1="100" y1="100" x2="50" y2="50" stroke="rgb(0,150,70)""#));
+        assert!(svg.contains(r#"stroke="rgb(1,2,3)""#));
+        let legend = &svg[svg.find(r#"<g id="signal-legend">"#).unwrap()..];
+        assert!(legend.contains(">AUDIO<") && legend.contains(">NETWORK<"));
+        assert!(!legend.contains(">VIDEO<"));
+    }
+
+    #[test]
+    fn test_svg_legend_omitted_without_signal_types() {
+        let svg = render_svg(&create_test_drawing(), &SvgExportConfig::default()).unwrap();
+        assert!(!svg.contains("signal-legend"));
+    }
+
+    #[test]
+    fn test_svg_no_visible_layers() {
+        let mut drawing = create_test_drawing();
+        drawing.layers.truncate(1);
+        drawing.layers[0].is_visible = false;
+
+        let result = render_svg(&drawing, &SvgExportConfig::default());
+        assert!(matches!(result, Err(ExportError::NoVisibleLayers)));
+    }
+
+    #[test]
+    fn test_write_svg_matches_string() {
+        let drawing = create_test_drawing();
+        let config = SvgExportConfig::default();
+        let file = tempfile::NamedTempFile::with_suffix(".svg").unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let result = write_svg(&drawing, &config, path).unwrap();
+
+        let written = std::fs::read_to_string(path).unwrap();
+        assert_eq!(written, render_svg(&drawing, &config).unwrap());
+        assert_eq!(result.file_size_bytes, written.len() as u64);
+    }
+}
@@ -0,0 +1,236 @@
+//! Category Reconciliation
+//!
+//! Maps free-text categories from vendor pricing sheets onto our controlled
+//! equipment category and subcategory vocabulary.
+
+use crate::drawings::EquipmentCategory;
+
+/// Synonyms for each category/subcategory pair, compared after normalization
+const CATEGORY_SYNONYMS: &[(&[&str], EquipmentCategory, &str)] = &[
+    (
+        &[
+            "display",
+            "displays",
+            "monitor",
+            "monitors",
+            "tv",
+            "flat panel",
+            "projector",
+            "projectors",
+        ],
+        EquipmentCategory::Video,
+        "displays",
+    ),
+    (
+        &[
+            "camera",
+            "cameras",
+            "ptz camera",
+            "ptz cameras",
+            "video camera",
+        ],
+        EquipmentCategory::Video,
+        "cameras",
+    ),
+    (
+        &[
+            "codec",
+            "codecs",
+            "video conferencing",
+            "video bar",
+            "video bars",
+            "vc codec",
+        ],
+        EquipmentCategory::Video,
+        "codecs",
+    ),
+    (
+        &[
+            "switcher",
+            "switchers",
+            "matrix switcher",
+            "video switcher",
+            "presentation switcher",
+        ],
+        EquipmentCategory::Video,
+        "switchers",
+    ),
+    (
+        &[
+            "extender",
+            "extenders",
+            "hdbaset",
+            "transmitter",
+            "receiver",
+        ],
+        EquipmentCategory::Video,
+        "extenders",
+    ),
+    (
+        &[
+            "microphone",
+            "microphones",
+            "mic",
+            "mics",
+            "ceiling mic",
+            "wireless mic",
+        ],
+        EquipmentCategory::Audio,
+        "microphones",
+    ),
+    (
+        &[
+            "speaker",
+            "speakers",
+            "loudspeaker",
+            "loudspeakers",
+            "ceiling speaker",
+        ],
+        EquipmentCategory::Audio,
+        "speakers",
+    ),
+    (
+        &[
+            "dsp",
+            "audio dsp",
+            "digital signal processor",
+            "audio processor",
+        ],
+        EquipmentCategory::Audio,
+        "dsp",
+    ),
+    (
+        &["amplifier", "amplifiers", "amp", "amps", "power amplifier"],
+        EquipmentCategory::Audio,
+        "amplifiers",
+    ),
+    (
+        &["mixer", "mixers", "audio mixer"],
+        EquipmentCategory::Audio,
+        "mixers",
+    ),
+    (
+        &[
+            "control",
+            "av control",
+            "control system",
+            "control systems",
+            "control processor",
+            "processor",
+        ],
+        EquipmentCategory::Control,
+        "processors",
+    ),
+    (
+        &[
+            "touch panel",
+            "touch panels",
+            "touchpanel",
+            "touch screen",
+            "touchscreen",
+        ],
+        EquipmentCategory::Control,
+        "touch-panels",
+    ),
+    (
+        &["keypad", "keypads", "button panel"],
+        EquipmentCategory::Control,
+        "keypads",
+    ),
+    (
+        &["control interface", "interface", "interfaces", "gateway"],
+        EquipmentCategory::Control,
+        "interfaces",
+    ),
+    (
+        &["rack", "racks", "equipment rack", "enclosure"],
+        EquipmentCategory::Infrastructure,
+        "racks",
+    ),
+    (
+        &["mount", "mounts", "bracket", "brackets", "display mount"],
+        EquipmentCategory::Infrastructure,
+        "mounts",
+    ),
+    (
+        &["cable", "cables", "cabling", "wire"],
+        EquipmentCategory::Infrastructure,
+        "cables",
+    ),
+    (
+        &["connector", "connectors", "adapter", "adapters"],
+        EquipmentCategory::Infrastructure,
+        "connectors",
+    ),
+    (
+        &["power", "pdu", "power distribution", "ups"],
+        EquipmentCategory::Infrastructure,
+        "power",
+    ),
+];
+
+/// Lowercases, treats punctuation as spaces and collapses whitespace
+fn normalize(raw: &str) -> String {
+    raw.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Maps a free-text category onto an equipment category and subcategory
+///
+/// Returns `None` when the value is not in the synonym table so the import
+/// wizard can ask the user to choose.
+pub fn map_category(raw: &str) -> Option<(EquipmentCategory, String)> {
+    let normalized = normalize(raw);
+    if normalized.is_empty() {
+        return None;
+    }
+
+    CATEGORY_SYNONYMS
+        .iter()
+        .find(|(synonyms, _, _)| synonyms.contains(&normalized.as_str()))
+        .map(|(_, category, subcategory)| (*category, subcategory.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_category_av_control() {
+        assert_eq!(
+            map_category("AV Control"),
+            Some((EquipmentCategory::Control, "processors".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_map_category_loudspeaker() {
+        assert_eq!(
+            map_category("Loudspeaker"),
+            Some((EquipmentCategory::Audio, "speakers".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_map_category_ignores_case_and_punctuation() {
+        assert_eq!(
+            map_category("  Touch-Panels "),
+            Some((EquipmentCategory::Control, "touch-panels".to_string()))
+        );
+        assert_eq!(
+            map_category("PTZ Cameras"),
+            Some((EquipmentCategory::Video, "cameras".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_map_category_unmapped() {
+        assert_eq!(map_category("qwxz blorp"), None);
+        assert_eq!(map_category(""), None);
+    }
+}
@@ -0,0 +1,420 @@
+//! Import Commit
+//!
+//! Writes validated import rows to the equipment catalog: new rows are
+//! inserted and matched rows update their existing record, all in one
+//! transaction so a failed import leaves the catalog untouched.
+
+use super::category::map_category;
+use super::parser::{
+    mapped_value, parse_currency, parse_dimension_mm, ColumnMapping, DecimalSeparator,
+    EquipmentField, ImportError, MatchType, ParsedRow, ValidationResult, ValidationStatus,
+};
+use crate::database::{get_equipment_by_id, insert_equipment, update_equipment_record, Equipment};
+use crate::drawings::EquipmentSpecs;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Millimetres per inch, the unit catalog dimensions are stored in
+const MM_PER_INCH: f64 = 25.4;
+
+/// Counts of what committing an import did to the catalog
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    /// Rows not written: not valid, or new rows with an unrecognized category
+    pub skipped: usize,
+}
+
+/// Map a catalog failure onto an import error
+fn write_error(error: impl std::fmt::Display) -> ImportError {
+    ImportError::WriteError(error.to_string())
+}
+
+/// Overwrite an equipment record's fields with the row's non-empty mapped values
+///
+/// Prices and dimensions prefer the values parsed during validation, which
+/// honor the import's decimal separator.
+fn apply_row(
+    equipment: &mut Equipment,
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    result: &ValidationResult,
+) {
+    let value = |field| {
+        mapped_value(row, mappings, field)
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+    let text = |field| value(field).map(str::to_string);
+    let inches = |field, parsed: Option<f64>| {
+        parsed
+            .or_else(|| parse_dimension_mm(value(field)?, DecimalSeparator::Point))
+            .map(|mm| mm / MM_PER_INCH)
+    };
+
+    // Subcategories go through the same vocabulary as categories so checks
+    // keyed on e.g. "cameras" see imported gear; an unrecognized subcategory
+    // is stored as given only when the category is unrecognized too
+    let subcategory = value(EquipmentField::Subcategory);
+    match (
+        value(EquipmentField::Category).and_then(map_category),
+        subcategory.and_then(map_category),
+    ) {
+        (Some((category, _)), Some((_, subcategory)))
+        | (Some((category, subcategory)), None)
+        | (None, Some((category, subcategory))) => {
+            equipment.category = category;
+            equipment.subcategory = subcategory;
+        }
+        (None, None) => {
+            if let Some(subcategory) = subcategory {
+                equipment.subcategory = subcategory.to_string();
+            }
+        }
+    }
+    if let Some(manufacturer) = text(EquipmentField::Manufacturer) {
+        equipment.manufacturer = manufacturer;
+    }
+    if let Some(model) = text(EquipmentField::Model) {
+        equipment.model = model;
+    }
+    if let Some(sku) = text(EquipmentField::Sku) {
+        equipment.sku = sku;
+    }
+    if let Some(description) = text(EquipmentField::Description) {
+        equipment.description = Some(description);
+    }
+    if let Some(cost) = result
+        .parsed_cost
+        .or_else(|| value(EquipmentField::Cost).and_then(parse_currency))
+    {
+        equipment.cost = Some(cost);
+    }
+    if let Some(msrp) = result
+        .parsed_msrp
+        .or_else(|| value(EquipmentField::Msrp).and_then(parse_currency))
+    {
+        equipment.msrp = Some(msrp);
+    }
+    if let Some(weight) = value(EquipmentField::Weight).and_then(parse_currency) {
+        equipment.weight = Some(weight);
+    }
+    if let Some(certifications) = value(EquipmentField::Certifications) {
+        equipment.certifications = certifications
+            .split([',', ';'])
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
+    let dimensions = result.parsed_dimensions_mm;
+    let specs = &mut equipment.specs;
+    specs.height = inches(EquipmentField::Height, dimensions.height).or(specs.height);
+    specs.width = inches(EquipmentField::Width, dimensions.width).or(specs.width);
+    specs.depth = inches(EquipmentField::Depth, dimensions.depth).or(specs.depth);
+    if let Some(wattage) = value(EquipmentField::Wattage)
+        .and_then(|w| parse_currency(w.trim_end_matches(['W', 'w']).trim()))
+    {
+        specs.wattage = Some(wattage);
+    }
+    if let Some(voltage) = text(EquipmentField::Voltage) {
+        specs.voltage = Some(voltage);
+    }
+    if let Some(image_url) = text(EquipmentField::ImageUrl) {
+        specs.image_url = Some(image_url);
+    }
+}
+
+/// Build a new equipment record from a row, if its category is recognized
+fn new_equipment(
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    result: &ValidationResult,
+) -> Option<Equipment> {
+    let (category, subcategory) = [EquipmentField::Category, EquipmentField::Subcategory]
+        .into_iter()
+        .find_map(|field| mapped_value(row, mappings, field).and_then(map_category))?;
+
+    let mut equipment = Equipment {
+        id: String::new(),
+        manufacturer: String::new(),
+        model: String::new(),
+        sku: String::new(),
+        category,
+        subcategory,
+        description: None,
+        cost: None,
+        msrp: None,
+        weight: None,
+        certifications: Vec::new(),
+        specs: EquipmentSpecs::default(),
+    };
+    apply_row(&mut equipment, row, mappings, result);
+    Some(equipment)
+}
+
+/// Write validated rows to the catalog in a single transaction
+///
+/// Rows are paired with their validation result by row number. Valid rows
+/// matched to an existing record update it; other valid rows are inserted.
+/// Invalid, incomplete and unvalidated rows are skipped. Any write failure
+/// rolls back the whole import.
+pub fn commit_rows(
+    conn: &mut Connection,
+    rows: &[ParsedRow],
+    mappings: &[ColumnMapping],
+    results: &[ValidationResult],
+) -> Result<ImportSummary, ImportError> {
+    let results_by_row: HashMap<usize, &ValidationResult> =
+        results.iter().map(|r| (r.row_number, r)).collect();
+    let mut summary = ImportSummary::default();
+
+    let tx = conn.transaction().map_err(write_error)?;
+    for row in rows {
+        let Some(result) = results_by_row
+            .get(&row.row_number)
+            .filter(|r| r.status == ValidationStatus::Valid)
+        else {
+            summary.skipped += 1;
+            continue;
+        };
+
+        match (result.match_type, result.existing_equipment_id.as_deref()) {
+            (Some(MatchType::UpdateSku | MatchType::UpdateFallback), Some(id)) => {
+                let mut equipment = get_equipment_by_id(&tx, id).map_err(write_error)?;
+                apply_row(&mut equipment, row, mappings, result);
+                update_equipment_record(&tx, &equipment).map_err(write_error)?;
+                summary.updated += 1;
+            }
+            _ => match new_equipment(row, mappings, result) {
+                Some(equipment) => {
+                    insert_equipment(&tx, &equipment).map_err(write_error)?;
+                    summary.inserted += 1;
+                }
+                None => summary.skipped += 1,
+            },
+        }
+    }
+    tx.commit().map_err(write_error)?;
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{list_equipment_records, DatabaseConfig, DatabaseManager};
+    use crate::drawings::EquipmentCategory;
+    use crate::import::parser::ParsedDimensions;
+
+    fn create_test_catalog(dir: &tempfile::TempDir) -> DatabaseManager {
+        let mut manager = DatabaseManager::with_config(DatabaseConfig {
+            path: dir.path().join("catalog.db").to_string_lossy().into_owned(),
+        });
+        manager.connect().unwrap();
+        manager
+    }
+
+    fn create_test_mappings() -> Vec<ColumnMapping> {
+        [
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::Category,
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, field)| ColumnMapping {
+            source_column: i,
+            source_header: field.canonical_name().to_string(),
+            target_field: Some(*field),
+            flag_label: None,
+        })
+        .collect()
+    }
+
+    fn create_test_row(row_number: usize, cells: &[&str]) -> ParsedRow {
+        ParsedRow {
+            row_number,
+            cells: cells.iter().map(|c| c.to_string()).collect(),
+            typed_cells: None,
+        }
+    }
+
+    fn create_test_result(
+        row_number: usize,
+        status: ValidationStatus,
+        existing_equipment_id: Option<&str>,
+    ) -> ValidationResult {
+        ValidationResult {
+            row_number,
+            status,
+            match_type: Some(match existing_equipment_id {
+                Some(_) => MatchType::UpdateSku,
+                None => MatchType::New,
+            }),
+            existing_equipment_id: existing_equipment_id.map(str::to_string),
+            match_score: None,
+            missing_fields: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            parsed_cost: None,
+            parsed_msrp: None,
+            parsed_dimensions_mm: ParsedDimensions::default(),
+        }
+    }
+
+    #[test]
+    fn test_commit_inserts_updates_and_skips() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut catalog = create_test_catalog(&dir);
+        let existing = insert_equipment(
+            catalog.connection().unwrap(),
+            &Equipment {
+                id: "eq-1".to_string(),
+                manufacturer: "Shure".to_string(),
+                model: "MXA920".to_string(),
+                sku: "MXA920W-S".to_string(),
+                category: EquipmentCategory::Audio,
+                subcategory: "microphones".to_string(),
+                description: None,
+                cost: Some(4500.0),
+                msrp: None,
+                weight: None,
+                certifications: Vec::new(),
+                specs: EquipmentSpecs::default(),
+            },
+        )
+        .unwrap();
+
+        let rows = vec![
+            create_test_row(2, &["Shure", "MXA920", "MXA920W-S", "$4,750.00", ""]),
+            create_test_row(
+                3,
+                &["Poly", "Studio X50", "2200-86260-001", "2500", "Video Bar"],
+            ),
+            create_test_row(4, &["Biamp", "Tesira", "TESIRA-1", "TBD", "DSP"]),
+        ];
+        let results = vec![
+            create_test_result(2, ValidationStatus::Valid, Some(&existing.id)),
+            create_test_result(3, ValidationStatus::Valid, None),
+            create_test_result(4, ValidationStatus::Invalid, None),
+        ];
+
+        let summary = commit_rows(
+            catalog.connection_mut().unwrap(),
+            &rows,
+            &create_test_mappings(),
+            &results,
+        )
+        .unwrap();
+
+        assert_eq!(
+            summary,
+            ImportSummary {
+                inserted: 1,
+                updated: 1,
+                skipped: 1,
+            }
+        );
+        let conn = catalog.connection().unwrap();
+        assert_eq!(
+            get_equipment_by_id(conn, "eq-1").unwrap().cost,
+            Some(4750.0)
+        );
+        let records = list_equipment_records(conn, None).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|e| e.manufacturer != "Biamp"));
+        let inserted = records.iter().find(|e| e.model == "Studio X50").unwrap();
+        assert_eq!(inserted.category, EquipmentCategory::Video);
+        assert_eq!(inserted.cost, Some(2500.0));
+    }
+
+    #[test]
+    fn test_commit_normalizes_subcategory() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut catalog = create_test_catalog(&dir);
+        let mut mappings = create_test_mappings();
+        mappings.push(ColumnMapping {
+            source_column: mappings.len(),
+            source_header: "Subcategory".to_string(),
+            target_field: Some(EquipmentField::Subcategory),
+            flag_label: None,
+        });
+
+        let rows = vec![
+            create_test_row(
+                2,
+                &[
+                    "Poly",
+                    "EagleEye IV",
+                    "EE4-12X",
+                    "3000",
+                    "Camera",
+                    "PTZ Cameras",
+                ],
+            ),
+            create_test_row(
+                3,
+                &["QSC", "AC-C6T", "AC-C6T", "300", "Loudspeaker", "Pendant"],
+            ),
+        ];
+        let results = vec![
+            create_test_result(2, ValidationStatus::Valid, None),
+            create_test_result(3, ValidationStatus::Valid, None),
+        ];
+
+        commit_rows(
+            catalog.connection_mut().unwrap(),
+            &rows,
+            &mappings,
+            &results,
+        )
+        .unwrap();
+
+        let records = list_equipment_records(catalog.connection().unwrap(), None).unwrap();
+        let subcategory = |model: &str| {
+            records
+                .iter()
+                .find(|e| e.model == model)
+                .map(|e| e.subcategory.clone())
+                .unwrap()
+        };
+        assert_eq!(subcategory("EagleEye IV"), "cameras");
+        assert_eq!(subcategory("AC-C6T"), "speakers");
+    }
+
+    #[test]
+    fn test_failed_commit_rolls_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut catalog = create_test_catalog(&dir);
+
+        let rows = vec![
+            create_test_row(
+                2,
+                &["Poly", "Studio X50", "2200-86260-001", "2500", "Video Bar"],
+            ),
+            create_test_row(3, &["Shure", "MXA920", "MXA920W-S", "4750", ""]),
+        ];
+        let results = vec![
+            create_test_result(2, ValidationStatus::Valid, None),
+            create_test_result(3, ValidationStatus::Valid, Some("missing-id")),
+        ];
+
+        let result = commit_rows(
+            catalog.connection_mut().unwrap(),
+            &rows,
+            &create_test_mappings(),
+            &results,
+        );
+
+        assert!(matches!(result, Err(ImportError::WriteError(_))));
+        let records = list_equipment_records(catalog.connection().unwrap(), None).unwrap();
+        assert!(records.is_empty());
+    }
+}
@@ -2,23 +2,27 @@
 //!
 //! Parses .csv files using the csv crate.
 
-use super::parser::{FileType, ImportError, ParsedFile, ParsedRow, Parser, MAX_ROWS};
+use super::parser::{
+    split_header_row, FileType, ImportError, ParseOptions, ParsedFile, Parser, SheetRows, TrimMode,
+};
 use csv::ReaderBuilder;
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+use encoding_rs_io::DecodeReaderBytesBuilder;
 use std::fs::File;
+use std::io::{BufReader, Cursor, Read};
 use std::path::Path;
 
 /// CSV file parser
 pub struct CsvParser;
 
 impl Parser for CsvParser {
-    fn parse(path: &Path) -> Result<ParsedFile, ImportError> {
+    fn parse_with_options(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
         let file_name = path
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("unknown.csv")
             .to_string();
 
-        // Open file
         let file = File::open(path).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 ImportError::FileNotFound(path.display().to_string())
@@ -27,63 +31,47 @@ impl Parser for CsvParser {
             }
         })?;
 
-        // Create CSV reader with flexible settings
-        let mut reader = ReaderBuilder::new()
-            .flexible(true) // Allow varying number of fields
-            .trim(csv::Trim::All)
-            .from_reader(file);
+        // Detect the encoding and delimiter from the start of the file
+        let mut prefix = Vec::with_capacity(SNIFF_BYTES);
+        let mut file = BufReader::new(file);
+        (&mut file)
+            .take(SNIFF_BYTES as u64)
+            .read_to_end(&mut prefix)
+            .map_err(|e| ImportError::ReadError(e.to_string()))?;
+        let encoding = detect_encoding(&prefix);
+        let delimiter = sniff_delimiter(&encoding.decode(&prefix).0);
 
-        // Get headers
-        let headers: Vec<String> = reader
-            .headers()
-            .map_err(|e| ImportError::ParseError(e.to_string()))?
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
+        // Stream the whole file once, transcoding to UTF-8 as it is read
+        let decoder = DecodeReaderBytesBuilder::new()
+            .encoding(Some(encoding))
+            .bom_override(true)
+            .strip_bom(true)
+            .build(Cursor::new(prefix).chain(file));
+        let raw_rows = ReaderBuilder::new()
+            .flexible(true) // Allow varying number of fields
+            .has_headers(false)
+            .delimiter(delimiter)
+            .from_reader(decoder)
+            .into_records()
+            // Malformed rows are kept as empty rows so row numbers stay aligned
+            .map(|result| {
+                result
+                    .map(|record| record.iter().map(|s| s.to_string()).collect())
+                    .unwrap_or_default()
+            });
 
-        if headers.is_empty() {
-            return Err(ImportError::EmptyFile);
-        }
+        let SheetRows {
+            headers,
+            rows,
+            data_row_count,
+            ..
+        } = split_header_row(
+            raw_rows,
+            options,
+            options.trim_mode.unwrap_or(TrimMode::All),
+        )?;
 
-        // Count total rows (we need to iterate through to count)
-        let file_for_count = File::open(path).map_err(|e| ImportError::ReadError(e.to_string()))?;
-        let count_reader = ReaderBuilder::new()
-            .flexible(true)
-            .from_reader(file_for_count);
-        let total_rows = count_reader.into_records().count() + 1; // +1 for header
-
-        // Re-open for actual reading
-        let file = File::open(path).map_err(|e| ImportError::ReadError(e.to_string()))?;
-        let mut reader = ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_reader(file);
-
-        // Extract data rows
-        let rows: Vec<ParsedRow> = reader
-            .records()
-            .take(MAX_ROWS)
-            .enumerate()
-            .filter_map(|(idx, result)| {
-                match result {
-                    Ok(record) => {
-                        let cells: Vec<String> = record.iter().map(|s| s.to_string()).collect();
-                        // Skip completely empty rows
-                        if cells.iter().all(|c| c.trim().is_empty()) {
-                            None
-                        } else {
-                            Some(ParsedRow {
-                                row_number: idx + 2, // 1-indexed, skip header
-                                cells,
-                            })
-                        }
-                    }
-                    Err(_) => None, // Skip malformed rows
-                }
-            })
-            .collect();
-
-        if rows.is_empty() {
+        if headers.is_empty() || rows.is_empty() {
             return Err(ImportError::EmptyFile);
         }
 
@@ -92,21 +80,88 @@ impl Parser for CsvParser {
             file_type: FileType::Csv,
             headers,
             rows,
-            total_rows,
-            truncated: total_rows > MAX_ROWS + 1, // +1 for header
+            total_rows: data_row_count + 1, // +1 for header
+            truncated: data_row_count > options.row_limit(),
+            delimiter: Some(delimiter as char),
+            encoding: Some(encoding.name().to_string()),
         })
     }
 }
 
+/// Bytes read from the start of a file to detect its encoding and delimiter
+const SNIFF_BYTES: usize = 64 * 1024;
+
+/// Candidate field delimiters, preferred in this order on ties
+const DELIMITERS: [u8; 3] = [b',', b';', b'\t'];
+
+/// Non-empty lines sampled when detecting the delimiter
+const DELIMITER_SAMPLE_LINES: usize = 5;
+
+/// Detect a file's encoding from its first bytes
+///
+/// A byte order mark decides the encoding when present. Otherwise UTF-8 is
+/// assumed when the bytes are valid UTF-8 (allowing a character cut off at
+/// the end), and anything else is read as Windows-1252, the Latin-1
+/// superset written by spreadsheet tools on Windows.
+fn detect_encoding(prefix: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(prefix) {
+        return encoding;
+    }
+    match std::str::from_utf8(prefix) {
+        Ok(_) => UTF_8,
+        Err(e) if e.error_len().is_none() => UTF_8,
+        Err(_) => WINDOWS_1252,
+    }
+}
+
+/// Count occurrences of a delimiter outside quoted fields
+fn count_unquoted(line: &str, delimiter: u8) -> usize {
+    let mut in_quotes = false;
+    let mut count = 0;
+    for byte in line.bytes() {
+        if byte == b'"' {
+            in_quotes = !in_quotes;
+        } else if byte == delimiter && !in_quotes {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Pick the delimiter used by the first few lines of a file
+///
+/// The delimiter appearing on the most sampled lines wins, then the one
+/// appearing most often, so decimal commas in semicolon-separated files
+/// don't count as separators. Falls back to a comma.
+fn sniff_delimiter(text: &str) -> u8 {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(DELIMITER_SAMPLE_LINES)
+        .collect();
+
+    let mut best = (DELIMITERS[0], 0, 0);
+    for delimiter in DELIMITERS {
+        let counts: Vec<usize> = lines.iter().map(|l| count_unquoted(l, delimiter)).collect();
+        let lines_with = counts.iter().filter(|&&c| c > 0).count();
+        let total: usize = counts.iter().sum();
+        if (lines_with, total) > (best.1, best.2) {
+            best = (delimiter, lines_with, total);
+        }
+    }
+    best.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::import::parser::PREVIEW_ROWS;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
-    fn create_test_csv(content: &str) -> NamedTempFile {
+    fn create_test_csv(content: impl AsRef<[u8]>) -> NamedTempFile {
         let mut file = NamedTempFile::with_suffix(".csv").unwrap();
-        file.write_all(content.as_bytes()).unwrap();
+        file.write_all(content.as_ref()).unwrap();
         file.flush().unwrap();
         file
     }
@@ -175,4 +230,158 @@ mod tests {
         assert_eq!(parsed.rows.len(), 3);
         assert_eq!(parsed.rows[1].cells.len(), 2); // Row with fewer columns
     }
+
+    #[test]
+    fn test_parse_csv_trim_none_preserves_whitespace() {
+        let content = "Manufacturer,SKU\nPoly,  AB 12 \n";
+        let file = create_test_csv(content);
+        let options = ParseOptions {
+            trim_mode: Some(TrimMode::None),
+            ..Default::default()
+        };
+
+        let parsed = CsvParser::parse_with_options(file.path(), &options).unwrap();
+        assert_eq!(parsed.rows[0].cells[1], "  AB 12 ");
+    }
+
+    #[test]
+    fn test_parse_csv_trim_all_by_default() {
+        let content = "Manufacturer,SKU\nPoly,  AB 12 \n";
+        let file = create_test_csv(content);
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.rows[0].cells[1], "AB 12");
+    }
+
+    #[test]
+    fn test_parse_csv_trim_headers_only() {
+        let content = " Manufacturer , SKU\nPoly,  AB 12\n";
+        let file = create_test_csv(content);
+        let options = ParseOptions {
+            trim_mode: Some(TrimMode::Headers),
+            ..Default::default()
+        };
+
+        let parsed = CsvParser::parse_with_options(file.path(), &options).unwrap();
+        assert_eq!(parsed.headers[0], "Manufacturer");
+        assert_eq!(parsed.rows[0].cells[1], "  AB 12");
+    }
+
+    #[test]
+    fn test_parse_csv_detects_header_below_title() {
+        let content = "Poly Dealer Price List,,
+,,
+Manufacturer,Model,Cost
+Poly,Studio X50,2500.00
+";
+        let file = create_test_csv(content);
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.headers, vec!["Manufacturer", "Model", "Cost"]);
+        assert_eq!(parsed.rows.len(), 1);
+        assert_eq!(parsed.rows[0].row_number, 4);
+        assert_eq!(parsed.total_rows, 2);
+    }
+
+    #[test]
+    fn test_parse_csv_header_row_override() {
+        let content = "Manufacturer,Model
+Vendor,Part
+Poly,Studio X50
+";
+        let file = create_test_csv(content);
+        let options = ParseOptions {
+            header_row_index: Some(1),
+            ..Default::default()
+        };
+
+        let parsed = CsvParser::parse_with_options(file.path(), &options).unwrap();
+        assert_eq!(parsed.headers, vec!["Vendor", "Part"]);
+        assert_eq!(parsed.rows[0].row_number, 3);
+    }
+
+    #[test]
+    fn test_parse_semicolon_delimited_csv() {
+        let content = "Hersteller;Modell;Preis\nSennheiser;EW-D SK;1.299,00\nShure;SM58;99,00\n";
+        let file = create_test_csv(content);
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.delimiter, Some(';'));
+        assert_eq!(parsed.headers, vec!["Hersteller", "Modell", "Preis"]);
+        assert_eq!(
+            parsed.rows[0].cells,
+            vec!["Sennheiser", "EW-D SK", "1.299,00"]
+        );
+    }
+
+    #[test]
+    fn test_parse_tab_delimited_csv() {
+        let file = create_test_csv("Manufacturer\tModel\nPoly\tStudio X50\n");
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.delimiter, Some('\t'));
+        assert_eq!(parsed.rows[0].cells, vec!["Poly", "Studio X50"]);
+    }
+
+    #[test]
+    fn test_parse_latin1_csv() {
+        // "Müller" and "Société" encoded as Latin-1
+        let content = b"Manufacturer,Model\nM\xfcller,MX-1\nSoci\xe9t\xe9 Audio,SA-2\n";
+        let file = create_test_csv(content);
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.encoding.as_deref(), Some("windows-1252"));
+        assert_eq!(parsed.rows[0].cells[0], "Müller");
+        assert_eq!(parsed.rows[1].cells[0], "Société Audio");
+    }
+
+    #[test]
+    fn test_parse_utf8_csv_reports_encoding() {
+        let file = create_test_csv("\u{feff}Manufacturer,Model\nMüller,MX-1\n");
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.encoding.as_deref(), Some("UTF-8"));
+        assert_eq!(parsed.delimiter, Some(','));
+        assert_eq!(parsed.headers[0], "Manufacturer");
+        assert_eq!(parsed.rows[0].cells[0], "Müller");
+    }
+
+    #[test]
+    fn test_parse_csv_preview_keeps_first_rows_and_counts_all() {
+        let mut content = String::from("Manufacturer,Model,Cost\n");
+        for i in 0..PREVIEW_ROWS + 50 {
+            content.push_str(&format!("Poly,Model {},{}.00\n", i, i));
+        }
+        let file = create_test_csv(content);
+        let options = ParseOptions {
+            preview: true,
+            ..Default::default()
+        };
+
+        let preview = CsvParser::parse_with_options(file.path(), &options).unwrap();
+        assert_eq!(preview.rows.len(), PREVIEW_ROWS);
+        assert_eq!(preview.total_rows, PREVIEW_ROWS + 51);
+        assert!(preview.truncated);
+
+        let full = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(full.rows.len(), PREVIEW_ROWS + 50);
+        assert!(!full.truncated);
+    }
+
+    #[test]
+    fn test_parse_csv_larger_than_sniff_window() {
+        let mut content = String::from("Manufacturer;Model\n");
+        while content.len() <= SNIFF_BYTES {
+            content.push_str("Müller;MX-1\n");
+        }
+        let file = create_test_csv(&content);
+
+        let parsed = CsvParser::parse(file.path()).unwrap();
+        assert_eq!(parsed.delimiter, Some(';'));
+        assert_eq!(parsed.total_rows, content.lines().count());
+        assert!(parsed
+            .rows
+            .iter()
+            .all(|r| r.cells == vec!["Müller", "MX-1"]));
+    }
 }
@@ -1,106 +1,170 @@
 //! Excel Parser
 //!
-//! Parses .xlsx and .xls files using the calamine crate.
+//! Parses .xlsx, .xls and .ods files using the calamine crate.
 
-use super::parser::{FileType, ImportError, ParsedFile, ParsedRow, Parser, MAX_ROWS};
-use calamine::{open_workbook_auto, Data, Reader};
+use super::parser::{
+    split_header_row, CellValue, FileType, ImportError, ParseOptions, ParsedFile, Parser,
+    SheetRows, SheetSelection, TrimMode,
+};
+use calamine::{open_workbook_auto, Data, Reader, Sheets};
+use chrono::{NaiveDateTime, Timelike};
+use std::fs::File;
+use std::io::BufReader;
 use std::path::Path;
 
-/// Excel file parser
+/// Spreadsheet parser for Excel and OpenDocument workbooks
 pub struct ExcelParser;
 
+impl ExcelParser {
+    /// List the worksheet names of a workbook, in workbook order
+    pub fn list_sheets(path: &Path) -> Result<Vec<String>, ImportError> {
+        Ok(open_workbook(path)?.sheet_names().to_vec())
+    }
+}
+
 impl Parser for ExcelParser {
-    fn parse(path: &Path) -> Result<ParsedFile, ImportError> {
+    fn parse_with_options(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
         let file_name = path
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("unknown.xlsx")
             .to_string();
 
-        // Open workbook
-        let mut workbook = open_workbook_auto(path).map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("password") || msg.contains("encrypted") {
-                ImportError::PasswordProtected
-            } else if msg.contains("not found") || msg.contains("No such file") {
-                ImportError::FileNotFound(path.display().to_string())
-            } else {
-                ImportError::ReadError(msg)
-            }
-        })?;
+        let mut workbook = open_workbook(path)?;
 
-        // Get first sheet
+        // Get the selected sheet, defaulting to the first
         let sheet_names = workbook.sheet_names().to_vec();
         if sheet_names.is_empty() {
             return Err(ImportError::EmptyFile);
         }
 
-        let first_sheet = &sheet_names[0];
+        let sheet_name = select_sheet(&sheet_names, options.sheet.as_ref())?;
         let range = workbook
-            .worksheet_range(first_sheet)
+            .worksheet_range(sheet_name)
             .map_err(|e| ImportError::ParseError(e.to_string()))?;
 
         if range.is_empty() {
             return Err(ImportError::EmptyFile);
         }
 
-        let total_rows = range.height();
-
-        // Extract headers from first row
-        let headers: Vec<String> = range
+        // Only convert the rows that can be kept; the sheet height gives the total
+        let raw_rows = range
             .rows()
-            .next()
-            .map(|row| row.iter().map(cell_to_string).collect())
-            .unwrap_or_default();
+            .take(options.header_scan_rows() + options.row_limit())
+            .map(|row| row.iter().map(cell_to_string).collect());
+
+        let SheetRows {
+            header_index,
+            headers,
+            mut rows,
+            ..
+        } = split_header_row(
+            raw_rows,
+            options,
+            options.trim_mode.unwrap_or(TrimMode::None),
+        )?;
+        let total_rows = range.height() - header_index;
 
         if headers.is_empty() {
             return Err(ImportError::EmptyFile);
         }
 
-        // Extract data rows (skip header)
-        let rows: Vec<ParsedRow> = range
-            .rows()
-            .skip(1)
-            .take(MAX_ROWS)
-            .enumerate()
-            .filter_map(|(idx, row)| {
-                let cells: Vec<String> = row.iter().map(cell_to_string).collect();
-                // Skip completely empty rows
-                if cells.iter().all(|c| c.trim().is_empty()) {
-                    None
-                } else {
-                    Some(ParsedRow {
-                        row_number: idx + 2, // 1-indexed, skip header
-                        cells,
-                    })
-                }
-            })
-            .collect();
-
         if rows.is_empty() {
             return Err(ImportError::EmptyFile);
         }
 
+        // Keep each cell's workbook type next to its display string
+        for row in &mut rows {
+            let typed_cells = row
+                .cells
+                .iter()
+                .enumerate()
+                .map(|(col, display)| {
+                    range
+                        .get((row.row_number - 1, col))
+                        .map_or(CellValue::Empty, |cell| cell_to_value(cell, display))
+                })
+                .collect();
+            row.typed_cells = Some(typed_cells);
+        }
+
         Ok(ParsedFile {
             file_name,
-            file_type: FileType::Xlsx,
+            file_type: workbook_file_type(path),
             headers,
             rows,
             total_rows,
-            truncated: total_rows > MAX_ROWS + 1, // +1 for header
+            truncated: total_rows > options.row_limit() + 1, // +1 for header
+            delimiter: None,
+            encoding: None,
         })
     }
 }
 
+/// File type reported for a workbook, from its extension
+fn workbook_file_type(path: &Path) -> FileType {
+    let is_ods = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("ods"));
+    if is_ods {
+        FileType::Ods
+    } else {
+        FileType::Xlsx
+    }
+}
+
+/// Open a workbook, mapping failures onto import errors
+fn open_workbook(path: &Path) -> Result<Sheets<BufReader<File>>, ImportError> {
+    open_workbook_auto(path).map_err(|e| {
+        let msg = e.to_string();
+        if msg.contains("password") || msg.contains("encrypted") {
+            ImportError::PasswordProtected
+        } else if msg.contains("not found") || msg.contains("No such file") {
+            ImportError::FileNotFound(path.display().to_string())
+        } else {
+            ImportError::ReadError(msg)
+        }
+    })
+}
+
+/// Resolve a sheet selection against the workbook's sheet names
+fn select_sheet<'a>(
+    sheet_names: &'a [String],
+    selection: Option<&SheetSelection>,
+) -> Result<&'a String, ImportError> {
+    match selection {
+        None => Ok(&sheet_names[0]),
+        Some(SheetSelection::Index(index)) => sheet_names.get(*index).ok_or_else(|| {
+            ImportError::ParseError(format!(
+                "Sheet index {} is out of range (workbook has {} sheets)",
+                index,
+                sheet_names.len()
+            ))
+        }),
+        Some(SheetSelection::Name(name)) => {
+            sheet_names.iter().find(|s| *s == name).ok_or_else(|| {
+                ImportError::ParseError(format!(
+                    "Sheet '{}' not found (available: {})",
+                    name,
+                    sheet_names.join(", ")
+                ))
+            })
+        }
+    }
+}
+
 /// Convert a cell to string representation
 fn cell_to_string(cell: &Data) -> String {
     match cell {
         Data::Empty => String::new(),
         Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.clone(),
         Data::Float(f) => {
-            // Format floats nicely (avoid scientific notation for prices)
+            // Whole numbers such as UPCs and numeric SKUs print the shortest
+            // digits that round-trip, which are the digits the file stored;
+            // `{:.0}` would expand the binary value of very large ones
             if f.fract() == 0.0 {
-                format!("{:.0}", f)
+                f.to_string()
             } else {
                 format!("{:.2}", f)
             }
@@ -112,6 +176,35 @@ fn cell_to_string(cell: &Data) -> String {
     }
 }
 
+/// Convert a cell to its typed value, reusing its display string for text
+///
+/// Date cells become ISO 8601 dates, with the time only when it is not
+/// midnight. Durations are kept as their number of days.
+fn cell_to_value(cell: &Data, display: &str) -> CellValue {
+    match cell {
+        Data::Empty => CellValue::Empty,
+        Data::Float(f) => CellValue::Number(*f),
+        Data::Int(i) => CellValue::Number(*i as f64),
+        Data::Bool(b) => CellValue::Bool(*b),
+        Data::DateTime(dt) => match dt.as_datetime() {
+            Some(datetime) if dt.is_datetime() => CellValue::Date(iso_date(datetime)),
+            _ => CellValue::Number(dt.as_f64()),
+        },
+        Data::DateTimeIso(s) => CellValue::Date(s.clone()),
+        Data::String(_) | Data::DurationIso(_) | Data::Error(_) => {
+            CellValue::String(display.to_string())
+        }
+    }
+}
+
+fn iso_date(datetime: NaiveDateTime) -> String {
+    if datetime.num_seconds_from_midnight() == 0 {
+        datetime.format("%Y-%m-%d").to_string()
+    } else {
+        datetime.format("%Y-%m-%dT%H:%M:%S").to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +235,19 @@ mod tests {
         assert_eq!(cell_to_string(&Data::Float(99.99)), "99.99");
     }
 
+    #[test]
+    fn test_cell_to_string_float_large_whole() {
+        assert_eq!(
+            cell_to_string(&Data::Float(4006381333931.0)),
+            "4006381333931"
+        );
+        assert_eq!(
+            cell_to_string(&Data::Float(12345678901234567890.0)),
+            "12345678901234567000"
+        );
+        assert_eq!(cell_to_string(&Data::Float(1e21)), "1000000000000000000000");
+    }
+
     #[test]
     fn test_cell_to_string_bool() {
         assert_eq!(cell_to_string(&Data::Bool(true)), "true");
@@ -152,4 +258,243 @@ mod tests {
         let result = ExcelParser::parse(Path::new("/nonexistent/file.xlsx"));
         assert!(matches!(result, Err(ImportError::FileNotFound(_)) | Err(ImportError::ReadError(_))));
     }
+
+    /// Write a workbook with a cover sheet followed by a price list sheet
+    fn create_test_workbook() -> tempfile::TempPath {
+        let path = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let cover = workbook.add_worksheet().set_name("Cover").unwrap();
+        cover.write_string(0, 0, "Acme AV Price List").unwrap();
+        cover.write_string(1, 0, "Effective 2026").unwrap();
+        let prices = workbook.add_worksheet().set_name("Price List").unwrap();
+        prices.write_string(0, 0, "Model").unwrap();
+        prices.write_string(0, 1, "Cost").unwrap();
+        prices.write_string(1, 0, "Studio X50").unwrap();
+        prices.write_number(1, 1, 2500.0).unwrap();
+        workbook.save(&path).unwrap();
+
+        path
+    }
+
+    fn parse_sheet(path: &Path, sheet: Option<SheetSelection>) -> Result<ParsedFile, ImportError> {
+        let options = ParseOptions {
+            sheet,
+            ..Default::default()
+        };
+        ExcelParser::parse_with_options(path, &options)
+    }
+
+    #[test]
+    fn test_list_sheets() {
+        let path = create_test_workbook();
+        let sheets = ExcelParser::list_sheets(&path).unwrap();
+        assert_eq!(sheets, vec!["Cover", "Price List"]);
+    }
+
+    #[test]
+    fn test_parse_defaults_to_first_sheet() {
+        let path = create_test_workbook();
+        let parsed = parse_sheet(&path, None).unwrap();
+        assert_eq!(parsed.headers, vec!["Acme AV Price List"]);
+    }
+
+    #[test]
+    fn test_parse_selected_sheet() {
+        let path = create_test_workbook();
+
+        let by_name =
+            parse_sheet(&path, Some(SheetSelection::Name("Price List".to_string()))).unwrap();
+        let by_index = parse_sheet(&path, Some(SheetSelection::Index(1))).unwrap();
+
+        for parsed in [by_name, by_index] {
+            assert_eq!(parsed.headers, vec!["Model", "Cost"]);
+            assert_eq!(parsed.rows[0].cells, vec!["Studio X50", "2500"]);
+        }
+    }
+
+    #[test]
+    fn test_parse_missing_sheet() {
+        let path = create_test_workbook();
+
+        let result = parse_sheet(&path, Some(SheetSelection::Name("Pricing".to_string())));
+
+        match result {
+            Err(ImportError::ParseError(msg)) => {
+                assert!(msg.contains("Pricing"));
+                assert!(msg.contains("Price List"));
+            }
+            _ => panic!("Expected ParseError"),
+        }
+    }
+
+    /// LibreOffice price list with the same cells as `create_test_price_list`
+    fn fixture_ods() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/price_list.ods")
+    }
+
+    fn create_test_price_list() -> tempfile::TempPath {
+        let path = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let sheet = workbook.add_worksheet().set_name("Price List").unwrap();
+        for (col, header) in ["Manufacturer", "Model", "Cost"].iter().enumerate() {
+            sheet.write_string(0, col as u16, *header).unwrap();
+        }
+        let rows = [
+            ("Poly", "Studio X50", 2499.0),
+            ("Logitech", "Rally Bar", 3999.99),
+        ];
+        for (row, (manufacturer, model, cost)) in rows.iter().enumerate() {
+            let row = row as u32 + 1;
+            sheet.write_string(row, 0, *manufacturer).unwrap();
+            sheet.write_string(row, 1, *model).unwrap();
+            sheet.write_number(row, 2, *cost).unwrap();
+        }
+        workbook.save(&path).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn test_parse_ods_matches_xlsx() {
+        let xlsx_path = create_test_price_list();
+
+        let ods = ExcelParser::parse(&fixture_ods()).unwrap();
+        let xlsx = ExcelParser::parse(&xlsx_path).unwrap();
+
+        assert_eq!(ods.file_type, FileType::Ods);
+        assert_eq!(xlsx.file_type, FileType::Xlsx);
+        assert_eq!(ods.headers, vec!["Manufacturer", "Model", "Cost"]);
+        assert_eq!(ods.headers, xlsx.headers);
+        assert_eq!(ods.total_rows, xlsx.total_rows);
+        let cells = |parsed: &ParsedFile| -> Vec<Vec<String>> {
+            parsed.rows.iter().map(|r| r.cells.clone()).collect()
+        };
+        assert_eq!(cells(&ods), cells(&xlsx));
+        assert_eq!(ods.rows[1].cells, vec!["Logitech", "Rally Bar", "3999.99"]);
+    }
+
+    #[test]
+    fn test_list_sheets_ods() {
+        let sheets = ExcelParser::list_sheets(&fixture_ods()).unwrap();
+        assert_eq!(sheets, vec!["Price List"]);
+    }
+
+    #[test]
+    fn test_parse_keeps_cell_types() {
+        let path = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let sheet = workbook.add_worksheet();
+        for (col, header) in ["Model", "Cost", "Discontinued", "Last Updated"]
+            .iter()
+            .enumerate()
+        {
+            sheet.write_string(0, col as u16, *header).unwrap();
+        }
+        let date_format = rust_xlsxwriter::Format::new().set_num_format("yyyy-mm-dd");
+        let updated = rust_xlsxwriter::ExcelDateTime::from_ymd(2026, 3, 14).unwrap();
+        sheet.write_string(1, 0, "Studio X50").unwrap();
+        sheet.write_number(1, 1, 1234.5678).unwrap();
+        sheet.write_boolean(1, 2, false).unwrap();
+        sheet
+            .write_datetime_with_format(1, 3, &updated, &date_format)
+            .unwrap();
+        workbook.save(&path).unwrap();
+
+        let parsed = ExcelParser::parse(&path).unwrap();
+
+        let row = &parsed.rows[0];
+        assert_eq!(row.cells[1], "1234.57");
+        assert_eq!(
+            row.typed_cells,
+            Some(vec![
+                CellValue::String("Studio X50".to_string()),
+                CellValue::Number(1234.5678),
+                CellValue::Bool(false),
+                CellValue::Date("2026-03-14".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_cell_to_value_datetime_with_time() {
+        let datetime = NaiveDateTime::parse_from_str("2026-03-14 09:30:00", "%Y-%m-%d %H:%M:%S");
+        assert_eq!(iso_date(datetime.unwrap()), "2026-03-14T09:30:00");
+    }
+
+    #[test]
+    fn test_parse_numeric_skus_exact() {
+        let path = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.write_string(0, 0, "Model").unwrap();
+        sheet.write_string(0, 1, "SKU").unwrap();
+        let skus = [
+            ("UPC", Some(4006381333931.0), None),
+            ("Long", Some(12345678901234567890.0), None),
+            ("Text", None, Some("00012345678905")),
+        ];
+        for (row, (model, number, text)) in skus.iter().enumerate() {
+            let row = row as u32 + 1;
+            sheet.write_string(row, 0, *model).unwrap();
+            if let Some(number) = number {
+                sheet.write_number(row, 1, *number).unwrap();
+            }
+            if let Some(text) = text {
+                sheet.write_string(row, 1, *text).unwrap();
+            }
+        }
+        workbook.save(&path).unwrap();
+
+        let parsed = ExcelParser::parse(&path).unwrap();
+
+        let skus: Vec<&str> = parsed.rows.iter().map(|r| r.cells[1].as_str()).collect();
+        assert_eq!(
+            skus,
+            vec!["4006381333931", "12345678901234567000", "00012345678905"]
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_cell_whitespace_by_default() {
+        let path = tempfile::Builder::new()
+            .suffix(".xlsx")
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.write_string(0, 0, "Manufacturer").unwrap();
+        sheet.write_string(0, 1, "SKU").unwrap();
+        sheet.write_string(1, 0, "Poly").unwrap();
+        sheet.write_string(1, 1, "  AB 12 ").unwrap();
+        workbook.save(&path).unwrap();
+
+        let parsed = ExcelParser::parse(&path).unwrap();
+        assert_eq!(parsed.rows[0].cells[1], "  AB 12 ");
+
+        let options = ParseOptions {
+            trim_mode: Some(TrimMode::All),
+            ..Default::default()
+        };
+        let parsed = ExcelParser::parse_with_options(&path, &options).unwrap();
+        assert_eq!(parsed.rows[0].cells[1], "AB 12");
+    }
 }
@@ -0,0 +1,193 @@
+//! Equipment Image Validation
+//!
+//! Checks the pixel dimensions of a downloaded equipment image before it is
+//! cached, so tiny vendor icons and oversized photos are rejected up front.
+
+use super::parser::ImportError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Accepted pixel dimension range for equipment images
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageLimits {
+    pub min_width: u32,
+    pub min_height: u32,
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+impl Default for ImageLimits {
+    fn default() -> Self {
+        Self {
+            min_width: 64,
+            min_height: 64,
+            max_width: 4096,
+            max_height: 4096,
+        }
+    }
+}
+
+/// Pixel dimensions read from an image header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDimensions {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Read the dimensions of a PNG image from its header
+fn png_dimensions(bytes: &[u8]) -> Result<ImageDimensions, ImportError> {
+    let reader = png::Decoder::new(bytes)
+        .read_info()
+        .map_err(|e| ImportError::ParseError(format!("Invalid PNG image: {}", e)))?;
+    let info = reader.info();
+    Ok(ImageDimensions {
+        width: info.width,
+        height: info.height,
+    })
+}
+
+/// Read the dimensions of a JPEG image from its start-of-frame segment
+fn jpeg_dimensions(bytes: &[u8]) -> Result<ImageDimensions, ImportError> {
+    let invalid = || ImportError::ParseError("Invalid JPEG image: no frame header".to_string());
+    let read_u16 = |at: usize| -> Option<u16> {
+        Some(u16::from_be_bytes([*bytes.get(at)?, *bytes.get(at + 1)?]))
+    };
+
+    // Skip the SOI marker, then walk segments until a start-of-frame
+    let mut pos = 2;
+    while pos + 1 < bytes.len() {
+        if bytes[pos] != 0xFF {
+            return Err(invalid());
+        }
+        let marker = bytes[pos + 1];
+        match marker {
+            // Fill bytes before a marker
+            0xFF => pos += 1,
+            // Standalone markers without a length
+            0x01 | 0xD0..=0xD7 => pos += 2,
+            // SOF0-SOF15, excluding DHT (C4), JPG (C8) and DAC (CC)
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let height = read_u16(pos + 5).ok_or_else(invalid)?;
+                let width = read_u16(pos + 7).ok_or_else(invalid)?;
+                return Ok(ImageDimensions {
+                    width: width.into(),
+                    height: height.into(),
+                });
+            }
+            _ => {
+                let length = read_u16(pos + 2).ok_or_else(invalid)?;
+                pos += 2 + length as usize;
+            }
+        }
+    }
+
+    Err(invalid())
+}
+
+/// Read image dimensions, detecting the format from the file signature
+pub fn image_dimensions(bytes: &[u8]) -> Result<ImageDimensions, ImportError> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        png_dimensions(bytes)
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg_dimensions(bytes)
+    } else {
+        Err(ImportError::UnsupportedFormat(
+            "Unsupported image format (expected PNG or JPEG)".to_string(),
+        ))
+    }
+}
+
+/// Check that the image at `path` is within the given pixel limits
+///
+/// Returns the image's dimensions when accepted.
+pub fn validate_image_dimensions(
+    path: &Path,
+    limits: &ImageLimits,
+) -> Result<ImageDimensions, ImportError> {
+    if !path.exists() {
+        return Err(ImportError::FileNotFound(path.display().to_string()));
+    }
+    let bytes = std::fs::read(path).map_err(|e| ImportError::ReadError(e.to_string()))?;
+    let dimensions = image_dimensions(&bytes)?;
+
+    if dimensions.width < limits.min_width || dimensions.height < limits.min_height {
+        return Err(ImportError::ValidationError(format!(
+            "Image is {}x{} px, smaller than the minimum {}x{} px",
+            dimensions.width, dimensions.height, limits.min_width, limits.min_height
+        )));
+    }
+    if dimensions.width > limits.max_width || dimensions.height > limits.max_height {
+        return Err(ImportError::ValidationError(format!(
+            "Image is {}x{} px, larger than the maximum {}x{} px",
+            dimensions.width, dimensions.height, limits.max_width, limits.max_height
+        )));
+    }
+
+    Ok(dimensions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufWriter;
+    use tempfile::TempDir;
+
+    fn write_png_fixture(dir: &TempDir, width: u32, height: u32) -> std::path::PathBuf {
+        let path = dir.path().join(format!("{}x{}.png", width, height));
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(File::create(&path).unwrap()), width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer
+            .write_image_data(&vec![255; (width * height * 3) as usize])
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_too_small_image_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_png_fixture(&dir, 16, 16);
+
+        let result = validate_image_dimensions(&path, &ImageLimits::default());
+
+        match result {
+            Err(ImportError::ValidationError(msg)) => assert!(msg.contains("16x16")),
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_normal_image_accepted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_png_fixture(&dir, 320, 240);
+
+        let dimensions = validate_image_dimensions(&path, &ImageLimits::default()).unwrap();
+
+        assert_eq!(
+            dimensions,
+            ImageDimensions {
+                width: 320,
+                height: 240
+            }
+        );
+    }
+
+    #[test]
+    fn test_jpeg_dimensions_from_frame_header() {
+        // SOI, an empty APP0 segment, then a baseline SOF0 for 640x480
+        let bytes = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x02, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01, 0xE0, 0x02,
+            0x80, 0x03,
+        ];
+
+        let dimensions = image_dimensions(&bytes).unwrap();
+
+        assert_eq!(dimensions.width, 640);
+        assert_eq!(dimensions.height, 480);
+    }
+}
@@ -0,0 +1,472 @@
+//! Import Impact
+//!
+//! Previews what committing an import would do to the existing catalog:
+//! which rows add new equipment, which update an existing record (and which
+//! fields change), and which are already identical.
+
+use super::parser::{
+    mapped_value, parse_currency, ColumnMapping, EquipmentField, MatchType, ParsedRow,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// An equipment record already in the catalog, keyed by mappable field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExistingEquipment {
+    pub id: String,
+    pub values: HashMap<EquipmentField, String>,
+}
+
+/// What committing a row would do to the catalog
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImpactKind {
+    New,
+    Update,
+    Unchanged,
+}
+
+/// A single field that differs between an imported row and its existing record
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: EquipmentField,
+    /// Current catalog value, if the record has one
+    pub old_value: Option<String>,
+    pub new_value: String,
+}
+
+/// Impact of importing a single row
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RowImpact {
+    /// Row number from source
+    pub row_number: usize,
+    pub kind: ImpactKind,
+    /// How the existing record was matched
+    pub match_type: MatchType,
+    /// ID of the matched existing record
+    pub existing_equipment_id: Option<String>,
+    /// Field-level differences, empty unless the row is an update
+    pub changes: Vec<FieldChange>,
+}
+
+// ============================================================================
+// Matching
+// ============================================================================
+
+/// Compare two optional values, ignoring case and surrounding whitespace
+fn same_key(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a.map(str::trim), b.map(str::trim)) {
+        (Some(a), Some(b)) => !a.is_empty() && a.eq_ignore_ascii_case(b),
+        _ => false,
+    }
+}
+
+/// Get a catalog record's value for a field, if any
+fn record_value(record: &ExistingEquipment, field: EquipmentField) -> Option<&str> {
+    record.values.get(&field).map(String::as_str)
+}
+
+/// Similarity a fuzzy manufacturer and model match needs by default
+pub const DEFAULT_MATCH_THRESHOLD: f64 = 0.85;
+
+/// Lowercase alphanumeric form of a value, ignoring spacing and punctuation
+fn normalize_key(value: &str) -> Vec<char> {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Edit-distance similarity of two values, from 0 (unrelated) to 1 (equal)
+fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize_key(a), normalize_key(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Levenshtein distance, keeping one row of the table
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    1.0 - distances[b.len()] as f64 / a.len().max(b.len()) as f64
+}
+
+/// Runs of consecutive digits in a value, e.g. `["350"]` for "DM-NVX-350"
+fn digit_runs(value: &str) -> Vec<String> {
+    value
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|run| !run.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Similarity of two model names, zero unless their numbers match exactly
+///
+/// Model names in a product line often differ by a single digit, such as
+/// "Studio X30" and "Studio X50", which edit distance alone scores as close.
+fn model_similarity(a: &str, b: &str) -> f64 {
+    if digit_runs(a) != digit_runs(b) {
+        return 0.0;
+    }
+    similarity(a, b)
+}
+
+/// Find the best catalog candidate for a row, with its similarity score
+///
+/// An exact SKU match scores 1. Otherwise each record scores the product of
+/// its manufacturer and model similarity to the row, and the highest wins.
+/// Models whose digit runs differ never match.
+pub(crate) fn best_match<'a>(
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    existing: &'a [ExistingEquipment],
+) -> Option<(MatchType, &'a ExistingEquipment, f64)> {
+    let row_value = |field| mapped_value(row, mappings, field);
+
+    if let Some(record) = existing.iter().find(|record| {
+        same_key(
+            row_value(EquipmentField::Sku),
+            record_value(record, EquipmentField::Sku),
+        )
+    }) {
+        return Some((MatchType::UpdateSku, record, 1.0));
+    }
+
+    let manufacturer = row_value(EquipmentField::Manufacturer)?;
+    let model = row_value(EquipmentField::Model)?;
+    existing
+        .iter()
+        .map(|record| {
+            let field_value = |field| record_value(record, field).unwrap_or_default();
+            let score = similarity(manufacturer, field_value(EquipmentField::Manufacturer))
+                * model_similarity(model, field_value(EquipmentField::Model));
+            (MatchType::UpdateFallback, record, score)
+        })
+        .filter(|(_, _, score)| *score > 0.0)
+        .reduce(|best, candidate| {
+            if candidate.2 > best.2 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+/// Find the existing record a row updates
+///
+/// Matches by SKU first, falling back to manufacturer and model together
+/// when their similarity reaches `match_threshold`, the same rule
+/// validation applies before commit.
+fn match_existing<'a>(
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    existing: &'a [ExistingEquipment],
+    match_threshold: f64,
+) -> Option<(MatchType, &'a ExistingEquipment)> {
+    best_match(row, mappings, existing)
+        .filter(|(_, _, score)| *score >= match_threshold)
+        .map(|(match_type, record, _)| (match_type, record))
+}
+
+// ============================================================================
+// Diffing
+// ============================================================================
+
+/// Whether a field holds a number, compared by value rather than by text
+fn is_numeric_field(field: EquipmentField) -> bool {
+    matches!(
+        field,
+        EquipmentField::Cost
+            | EquipmentField::Msrp
+            | EquipmentField::Height
+            | EquipmentField::Width
+            | EquipmentField::Depth
+            | EquipmentField::Weight
+            | EquipmentField::Wattage
+    )
+}
+
+/// Compare an imported value against the catalog value for a field
+///
+/// Numeric fields compare by value, so "$1,299.00" equals "1299".
+fn values_equal(field: EquipmentField, new_value: &str, old_value: &str) -> bool {
+    if is_numeric_field(field) {
+        if let (Some(new), Some(old)) = (parse_currency(new_value), parse_currency(old_value)) {
+            return (new - old).abs() < 1e-9;
+        }
+    }
+    new_value.trim() == old_value.trim()
+}
+
+/// List the mapped fields whose imported value differs from the record
+///
+/// Blank cells leave the existing value untouched and are not reported.
+fn diff_fields(
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    record: &ExistingEquipment,
+) -> Vec<FieldChange> {
+    EquipmentField::ALL
+        .iter()
+        .filter_map(|&field| {
+            let new_value = mapped_value(row, mappings, field)?.trim();
+            if new_value.is_empty() {
+                return None;
+            }
+            let old_value = record.values.get(&field);
+            if old_value.is_some_and(|old| values_equal(field, new_value, old)) {
+                return None;
+            }
+            Some(FieldChange {
+                field,
+                old_value: old_value.cloned(),
+                new_value: new_value.to_string(),
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Impact Report
+// ============================================================================
+
+/// Classify each row as new, an update with its field changes, or unchanged
+///
+/// Rows are matched with the same `match_threshold` as validation, so the
+/// preview agrees with what committing the import does.
+pub fn import_impact(
+    rows: &[ParsedRow],
+    mappings: &[ColumnMapping],
+    existing: &[ExistingEquipment],
+    match_threshold: f64,
+) -> Vec<RowImpact> {
+    rows.iter()
+        .map(|row| {
+            let Some((match_type, record)) =
+                match_existing(row, mappings, existing, match_threshold)
+            else {
+                return RowImpact {
+                    row_number: row.row_number,
+                    kind: ImpactKind::New,
+                    match_type: MatchType::New,
+                    existing_equipment_id: None,
+                    changes: Vec::new(),
+                };
+            };
+            let changes = diff_fields(row, mappings, record);
+            RowImpact {
+                row_number: row.row_number,
+                kind: if changes.is_empty() {
+                    ImpactKind::Unchanged
+                } else {
+                    ImpactKind::Update
+                },
+                match_type,
+                existing_equipment_id: Some(record.id.clone()),
+                changes,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_mappings() -> Vec<ColumnMapping> {
+        [
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, field)| ColumnMapping {
+            source_column: i,
+            source_header: field.canonical_name().to_string(),
+            target_field: Some(*field),
+            flag_label: None,
+        })
+        .collect()
+    }
+
+    fn create_test_row(row_number: usize, cells: &[&str]) -> ParsedRow {
+        ParsedRow {
+            row_number,
+            cells: cells.iter().map(|c| c.to_string()).collect(),
+            typed_cells: None,
+        }
+    }
+
+    fn create_test_existing(id: &str, cells: &[&str]) -> ExistingEquipment {
+        ExistingEquipment {
+            id: id.to_string(),
+            values: [
+                EquipmentField::Manufacturer,
+                EquipmentField::Model,
+                EquipmentField::Sku,
+                EquipmentField::Cost,
+            ]
+            .into_iter()
+            .zip(cells.iter().map(|c| c.to_string()))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_new_changed_and_identical_rows() {
+        let existing = vec![
+            create_test_existing("eq-1", &["Shure", "MXA920", "MXA920W-S", "4500"]),
+            create_test_existing("eq-2", &["Crestron", "TSW-1070", "TSW-1070-B-S", "1800"]),
+        ];
+        let rows = vec![
+            create_test_row(2, &["Biamp", "Tesira Forte", "TESIRAFORTE-AI", "2500"]),
+            create_test_row(3, &["Shure", "MXA920", "MXA920W-S", "$4,750.00"]),
+            create_test_row(4, &["Crestron", "TSW-1070", "TSW-1070-B-S", "$1,800.00"]),
+        ];
+
+        let impacts = import_impact(
+            &rows,
+            &create_test_mappings(),
+            &existing,
+            DEFAULT_MATCH_THRESHOLD,
+        );
+
+        assert_eq!(impacts[0].kind, ImpactKind::New);
+        assert_eq!(impacts[0].existing_equipment_id, None);
+
+        assert_eq!(impacts[1].kind, ImpactKind::Update);
+        assert_eq!(impacts[1].match_type, MatchType::UpdateSku);
+        assert_eq!(impacts[1].existing_equipment_id.as_deref(), Some("eq-1"));
+        assert_eq!(
+            impacts[1].changes,
+            vec![FieldChange {
+                field: EquipmentField::Cost,
+                old_value: Some("4500".to_string()),
+                new_value: "$4,750.00".to_string(),
+            }]
+        );
+
+        assert_eq!(impacts[2].kind, ImpactKind::Unchanged);
+        assert!(impacts[2].changes.is_empty());
+    }
+
+    #[test]
+    fn test_fallback_match_by_manufacturer_and_model() {
+        let existing = vec![create_test_existing(
+            "eq-1",
+            &["Shure", "MXA920", "", "4500"],
+        )];
+        let rows = vec![create_test_row(
+            2,
+            &["shure", "MXA920", "MXA920W-S", "4500"],
+        )];
+
+        let impacts = import_impact(
+            &rows,
+            &create_test_mappings(),
+            &existing,
+            DEFAULT_MATCH_THRESHOLD,
+        );
+
+        assert_eq!(impacts[0].match_type, MatchType::UpdateFallback);
+        assert_eq!(impacts[0].kind, ImpactKind::Update);
+        assert_eq!(impacts[0].changes.len(), 2);
+    }
+
+    #[test]
+    fn test_near_match_previewed_as_update() {
+        let existing = vec![create_test_existing(
+            "eq-1",
+            &["Poly", "Studio X30", "", "1500"],
+        )];
+        let rows = vec![create_test_row(2, &["Poly", "Stdio X30", "", "1500"])];
+
+        let impacts = import_impact(
+            &rows,
+            &create_test_mappings(),
+            &existing,
+            DEFAULT_MATCH_THRESHOLD,
+        );
+
+        assert_eq!(impacts[0].kind, ImpactKind::Update);
+        assert_eq!(impacts[0].match_type, MatchType::UpdateFallback);
+        assert_eq!(impacts[0].existing_equipment_id.as_deref(), Some("eq-1"));
+
+        let strict = import_impact(&rows, &create_test_mappings(), &existing, 1.0);
+        assert_eq!(strict[0].kind, ImpactKind::New);
+    }
+
+    #[test]
+    fn test_similarity_ignores_case_and_punctuation() {
+        assert_eq!(similarity("TSW-1070", "tsw 1070"), 1.0);
+        assert_eq!(similarity("MXA920", ""), 0.0);
+        assert!((similarity("MXA920", "MXA910") - 5.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_best_match_picks_closest_model() {
+        let existing = vec![
+            create_test_existing("eq-1", &["Shure", "MXA910", "", "3800"]),
+            create_test_existing("eq-2", &["Shure", "MXA920", "", "4500"]),
+        ];
+        let row = create_test_row(2, &["Shure", "MXA-920", "", "4500"]);
+
+        let (match_type, record, score) =
+            best_match(&row, &create_test_mappings(), &existing).unwrap();
+
+        assert_eq!(match_type, MatchType::UpdateFallback);
+        assert_eq!(record.id, "eq-2");
+        assert_eq!(score, 1.0);
+    }
+
+    #[test]
+    fn test_best_match_rejects_near_miss_models() {
+        let existing = vec![
+            create_test_existing("eq-1", &["Poly", "Studio X30", "", "1500"]),
+            create_test_existing("eq-2", &["Crestron", "DM-NVX-350", "", "1200"]),
+        ];
+        let mappings = create_test_mappings();
+
+        for model in ["Studio X50", "DM-NVX-360", "DM-NVX-3500"] {
+            let manufacturer = if model.starts_with("DM") {
+                "Crestron"
+            } else {
+                "Poly"
+            };
+            let row = create_test_row(2, &[manufacturer, model, "", "1500"]);
+            assert!(
+                best_match(&row, &mappings, &existing).is_none(),
+                "{}",
+                model
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_match_tolerates_letter_typos() {
+        let existing = vec![create_test_existing(
+            "eq-1",
+            &["Poly", "Studio X30", "", "1500"],
+        )];
+        let row = create_test_row(2, &["Poly", "Stdio X30", "", "1500"]);
+
+        let (_, record, score) = best_match(&row, &create_test_mappings(), &existing).unwrap();
+
+        assert_eq!(record.id, "eq-1");
+        assert!(score >= DEFAULT_MATCH_THRESHOLD);
+    }
+}
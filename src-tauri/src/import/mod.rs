@@ -1,38 +1,64 @@
 //! Import Module
 //!
-//! Handles parsing of pricing sheets (Excel, CSV, PDF) for equipment import.
+//! Handles parsing of pricing sheets (Excel, OpenDocument, CSV, PDF) for equipment import.
 //! Provides Tauri commands for the frontend import wizard.
 
+mod category;
+mod commit;
 mod csv_parser;
 mod excel;
+mod image;
+mod impact;
 mod parser;
+mod pdf_parser;
+mod registry;
+mod remote;
+mod stats;
+mod template;
 
-pub use parser::{HeaderSuggestion, ImportError, ParsedFile, ParsedRow};
+pub use commit::ImportSummary;
+pub use image::{ImageDimensions, ImageLimits};
+pub use impact::{ExistingEquipment, FieldChange, ImpactKind, RowImpact};
+pub use parser::{
+    CellValue, DecimalSeparator, HeaderDedupeResult, HeaderSuggestion, ImportError, ParseOptions,
+    ParsedDimensions, ParsedFile, ParsedRow, Parser, SheetSelection, TrimMode, ValidationProfile,
+};
+pub use registry::{register_parser, FileParser, ParserRegistry};
+pub use stats::ColumnStat;
 
-use crate::import::parser::Parser;
+use crate::database::DatabaseState;
+use crate::drawings::EquipmentCategory;
 use std::path::Path;
+use tauri::State;
 
 /// Parse a file and return structured data
 ///
-/// Automatically detects file type based on extension and uses appropriate parser.
+/// Picks the parser registered for the file's extension.
+/// Parsing options default to trimming all whitespace when not provided.
 #[tauri::command]
-pub async fn parse_import_file(path: String) -> Result<ParsedFile, ImportError> {
-    let path = Path::new(&path);
-
-    let extension = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| e.to_lowercase())
-        .unwrap_or_default();
-
-    match extension.as_str() {
-        "xlsx" | "xls" => excel::ExcelParser::parse(path),
-        "csv" => csv_parser::CsvParser::parse(path),
-        _ => Err(ImportError::UnsupportedFormat(format!(
-            "Unsupported file format: .{}",
-            extension
-        ))),
-    }
+pub async fn parse_import_file(
+    path: String,
+    options: Option<ParseOptions>,
+) -> Result<ParsedFile, ImportError> {
+    registry::parse_file(Path::new(&path), &options.unwrap_or_default())
+}
+
+/// Download a CSV or Excel file from an http(s) URL and parse it
+///
+/// Accepts Google Sheets "publish to web" CSV links. The file type is taken
+/// from the response content type or the URL.
+#[tauri::command]
+pub async fn parse_import_url(
+    url: String,
+    options: Option<ParseOptions>,
+) -> Result<ParsedFile, ImportError> {
+    remote::parse_url(&url, &options.unwrap_or_default())
+}
+
+/// List the worksheet names of an Excel workbook so the user can pick one
+#[tauri::command]
+pub async fn list_excel_sheets(path: String) -> Result<Vec<String>, ImportError> {
+    excel::ExcelParser::list_sheets(Path::new(&path))
 }
 
 /// Detect header names and suggest field mappings
@@ -41,13 +67,165 @@ pub async fn detect_headers(parsed: ParsedFile) -> Result<Vec<HeaderSuggestion>,
     parser::detect_header_mappings(&parsed)
 }
 
+/// Flag duplicate or near-duplicate headers ("Cost", "Cost.1") in a parsed file
+///
+/// When `disambiguate` is set, repeated headers are suffixed so each column
+/// can be mapped unambiguously.
+#[tauri::command]
+pub async fn dedupe_import_headers(
+    parsed: ParsedFile,
+    disambiguate: bool,
+) -> Result<HeaderDedupeResult, ImportError> {
+    let mut parsed = parsed;
+    let warnings = parser::dedupe_headers(&mut parsed, disambiguate);
+    Ok(HeaderDedupeResult { parsed, warnings })
+}
+
 /// Validate rows against equipment schema and check for existing matches
+///
+/// Yes/no columns mapped with a flag label are first combined into one value
+/// per field, see [`parser::combine_flag_columns`].
+///
+/// Fuzzy manufacturer and model matches need `match_threshold` similarity,
+/// [`impact::DEFAULT_MATCH_THRESHOLD`] when unset. Rows missing a field of
+/// `profile` are incomplete; the default profile requires manufacturer,
+/// model, SKU and cost.
 #[tauri::command]
 pub async fn validate_import_rows(
     rows: Vec<ParsedRow>,
     mappings: Vec<parser::ColumnMapping>,
+    decimal_separator: Option<DecimalSeparator>,
+    profile: Option<ValidationProfile>,
+    existing: Option<Vec<ExistingEquipment>>,
+    match_threshold: Option<f64>,
 ) -> Result<Vec<parser::ValidationResult>, ImportError> {
-    parser::validate_rows(&rows, &mappings)
+    let (rows, mappings) = parser::combine_flag_columns(&rows, &mappings);
+    parser::validate_rows(
+        &rows,
+        &mappings,
+        decimal_separator.unwrap_or_default(),
+        &profile.unwrap_or_default(),
+        &existing.unwrap_or_default(),
+        match_threshold.unwrap_or(impact::DEFAULT_MATCH_THRESHOLD),
+    )
+}
+
+/// Re-validate rows the user edited in the wizard without re-parsing the file
+#[tauri::command]
+pub async fn revalidate_import_rows(
+    edited: Vec<ParsedRow>,
+    mappings: Vec<parser::ColumnMapping>,
+    decimal_separator: Option<DecimalSeparator>,
+    profile: Option<ValidationProfile>,
+    existing: Option<Vec<ExistingEquipment>>,
+    match_threshold: Option<f64>,
+) -> Result<Vec<parser::ValidationResult>, ImportError> {
+    let (edited, mappings) = parser::combine_flag_columns(&edited, &mappings);
+    parser::revalidate_rows(
+        &edited,
+        &mappings,
+        decimal_separator.unwrap_or_default(),
+        &profile.unwrap_or_default(),
+        &existing.unwrap_or_default(),
+        match_threshold.unwrap_or(impact::DEFAULT_MATCH_THRESHOLD),
+    )
+}
+
+/// Preview how rows would change the existing catalog before committing an import
+///
+/// Each row is reported as new, an update with its field-level diff, or unchanged.
+/// Rows match existing records as in [`validate_import_rows`], with
+/// `match_threshold` defaulting to [`impact::DEFAULT_MATCH_THRESHOLD`].
+#[tauri::command]
+pub async fn import_impact(
+    rows: Vec<ParsedRow>,
+    mappings: Vec<parser::ColumnMapping>,
+    existing: Vec<ExistingEquipment>,
+    match_threshold: Option<f64>,
+) -> Result<Vec<RowImpact>, ImportError> {
+    let (rows, mappings) = parser::combine_flag_columns(&rows, &mappings);
+    Ok(impact::import_impact(
+        &rows,
+        &mappings,
+        &existing,
+        match_threshold.unwrap_or(impact::DEFAULT_MATCH_THRESHOLD),
+    ))
+}
+
+/// Report per-column fill rates and sample values for a parsed file
+#[tauri::command]
+pub async fn column_stats(parsed: ParsedFile) -> Result<Vec<ColumnStat>, ImportError> {
+    Ok(stats::column_stats(&parsed))
+}
+
+/// Check a downloaded equipment image's pixel dimensions before caching it
+///
+/// Limits default to 64x64 through 4096x4096 pixels when not provided.
+#[tauri::command]
+pub async fn validate_equipment_image(
+    path: String,
+    limits: Option<ImageLimits>,
+) -> Result<ImageDimensions, ImportError> {
+    image::validate_image_dimensions(Path::new(&path), &limits.unwrap_or_default())
+}
+
+/// Count validation results by status for the wizard header
+#[tauri::command]
+pub async fn summarize_validation_results(
+    results: Vec<parser::ValidationResult>,
+) -> Result<parser::ValidationSummary, ImportError> {
+    Ok(parser::summarize_validation(&results))
+}
+
+/// Export a blank import template listing every mappable equipment field
+///
+/// `format` is `csv` or `xlsx`.
+#[tauri::command]
+pub async fn export_import_template(
+    output_path: String,
+    format: String,
+) -> Result<(), ImportError> {
+    template::write_template(Path::new(&output_path), &format)
+}
+
+/// Check that a parsed file has exactly the declared columns
+///
+/// Returns the missing or unexpected column names, empty when the file matches.
+#[tauri::command]
+pub async fn assert_import_columns(
+    parsed: ParsedFile,
+    expected: Vec<String>,
+) -> Result<Vec<String>, ImportError> {
+    Ok(parser::assert_columns(&parsed, &expected)
+        .err()
+        .unwrap_or_default())
+}
+
+/// Map a free-text category from a pricing sheet onto the equipment vocabulary
+///
+/// Returns `None` when the value is unrecognized so the wizard can prompt the user.
+#[tauri::command]
+pub async fn map_import_category(
+    raw: String,
+) -> Result<Option<(EquipmentCategory, String)>, ImportError> {
+    Ok(category::map_category(&raw))
+}
+
+/// Write validated rows to the equipment catalog in a single transaction
+///
+/// Valid rows are inserted or update their matched record; all other rows
+/// are skipped and counted in the summary.
+#[tauri::command]
+pub async fn commit_import(
+    database: State<'_, DatabaseState>,
+    rows: Vec<ParsedRow>,
+    mappings: Vec<parser::ColumnMapping>,
+    results: Vec<parser::ValidationResult>,
+) -> Result<ImportSummary, ImportError> {
+    let (rows, mappings) = parser::combine_flag_columns(&rows, &mappings);
+    database
+        .with_connection(|conn| Ok(commit::commit_rows(conn, &rows, &mappings, &results)))
+        .map_err(|e| ImportError::WriteError(e.to_string()))?
 }
 
 #[cfg(test)]
@@ -56,7 +234,7 @@ mod tests {
 
     #[test]
     fn test_unsupported_format() {
-        let result = tokio_test::block_on(parse_import_file("/test/file.txt".to_string()));
+        let result = tokio_test::block_on(parse_import_file("/test/file.txt".to_string(), None));
         assert!(result.is_err());
         match result {
             Err(ImportError::UnsupportedFormat(msg)) => {
@@ -2,7 +2,9 @@
 //!
 //! Defines the common interface for all file parsers and shared data structures.
 
+use super::impact::{best_match, ExistingEquipment};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use thiserror::Error;
 
@@ -29,6 +31,19 @@ pub enum ImportError {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Failed to write file: {0}")]
+    WriteError(String),
+}
+
+/// Outcome of checking a parsed file for duplicate header names
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderDedupeResult {
+    /// The parsed file, with renamed headers if disambiguation was requested
+    pub parsed: ParsedFile,
+    /// One warning per group of duplicate or near-duplicate headers
+    pub warnings: Vec<String>,
 }
 
 /// Represents a parsed file ready for column mapping
@@ -47,6 +62,12 @@ pub struct ParsedFile {
     pub total_rows: usize,
     /// Whether the file was truncated due to size limits
     pub truncated: bool,
+    /// Field delimiter detected in text files
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    /// Character encoding detected in text files, transcoded to UTF-8
+    #[serde(default)]
+    pub encoding: Option<String>,
 }
 
 /// Supported file types
@@ -54,10 +75,24 @@ pub struct ParsedFile {
 #[serde(rename_all = "lowercase")]
 pub enum FileType {
     Xlsx,
+    /// OpenDocument spreadsheet, as saved by LibreOffice
+    Ods,
     Csv,
     Pdf,
 }
 
+/// A cell value with the type it had in the source workbook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum CellValue {
+    Empty,
+    Number(f64),
+    Bool(bool),
+    /// ISO 8601 date, or date and time when the cell has a time of day
+    Date(String),
+    String(String),
+}
+
 /// A single parsed row of data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -66,9 +101,15 @@ pub struct ParsedRow {
     pub row_number: usize,
     /// Cell values as strings
     pub cells: Vec<String>,
+    /// Typed cell values, parallel to `cells`, for formats that store types
+    #[serde(default)]
+    pub typed_cells: Option<Vec<CellValue>>,
 }
 
 /// Column mapping from source to equipment field
+///
+/// Several yes/no columns can map to one field by each setting a flag label;
+/// [`combine_flag_columns`] joins them into a single value.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ColumnMapping {
@@ -78,10 +119,13 @@ pub struct ColumnMapping {
     pub source_header: String,
     /// Target equipment field (null if unmapped)
     pub target_field: Option<EquipmentField>,
+    /// Value this yes/no column contributes when its cell is truthy
+    #[serde(default)]
+    pub flag_label: Option<String>,
 }
 
 /// Equipment fields that can be mapped
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub enum EquipmentField {
     Manufacturer,
@@ -102,6 +146,50 @@ pub enum EquipmentField {
     ImageUrl,
 }
 
+impl EquipmentField {
+    /// Every mappable field, in canonical column order
+    pub const ALL: [EquipmentField; 16] = [
+        EquipmentField::Manufacturer,
+        EquipmentField::Model,
+        EquipmentField::Sku,
+        EquipmentField::Category,
+        EquipmentField::Subcategory,
+        EquipmentField::Description,
+        EquipmentField::Cost,
+        EquipmentField::Msrp,
+        EquipmentField::Height,
+        EquipmentField::Width,
+        EquipmentField::Depth,
+        EquipmentField::Weight,
+        EquipmentField::Voltage,
+        EquipmentField::Wattage,
+        EquipmentField::Certifications,
+        EquipmentField::ImageUrl,
+    ];
+
+    /// Canonical column header for this field
+    pub fn canonical_name(&self) -> &'static str {
+        match self {
+            EquipmentField::Manufacturer => "Manufacturer",
+            EquipmentField::Model => "Model",
+            EquipmentField::Sku => "SKU",
+            EquipmentField::Category => "Category",
+            EquipmentField::Subcategory => "Subcategory",
+            EquipmentField::Description => "Description",
+            EquipmentField::Cost => "Cost",
+            EquipmentField::Msrp => "MSRP",
+            EquipmentField::Height => "Height",
+            EquipmentField::Width => "Width",
+            EquipmentField::Depth => "Depth",
+            EquipmentField::Weight => "Weight",
+            EquipmentField::Voltage => "Voltage",
+            EquipmentField::Wattage => "Wattage",
+            EquipmentField::Certifications => "Certifications",
+            EquipmentField::ImageUrl => "Image URL",
+        }
+    }
+}
+
 /// Suggested mapping for a header
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -114,6 +202,9 @@ pub struct HeaderSuggestion {
     pub suggested_field: Option<EquipmentField>,
     /// Confidence score (0.0 - 1.0)
     pub confidence: f32,
+    /// Platform named by a yes/no column such as "Teams Certified"
+    #[serde(default)]
+    pub flag_label: Option<String>,
 }
 
 /// Validation result for a single row
@@ -128,10 +219,77 @@ pub struct ValidationResult {
     pub match_type: Option<MatchType>,
     /// ID of existing equipment if matched
     pub existing_equipment_id: Option<String>,
+    /// Similarity of the best catalog candidate, from 0 to 1, even when
+    /// it falls below the match threshold
+    #[serde(default)]
+    pub match_score: Option<f64>,
     /// Fields that are missing but required
     pub missing_fields: Vec<EquipmentField>,
     /// Error messages
     pub errors: Vec<String>,
+    /// Non-fatal warnings that do not affect the status
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// Cost as a number, when the cost cell parses
+    #[serde(default)]
+    pub parsed_cost: Option<f64>,
+    /// MSRP as a number, when the MSRP cell parses
+    #[serde(default)]
+    pub parsed_msrp: Option<f64>,
+    /// Dimensions converted to millimetres
+    #[serde(default)]
+    pub parsed_dimensions_mm: ParsedDimensions,
+}
+
+/// Equipment dimensions in millimetres, each set when its cell parses
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedDimensions {
+    pub height: Option<f64>,
+    pub width: Option<f64>,
+    pub depth: Option<f64>,
+}
+
+/// Decimal separator used by numbers in an import
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalSeparator {
+    /// "1,234.56": commas group thousands
+    #[default]
+    Point,
+    /// "1.234,56": points group thousands
+    Comma,
+}
+
+/// Which fields a row must fill in for an import to treat it as complete
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationProfile {
+    /// Fields reported missing, leaving the row incomplete, when empty
+    pub required_fields: Vec<EquipmentField>,
+}
+
+impl Default for ValidationProfile {
+    /// Manufacturer, model, SKU and cost
+    fn default() -> Self {
+        Self {
+            required_fields: vec![
+                EquipmentField::Manufacturer,
+                EquipmentField::Model,
+                EquipmentField::Sku,
+                EquipmentField::Cost,
+            ],
+        }
+    }
+}
+
+/// Count of validation results by status, shown in the wizard header
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationSummary {
+    pub valid: usize,
+    pub incomplete: usize,
+    pub invalid: usize,
 }
 
 /// Validation status for a row
@@ -152,9 +310,83 @@ pub enum MatchType {
     UpdateFallback,
 }
 
+/// How whitespace around cell values is trimmed while parsing
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrimMode {
+    /// Trim headers and data cells
+    All,
+    /// Trim headers only, preserving data cell whitespace
+    Headers,
+    /// Keep all values exactly as they appear in the file
+    None,
+}
+
+impl TrimMode {
+    /// Whether header values should be trimmed
+    pub fn trims_headers(&self) -> bool {
+        matches!(self, TrimMode::All | TrimMode::Headers)
+    }
+
+    /// Whether data cell values should be trimmed
+    pub fn trims_cells(&self) -> bool {
+        matches!(self, TrimMode::All)
+    }
+}
+
+/// Which worksheet of a workbook to parse
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum SheetSelection {
+    /// Zero-based position of the sheet in the workbook
+    Index(usize),
+    /// Sheet name, matched exactly
+    Name(String),
+}
+
+/// Options controlling how a file is parsed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ParseOptions {
+    /// Whitespace trimming applied to headers and cells
+    ///
+    /// When unset, CSV and PDF files trim everything and workbooks keep
+    /// values exactly as stored.
+    pub trim_mode: Option<TrimMode>,
+    /// Worksheet to read from workbooks, the first sheet when unset
+    pub sheet: Option<SheetSelection>,
+    /// Zero-based index of the header row, detected automatically when unset
+    pub header_row_index: Option<usize>,
+    /// Keep only the first [`PREVIEW_ROWS`] data rows, for previewing a file
+    pub preview: bool,
+}
+
+impl ParseOptions {
+    /// Maximum data rows to keep in the parsed file
+    pub fn row_limit(&self) -> usize {
+        if self.preview {
+            PREVIEW_ROWS
+        } else {
+            MAX_ROWS
+        }
+    }
+
+    /// Rows to read from the top of a sheet before choosing the header row
+    pub(crate) fn header_scan_rows(&self) -> usize {
+        self.header_row_index
+            .map_or(HEADER_SCAN_ROWS, |index| HEADER_SCAN_ROWS.max(index + 1))
+    }
+}
+
 /// Trait for file parsers
 pub trait Parser {
-    fn parse(path: &Path) -> Result<ParsedFile, ImportError>;
+    /// Parse a file with default options
+    fn parse(path: &Path) -> Result<ParsedFile, ImportError> {
+        Self::parse_with_options(path, &ParseOptions::default())
+    }
+
+    /// Parse a file with the given options
+    fn parse_with_options(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError>;
 }
 
 /// Maximum rows to load into memory
@@ -163,6 +395,147 @@ pub const MAX_ROWS: usize = 10_000;
 /// Rows to show in preview
 pub const PREVIEW_ROWS: usize = 100;
 
+/// Rows scanned from the top of a sheet when detecting the header row
+pub const HEADER_SCAN_ROWS: usize = 10;
+
+/// Score at which a row is taken as the header without looking further down
+const HEADER_MIN_SCORE: f64 = 0.5;
+
+/// Score how much a row looks like a header row, from 0 to 1
+///
+/// Headers are mostly filled in (relative to the widest scanned row),
+/// mostly non-numeric, and have distinct values. Rows with fewer than two
+/// values, such as titles and blank rows, score zero.
+fn header_row_score(row: &[String], width: usize) -> f64 {
+    let values: Vec<&str> = row
+        .iter()
+        .map(|c| c.trim())
+        .filter(|c| !c.is_empty())
+        .collect();
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let count = values.len() as f64;
+    let filled = count / width as f64;
+    let numeric = values.iter().filter(|v| parse_currency(v).is_some()).count();
+    let text = 1.0 - numeric as f64 / count;
+    let distinct = values.iter().collect::<HashSet<_>>().len() as f64 / count;
+
+    filled * text * distinct
+}
+
+/// Pick the row most likely to hold column headers
+///
+/// Scans the first [`HEADER_SCAN_ROWS`] rows and returns the first one that
+/// scores at least `HEADER_MIN_SCORE`, so rows are only skipped when they
+/// are sparse, numeric or title-like. When no row clears the bar, the
+/// best-scoring one is used, preferring the earliest on ties, and the first
+/// row when none looks like a header.
+pub fn detect_header_row(rows: &[Vec<String>]) -> usize {
+    let scanned = &rows[..rows.len().min(HEADER_SCAN_ROWS)];
+    let width = scanned
+        .iter()
+        .map(|row| row.iter().filter(|c| !c.trim().is_empty()).count())
+        .max()
+        .unwrap_or(0);
+
+    let mut best = (0, 0.0);
+    for (idx, row) in scanned.iter().enumerate() {
+        let score = header_row_score(row, width);
+        if score >= HEADER_MIN_SCORE {
+            return idx;
+        }
+        if score > best.1 {
+            best = (idx, score);
+        }
+    }
+    best.0
+}
+
+/// Trim surrounding whitespace from a value when enabled
+fn trim_value(value: String, trim: bool) -> String {
+    if trim {
+        value.trim().to_string()
+    } else {
+        value
+    }
+}
+
+/// Raw rows split into headers and data rows
+pub(crate) struct SheetRows {
+    /// Index of the header row in the raw rows
+    pub header_index: usize,
+    pub headers: Vec<String>,
+    pub rows: Vec<ParsedRow>,
+    /// Raw rows read below the header, including empty and unkept rows
+    pub data_row_count: usize,
+}
+
+/// Split raw sheet rows into headers and data rows in a single pass
+///
+/// The header row is `options.header_row_index` when set, otherwise the one
+/// detected among the first rows. Rows above it are dropped, empty rows are
+/// skipped, and data rows keep their 1-indexed position in the source file.
+/// Rows past [`ParseOptions::row_limit`] are counted but not kept. Values
+/// are trimmed according to `trim_mode`.
+pub(crate) fn split_header_row(
+    mut raw_rows: impl Iterator<Item = Vec<String>>,
+    options: &ParseOptions,
+    trim_mode: TrimMode,
+) -> Result<SheetRows, ImportError> {
+    let scanned: Vec<Vec<String>> = raw_rows.by_ref().take(options.header_scan_rows()).collect();
+    let header_index = match options.header_row_index {
+        Some(index) if index >= scanned.len() => {
+            return Err(ImportError::ParseError(format!(
+                "Header row {} is past the end of the file ({} rows)",
+                index + 1,
+                scanned.len()
+            )));
+        }
+        Some(index) => index,
+        None => detect_header_row(&scanned),
+    };
+
+    let mut raw_rows = scanned.into_iter().skip(header_index).chain(raw_rows);
+    let headers: Vec<String> = raw_rows
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|h| trim_value(h, trim_mode.trims_headers()))
+        .collect();
+
+    let row_limit = options.row_limit();
+    let mut rows = Vec::new();
+    let mut data_row_count = 0;
+    for (idx, row) in raw_rows.enumerate() {
+        data_row_count += 1;
+        if idx >= row_limit {
+            continue;
+        }
+
+        let cells: Vec<String> = row
+            .into_iter()
+            .map(|c| trim_value(c, trim_mode.trims_cells()))
+            .collect();
+        // Skip completely empty rows
+        if !cells.iter().all(|c| c.trim().is_empty()) {
+            rows.push(ParsedRow {
+                row_number: header_index + idx + 2, // 1-indexed, after header
+                cells,
+                typed_cells: None,
+            });
+        }
+    }
+
+    Ok(SheetRows {
+        header_index,
+        headers,
+        rows,
+        data_row_count,
+    })
+}
+
 /// Detect header mappings based on common patterns
 pub fn detect_header_mappings(parsed: &ParsedFile) -> Result<Vec<HeaderSuggestion>, ImportError> {
     let suggestions: Vec<HeaderSuggestion> = parsed
@@ -176,6 +549,7 @@ pub fn detect_header_mappings(parsed: &ParsedFile) -> Result<Vec<HeaderSuggestio
                 header: header.clone(),
                 suggested_field: field,
                 confidence,
+                flag_label: flag_label_for_header(header),
             }
         })
         .collect();
@@ -278,38 +652,319 @@ fn suggest_field_for_header(header: &str) -> (Option<EquipmentField>, f32) {
     if lower.contains("desc") {
         return (Some(EquipmentField::Description), 0.7);
     }
+    if lower.contains("certified") || lower.contains("certification") {
+        return (Some(EquipmentField::Certifications), 0.7);
+    }
 
     // No match
     (None, 0.0)
 }
 
-/// Validate rows against mappings
+/// Platform named by a yes/no certification header
+///
+/// "Teams Certified" and "Certified for Zoom" name "Teams" and "Zoom".
+/// Headers without "certified" are not yes/no columns and name nothing.
+fn flag_label_for_header(header: &str) -> Option<String> {
+    if !header.to_lowercase().contains("certified") {
+        return None;
+    }
+
+    let label = header
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| {
+            !word.is_empty()
+                && !word.eq_ignore_ascii_case("certified")
+                && !word.eq_ignore_ascii_case("for")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    (!label.is_empty()).then_some(label)
+}
+
+/// Whether a yes/no cell is set, e.g. "Yes", "Y", "TRUE", "1" or "X"
+fn is_truthy(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "yes" | "y" | "true" | "1" | "x"
+    )
+}
+
+/// Join yes/no columns mapped to the same field into one value per row
+///
+/// Mappings with a flag label are replaced by one mapping per field to a
+/// column appended to every row, holding the comma-joined labels of the
+/// columns whose cell is truthy, in mapping order. A plain mapping to the
+/// same field comes first and takes precedence. Rows and mappings are
+/// returned unchanged when no mapped column has a flag label.
+pub fn combine_flag_columns(
+    rows: &[ParsedRow],
+    mappings: &[ColumnMapping],
+) -> (Vec<ParsedRow>, Vec<ColumnMapping>) {
+    let is_flag = |m: &ColumnMapping| m.flag_label.is_some() && m.target_field.is_some();
+    let mut fields: Vec<EquipmentField> = Vec::new();
+    for field in mappings
+        .iter()
+        .filter(|m| is_flag(m))
+        .filter_map(|m| m.target_field)
+    {
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+
+    let mut rows = rows.to_vec();
+    let mut combined: Vec<ColumnMapping> =
+        mappings.iter().filter(|m| !is_flag(m)).cloned().collect();
+    let first_column = rows
+        .iter()
+        .map(|r| r.cells.len())
+        .chain(mappings.iter().map(|m| m.source_column + 1))
+        .max()
+        .unwrap_or(0);
+
+    for (column, field) in (first_column..).zip(fields) {
+        let flags: Vec<&ColumnMapping> = mappings
+            .iter()
+            .filter(|m| is_flag(m) && m.target_field == Some(field))
+            .collect();
+
+        for row in &mut rows {
+            let value = flags
+                .iter()
+                .filter(|m| row.cells.get(m.source_column).is_some_and(|c| is_truthy(c)))
+                .filter_map(|m| m.flag_label.as_deref())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            row.cells.resize(column, String::new());
+            row.cells.push(value.clone());
+            if let Some(typed) = &mut row.typed_cells {
+                typed.resize(column, CellValue::Empty);
+                typed.push(CellValue::String(value));
+            }
+        }
+
+        combined.push(ColumnMapping {
+            source_column: column,
+            source_header: flags
+                .iter()
+                .map(|m| m.source_header.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            target_field: Some(field),
+            flag_label: None,
+        });
+    }
+
+    (rows, combined)
+}
+
+/// Validate rows against mappings and match them to the existing catalog
+///
+/// Prices and dimensions are read with the given decimal separator. Rows
+/// repeating the SKU of an earlier row (ignoring case and surrounding
+/// whitespace) are marked invalid with a reference to the first occurrence.
+/// Rows update a catalog record when the SKU matches exactly or the
+/// manufacturer and model similarity reaches `match_threshold`. Rows
+/// missing a field required by `profile` are incomplete.
 pub fn validate_rows(
     rows: &[ParsedRow],
     mappings: &[ColumnMapping],
+    separator: DecimalSeparator,
+    profile: &ValidationProfile,
+    existing: &[ExistingEquipment],
+    match_threshold: f64,
 ) -> Result<Vec<ValidationResult>, ImportError> {
+    let mut first_seen: HashMap<String, usize> = HashMap::new();
     let results: Vec<ValidationResult> = rows
         .iter()
-        .map(|row| validate_single_row(row, mappings))
+        .map(|row| {
+            let mut result = validate_single_row(row, mappings, separator, profile);
+
+            let sku = mapped_value(row, mappings, EquipmentField::Sku)
+                .map(|v| v.trim().to_uppercase())
+                .filter(|v| !v.is_empty());
+            if let Some(sku) = sku {
+                match first_seen.get(&sku) {
+                    Some(first_row) => {
+                        result
+                            .errors
+                            .push(format!("Duplicate SKU in import (also row {})", first_row));
+                        result.status = ValidationStatus::Invalid;
+                    }
+                    None => {
+                        first_seen.insert(sku, row.row_number);
+                    }
+                }
+            }
+
+            if let Some((match_type, record, score)) = best_match(row, mappings, existing) {
+                result.match_score = Some(score);
+                if score >= match_threshold {
+                    result.match_type = Some(match_type);
+                    result.existing_equipment_id = Some(record.id.clone());
+                }
+            }
+
+            result
+        })
         .collect();
 
     Ok(results)
 }
 
+/// Re-validate rows after the user has edited cells in the import wizard
+///
+/// Runs the same checks as [`validate_rows`] on already-parsed data, so the
+/// source file does not need to be parsed again. Row numbers are carried over
+/// from the edited rows, which keep the numbering of the original file.
+pub fn revalidate_rows(
+    edited: &[ParsedRow],
+    mappings: &[ColumnMapping],
+    separator: DecimalSeparator,
+    profile: &ValidationProfile,
+    existing: &[ExistingEquipment],
+    match_threshold: f64,
+) -> Result<Vec<ValidationResult>, ImportError> {
+    validate_rows(
+        edited,
+        mappings,
+        separator,
+        profile,
+        existing,
+        match_threshold,
+    )
+}
+
+/// Tally validation results by status
+pub fn summarize_validation(results: &[ValidationResult]) -> ValidationSummary {
+    results
+        .iter()
+        .fold(ValidationSummary::default(), |mut summary, result| {
+            match result.status {
+                ValidationStatus::Valid => summary.valid += 1,
+                ValidationStatus::Incomplete => summary.incomplete += 1,
+                ValidationStatus::Invalid => summary.invalid += 1,
+            }
+            summary
+        })
+}
+
+/// Check that a parsed file has exactly the expected columns
+///
+/// Headers are compared case-insensitively, ignoring surrounding whitespace.
+/// On mismatch, returns the missing expected columns followed by any
+/// unexpected columns found in the file.
+pub fn assert_columns(parsed: &ParsedFile, expected: &[String]) -> Result<(), Vec<String>> {
+    let normalize = |header: &str| header.trim().to_lowercase();
+    let actual: Vec<String> = parsed.headers.iter().map(|h| normalize(h)).collect();
+    let wanted: Vec<String> = expected.iter().map(|h| normalize(h)).collect();
+
+    let missing = expected
+        .iter()
+        .zip(&wanted)
+        .filter(|(_, w)| !actual.contains(w))
+        .map(|(e, _)| e.clone());
+    let unexpected = parsed
+        .headers
+        .iter()
+        .zip(&actual)
+        .filter(|(_, a)| !a.is_empty() && !wanted.contains(a))
+        .map(|(h, _)| h.clone());
+
+    let mismatched: Vec<String> = missing.chain(unexpected).collect();
+    if mismatched.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatched)
+    }
+}
+
+/// Normalize a header for duplicate detection
+///
+/// Ignores case, surrounding whitespace and the copy suffixes that joins
+/// and exports append to repeated columns ("Cost.1", "Cost_2", "Cost (3)").
+fn header_base_name(header: &str) -> String {
+    let lower = header.trim().to_lowercase();
+    let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+
+    let stripped = lower
+        .strip_suffix(')')
+        .and_then(|s| s.rsplit_once(" ("))
+        .filter(|(_, n)| is_digits(n))
+        .map(|(base, _)| base)
+        .or_else(|| {
+            lower
+                .rsplit_once(['.', '_'])
+                .filter(|(_, n)| is_digits(n))
+                .map(|(base, _)| base)
+        })
+        .unwrap_or(&lower);
+
+    stripped.trim_end().to_string()
+}
+
+/// Detect duplicate and near-duplicate headers in a parsed file
+///
+/// Returns one warning per group of matching headers. When `disambiguate`
+/// is set, every header after the first in a group is renamed to
+/// "<first> (2)", "<first> (3)" and so on so column mappings stay unambiguous.
+pub fn dedupe_headers(parsed: &mut ParsedFile, disambiguate: bool) -> Vec<String> {
+    let bases: Vec<String> = parsed.headers.iter().map(|h| header_base_name(h)).collect();
+    let mut warnings = Vec::new();
+
+    for (first, base) in bases.iter().enumerate() {
+        if base.is_empty() || bases[..first].contains(base) {
+            continue;
+        }
+
+        let group: Vec<usize> = (first..bases.len())
+            .filter(|&i| bases[i] == *base)
+            .collect();
+        if group.len() < 2 {
+            continue;
+        }
+
+        let names: Vec<String> = group
+            .iter()
+            .map(|&i| format!("'{}'", parsed.headers[i]))
+            .collect();
+        warnings.push(format!(
+            "Duplicate columns {} (columns {})",
+            names.join(", "),
+            group
+                .iter()
+                .map(|i| (i + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        if disambiguate {
+            let original = parsed.headers[first].trim().to_string();
+            for (n, &i) in group.iter().enumerate().skip(1) {
+                parsed.headers[i] = format!("{} ({})", original, n + 1);
+            }
+        }
+    }
+
+    warnings
+}
+
 /// Validate a single row
-fn validate_single_row(row: &ParsedRow, mappings: &[ColumnMapping]) -> ValidationResult {
+fn validate_single_row(
+    row: &ParsedRow,
+    mappings: &[ColumnMapping],
+    separator: DecimalSeparator,
+    profile: &ValidationProfile,
+) -> ValidationResult {
     let mut missing_fields = Vec::new();
     let mut errors = Vec::new();
+    let mut warnings = Vec::new();
 
     // Required fields
-    let required = [
-        EquipmentField::Manufacturer,
-        EquipmentField::Model,
-        EquipmentField::Sku,
-        EquipmentField::Cost,
-    ];
-
-    for field in required.iter() {
+    for field in profile.required_fields.iter() {
         let has_value = mappings.iter().any(|m| {
             if m.target_field == Some(*field) {
                 row.cells
@@ -326,23 +981,56 @@ fn validate_single_row(row: &ParsedRow, mappings: &[ColumnMapping]) -> Validatio
         }
     }
 
-    // Validate cost is numeric
-    if let Some(cost_mapping) = mappings.iter().find(|m| m.target_field == Some(EquipmentField::Cost)) {
-        if let Some(cost_str) = row.cells.get(cost_mapping.source_column) {
-            let cleaned = cost_str.replace(['$', ',', ' '], "");
-            if !cleaned.is_empty() && cleaned.parse::<f64>().is_err() {
-                errors.push(format!("Invalid cost format: '{}'", cost_str));
-            }
+    // Validate cost and MSRP are numeric; empty MSRP is allowed. Numeric
+    // workbook cells are taken as-is rather than re-parsed from their text
+    let mut parse_price = |field: EquipmentField, label: &str| {
+        let value = mapped_value(row, mappings, field)?.trim();
+        let parsed = match mapped_typed_value(row, mappings, field) {
+            Some(CellValue::Number(number)) => Some(*number),
+            _ => parse_amount(value, separator),
+        };
+        if !value.is_empty() && parsed.is_none() {
+            errors.push(format!("Invalid {} format: '{}'", label, value));
+        }
+        parsed
+    };
+    let parsed_cost = parse_price(EquipmentField::Cost, "cost");
+    let parsed_msrp = parse_price(EquipmentField::Msrp, "MSRP");
+
+    // Warn when MSRP is below cost, which usually means swapped columns
+    if let (Some(cost), Some(msrp)) = (parsed_cost, parsed_msrp) {
+        if msrp < cost {
+            warnings.push(format!(
+                "MSRP ({:.2}) is less than cost ({:.2}); values may be swapped",
+                msrp, cost
+            ));
         }
     }
 
-    // Validate MSRP is numeric if present
-    if let Some(msrp_mapping) = mappings.iter().find(|m| m.target_field == Some(EquipmentField::Msrp)) {
-        if let Some(msrp_str) = row.cells.get(msrp_mapping.source_column) {
-            let cleaned = msrp_str.replace(['$', ',', ' '], "");
-            if !cleaned.is_empty() && cleaned.parse::<f64>().is_err() {
-                errors.push(format!("Invalid MSRP format: '{}'", msrp_str));
-            }
+    // Convert dimensions to millimetres, warning about unrecognized values
+    let mut parse_dimension = |field: EquipmentField| {
+        let value = mapped_value(row, mappings, field)?.trim();
+        let parsed = parse_dimension_mm(value, separator);
+        if !value.is_empty() && parsed.is_none() {
+            warnings.push(format!(
+                "Unrecognized {} value: '{}'",
+                field.canonical_name().to_lowercase(),
+                value
+            ));
+        }
+        parsed
+    };
+    let parsed_dimensions_mm = ParsedDimensions {
+        height: parse_dimension(EquipmentField::Height),
+        width: parse_dimension(EquipmentField::Width),
+        depth: parse_dimension(EquipmentField::Depth),
+    };
+
+    // Warn about malformed image URLs; empty values are allowed
+    if let Some(url) = mapped_value(row, mappings, EquipmentField::ImageUrl) {
+        let url = url.trim();
+        if !url.is_empty() && !is_valid_http_url(url) {
+            warnings.push(format!("Invalid image URL: '{}'", url));
         }
     }
 
@@ -358,13 +1046,111 @@ fn validate_single_row(row: &ParsedRow, mappings: &[ColumnMapping]) -> Validatio
     ValidationResult {
         row_number: row.row_number,
         status,
-        match_type: Some(MatchType::New), // Will be updated when we check against DB
+        match_type: Some(MatchType::New), // Updated by validate_rows from the catalog
         existing_equipment_id: None,
+        match_score: None,
         missing_fields,
         errors,
+        warnings,
+        parsed_cost,
+        parsed_msrp,
+        parsed_dimensions_mm,
     }
 }
 
+/// Get the typed cell value mapped to a field, when the source kept types
+pub(crate) fn mapped_typed_value<'a>(
+    row: &'a ParsedRow,
+    mappings: &[ColumnMapping],
+    field: EquipmentField,
+) -> Option<&'a CellValue> {
+    let column = mappings
+        .iter()
+        .find(|m| m.target_field == Some(field))?
+        .source_column;
+    row.typed_cells.as_ref()?.get(column)
+}
+
+/// Get the cell value mapped to a field, if any
+pub(crate) fn mapped_value<'a>(
+    row: &'a ParsedRow,
+    mappings: &[ColumnMapping],
+    field: EquipmentField,
+) -> Option<&'a str> {
+    mappings
+        .iter()
+        .find(|m| m.target_field == Some(field))
+        .and_then(|m| row.cells.get(m.source_column))
+        .map(|v| v.as_str())
+}
+
+/// Check that a value is an absolute http(s) URL with a plausible host
+fn is_valid_http_url(value: &str) -> bool {
+    let lower = value.to_lowercase();
+    let Some(rest) = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))
+    else {
+        return false;
+    };
+
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty()
+        && !host.starts_with(['.', '-', ':'])
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+}
+
+/// Parse a currency value written with a decimal point ("$2,500.00")
+pub(crate) fn parse_currency(value: &str) -> Option<f64> {
+    parse_amount(value, DecimalSeparator::Point)
+}
+
+/// Parse a number, ignoring currency symbols, spaces and thousands separators
+///
+/// Handles "$2,500.00" with [`DecimalSeparator::Point`] and "2 500,00" or
+/// "€1.899,00" with [`DecimalSeparator::Comma`]. Values that are not
+/// finite numbers, such as "NaN", "inf" or an overflowing "1e999", are
+/// rejected.
+pub fn parse_amount(value: &str, separator: DecimalSeparator) -> Option<f64> {
+    let cleaned: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '$' | '€' | '£' | '¥' | '\''))
+        .collect();
+    let normalized = match separator {
+        DecimalSeparator::Point => cleaned.replace(',', ""),
+        DecimalSeparator::Comma => cleaned.replace('.', "").replace(',', "."),
+    };
+    normalized.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Unit suffixes accepted on dimension values, with millimetres per unit
+const LENGTH_UNITS: [(&str, f64); 6] = [
+    ("mm", 1.0),
+    ("cm", 10.0),
+    ("inches", 25.4),
+    ("inch", 25.4),
+    ("in", 25.4),
+    ("\"", 25.4),
+];
+
+/// Parse a length such as "19\"", "483mm" or "48,3 cm" into millimetres
+///
+/// Values without a unit are taken as inches, the unit of most AV price lists.
+pub fn parse_dimension_mm(value: &str, separator: DecimalSeparator) -> Option<f64> {
+    let lower = value.trim().to_lowercase();
+    let (number, mm_per_unit) = LENGTH_UNITS
+        .iter()
+        .find_map(|(unit, scale)| lower.strip_suffix(unit).map(|n| (n, *scale)))
+        .unwrap_or((lower.as_str(), 25.4));
+    parse_amount(number, separator).map(|n| n * mm_per_unit)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +1190,66 @@ mod tests {
         assert_eq!(confidence, 0.0);
     }
 
+    #[test]
+    fn test_suggest_field_certified_flag() {
+        let (field, _) = suggest_field_for_header("Teams Certified");
+        assert_eq!(field, Some(EquipmentField::Certifications));
+        assert_eq!(
+            flag_label_for_header("Teams Certified"),
+            Some("Teams".to_string())
+        );
+        assert_eq!(
+            flag_label_for_header("Certified for Zoom?"),
+            Some("Zoom".to_string())
+        );
+        assert_eq!(flag_label_for_header("Certifications"), None);
+    }
+
+    #[test]
+    fn test_combine_flag_columns_teams_zoom() {
+        let flag = |column: usize, header: &str, label: &str| ColumnMapping {
+            source_column: column,
+            source_header: header.to_string(),
+            target_field: Some(EquipmentField::Certifications),
+            flag_label: Some(label.to_string()),
+        };
+        let mappings = vec![
+            ColumnMapping {
+                source_column: 0,
+                source_header: "Model".to_string(),
+                target_field: Some(EquipmentField::Model),
+                flag_label: None,
+            },
+            flag(1, "Teams Certified", "Teams"),
+            flag(2, "Zoom Certified", "Zoom"),
+        ];
+        let rows: Vec<ParsedRow> = [["X50", "Yes", "TRUE"], ["X30", "No", "x"], ["X70", "", "0"]]
+            .iter()
+            .enumerate()
+            .map(|(i, cells)| ParsedRow {
+                row_number: i + 2,
+                cells: cells.iter().map(|c| c.to_string()).collect(),
+                typed_cells: None,
+            })
+            .collect();
+
+        let (rows, mappings) = combine_flag_columns(&rows, &mappings);
+
+        assert_eq!(mappings.len(), 2);
+        let certifications: Vec<Option<&str>> = rows
+            .iter()
+            .map(|row| mapped_value(row, &mappings, EquipmentField::Certifications))
+            .collect();
+        assert_eq!(
+            certifications,
+            vec![Some("Teams,Zoom"), Some("Zoom"), Some("")]
+        );
+        assert_eq!(
+            mapped_value(&rows[0], &mappings, EquipmentField::Model),
+            Some("X50")
+        );
+    }
+
     #[test]
     fn test_validate_row_complete() {
         let row = ParsedRow {
@@ -414,6 +1260,7 @@ mod tests {
                 "2200-86260-001".to_string(),
                 "2500.00".to_string(),
             ],
+            typed_cells: None,
         };
 
         let mappings = vec![
@@ -421,25 +1268,34 @@ mod tests {
                 source_column: 0,
                 source_header: "Manufacturer".to_string(),
                 target_field: Some(EquipmentField::Manufacturer),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 1,
                 source_header: "Model".to_string(),
                 target_field: Some(EquipmentField::Model),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 2,
                 source_header: "SKU".to_string(),
                 target_field: Some(EquipmentField::Sku),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 3,
                 source_header: "Cost".to_string(),
                 target_field: Some(EquipmentField::Cost),
+                flag_label: None,
             },
         ];
 
-        let result = validate_single_row(&row, &mappings);
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
         assert_eq!(result.status, ValidationStatus::Valid);
         assert!(result.missing_fields.is_empty());
         assert!(result.errors.is_empty());
@@ -455,6 +1311,7 @@ mod tests {
                 "".to_string(),
                 "2500.00".to_string(),
             ],
+            typed_cells: None,
         };
 
         let mappings = vec![
@@ -462,29 +1319,76 @@ mod tests {
                 source_column: 0,
                 source_header: "Manufacturer".to_string(),
                 target_field: Some(EquipmentField::Manufacturer),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 1,
                 source_header: "Model".to_string(),
                 target_field: Some(EquipmentField::Model),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 2,
                 source_header: "SKU".to_string(),
                 target_field: Some(EquipmentField::Sku),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 3,
                 source_header: "Cost".to_string(),
                 target_field: Some(EquipmentField::Cost),
+                flag_label: None,
             },
         ];
 
-        let result = validate_single_row(&row, &mappings);
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
         assert_eq!(result.status, ValidationStatus::Incomplete);
         assert!(result.missing_fields.contains(&EquipmentField::Sku));
     }
 
+    #[test]
+    fn test_validate_rows_profile_without_sku() {
+        let row = ParsedRow {
+            row_number: 1,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "2500.00".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Cost,
+        ]);
+        let profile = ValidationProfile {
+            required_fields: vec![
+                EquipmentField::Manufacturer,
+                EquipmentField::Model,
+                EquipmentField::Cost,
+            ],
+        };
+
+        let results = validate_rows(
+            &[row],
+            &mappings,
+            DecimalSeparator::Point,
+            &profile,
+            &[],
+            1.0,
+        )
+        .unwrap();
+
+        assert_eq!(results[0].status, ValidationStatus::Valid);
+        assert!(results[0].missing_fields.is_empty());
+    }
+
     #[test]
     fn test_validate_row_invalid_cost() {
         let row = ParsedRow {
@@ -495,6 +1399,7 @@ mod tests {
                 "ABC123".to_string(),
                 "TBD".to_string(),
             ],
+            typed_cells: None,
         };
 
         let mappings = vec![
@@ -502,26 +1407,644 @@ mod tests {
                 source_column: 0,
                 source_header: "Manufacturer".to_string(),
                 target_field: Some(EquipmentField::Manufacturer),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 1,
                 source_header: "Model".to_string(),
                 target_field: Some(EquipmentField::Model),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 2,
                 source_header: "SKU".to_string(),
                 target_field: Some(EquipmentField::Sku),
+                flag_label: None,
             },
             ColumnMapping {
                 source_column: 3,
                 source_header: "Cost".to_string(),
                 target_field: Some(EquipmentField::Cost),
+                flag_label: None,
             },
         ];
 
-        let result = validate_single_row(&row, &mappings);
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
         assert_eq!(result.status, ValidationStatus::Invalid);
         assert!(result.errors[0].contains("Invalid cost"));
     }
+
+    #[test]
+    fn test_revalidate_rows_after_fixing_cost() {
+        let mut row = ParsedRow {
+            row_number: 7,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "ABC123".to_string(),
+                "TBD".to_string(),
+            ],
+            typed_cells: None,
+        };
+
+        let mappings = vec![
+            ColumnMapping {
+                source_column: 0,
+                source_header: "Manufacturer".to_string(),
+                target_field: Some(EquipmentField::Manufacturer),
+                flag_label: None,
+            },
+            ColumnMapping {
+                source_column: 1,
+                source_header: "Model".to_string(),
+                target_field: Some(EquipmentField::Model),
+                flag_label: None,
+            },
+            ColumnMapping {
+                source_column: 2,
+                source_header: "SKU".to_string(),
+                target_field: Some(EquipmentField::Sku),
+                flag_label: None,
+            },
+            ColumnMapping {
+                source_column: 3,
+                source_header: "Cost".to_string(),
+                target_field: Some(EquipmentField::Cost),
+                flag_label: None,
+            },
+        ];
+
+        let before = revalidate_rows(
+            &[row.clone()],
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+            &[],
+            1.0,
+        )
+        .unwrap();
+        assert_eq!(before[0].status, ValidationStatus::Invalid);
+
+        row.cells[3] = "$1,299.00".to_string();
+        let after = revalidate_rows(
+            &[row],
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+            &[],
+            1.0,
+        )
+        .unwrap();
+        assert_eq!(after[0].status, ValidationStatus::Valid);
+        assert_eq!(after[0].row_number, 7);
+        assert!(after[0].errors.is_empty());
+    }
+
+    #[test]
+    fn test_summarize_validation_counts() {
+        let result = |status| ValidationResult {
+            row_number: 1,
+            status,
+            match_type: None,
+            existing_equipment_id: None,
+            match_score: None,
+            missing_fields: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            parsed_cost: None,
+            parsed_msrp: None,
+            parsed_dimensions_mm: ParsedDimensions::default(),
+        };
+        let results = vec![
+            result(ValidationStatus::Valid),
+            result(ValidationStatus::Invalid),
+            result(ValidationStatus::Valid),
+            result(ValidationStatus::Incomplete),
+            result(ValidationStatus::Valid),
+        ];
+
+        let summary = summarize_validation(&results);
+
+        assert_eq!(summary.valid, 3);
+        assert_eq!(summary.incomplete, 1);
+        assert_eq!(summary.invalid, 1);
+        assert_eq!(
+            summary.valid + summary.incomplete + summary.invalid,
+            results.len()
+        );
+    }
+
+    fn create_test_parsed(headers: &[&str]) -> ParsedFile {
+        ParsedFile {
+            file_name: "test.csv".to_string(),
+            file_type: FileType::Csv,
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+            total_rows: 0,
+            truncated: false,
+            delimiter: None,
+            encoding: None,
+        }
+    }
+
+    #[test]
+    fn test_assert_columns_missing_expected_column() {
+        let parsed = create_test_parsed(&["Manufacturer", "Model", "Cost"]);
+        let expected = vec![
+            "manufacturer".to_string(),
+            "model".to_string(),
+            "SKU".to_string(),
+            "cost".to_string(),
+        ];
+
+        assert_eq!(
+            assert_columns(&parsed, &expected),
+            Err(vec!["SKU".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_assert_columns_reports_unexpected_column() {
+        let parsed = create_test_parsed(&["Manufacturer", "Model", "Notes"]);
+        let expected = vec!["Manufacturer".to_string(), "Model".to_string()];
+
+        assert_eq!(
+            assert_columns(&parsed, &expected),
+            Err(vec!["Notes".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_assert_columns_full_match() {
+        let parsed = create_test_parsed(&[" Manufacturer ", "MODEL", "Sku"]);
+        let expected = vec![
+            "manufacturer".to_string(),
+            "model".to_string(),
+            "SKU".to_string(),
+        ];
+
+        assert_eq!(assert_columns(&parsed, &expected), Ok(()));
+    }
+
+    #[test]
+    fn test_dedupe_headers_disambiguates_repeated_cost() {
+        let mut parsed = create_test_parsed(&["Manufacturer", "Cost", "Model", "Cost"]);
+
+        let warnings = dedupe_headers(&mut parsed, true);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("columns 2, 4"));
+        assert_eq!(
+            parsed.headers,
+            vec!["Manufacturer", "Cost", "Model", "Cost (2)"]
+        );
+    }
+
+    #[test]
+    fn test_dedupe_headers_flags_near_duplicates_without_renaming() {
+        let mut parsed = create_test_parsed(&["Cost", "cost.1", "MSRP", "Cost_2"]);
+
+        let warnings = dedupe_headers(&mut parsed, false);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'cost.1'"));
+        assert_eq!(parsed.headers, vec!["Cost", "cost.1", "MSRP", "Cost_2"]);
+    }
+
+    #[test]
+    fn test_dedupe_headers_unique_headers_unchanged() {
+        let mut parsed = create_test_parsed(&["Manufacturer", "Model", "SKU", "Cost"]);
+
+        assert!(dedupe_headers(&mut parsed, true).is_empty());
+        assert_eq!(parsed.headers, vec!["Manufacturer", "Model", "SKU", "Cost"]);
+    }
+
+    fn create_test_mappings(fields: &[EquipmentField]) -> Vec<ColumnMapping> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| ColumnMapping {
+                source_column: idx,
+                source_header: field.canonical_name().to_string(),
+                target_field: Some(*field),
+                flag_label: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_row_msrp_below_cost_warns() {
+        let row = ParsedRow {
+            row_number: 1,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "2200-86260-001".to_string(),
+                "$2,500.00".to_string(),
+                "$1,999.00".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::Msrp,
+        ]);
+
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
+
+        assert_eq!(result.status, ValidationStatus::Valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("less than cost"));
+    }
+
+    #[test]
+    fn test_validate_row_msrp_above_cost_passes() {
+        let row = ParsedRow {
+            row_number: 1,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "2200-86260-001".to_string(),
+                "$2,500.00".to_string(),
+                "$3,499.00".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::Msrp,
+        ]);
+
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
+
+        assert_eq!(result.status, ValidationStatus::Valid);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_rows_flags_duplicate_sku() {
+        let row = |row_number: usize, model: &str, sku: &str| ParsedRow {
+            row_number,
+            cells: vec![
+                "Poly".to_string(),
+                model.to_string(),
+                sku.to_string(),
+                "2500.00".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let rows = vec![
+            row(2, "Studio X50", "2200-86260-001"),
+            row(3, "Studio X30", "2200-86270-001"),
+            row(4, "Studio X50", " 2200-86260-001 "),
+        ];
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+        ]);
+
+        let results = validate_rows(
+            &rows,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+            &[],
+            1.0,
+        )
+        .unwrap();
+
+        assert_eq!(results[0].status, ValidationStatus::Valid);
+        assert_eq!(results[1].status, ValidationStatus::Valid);
+        assert_eq!(results[2].status, ValidationStatus::Invalid);
+        assert_eq!(
+            results[2].errors,
+            vec!["Duplicate SKU in import (also row 2)"]
+        );
+    }
+
+    #[test]
+    fn test_validate_row_uses_typed_cost() {
+        let row = ParsedRow {
+            row_number: 2,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "2200-86260-001".to_string(),
+                "1234.57".to_string(),
+            ],
+            typed_cells: Some(vec![
+                CellValue::String("Poly".to_string()),
+                CellValue::String("Studio X50".to_string()),
+                CellValue::String("2200-86260-001".to_string()),
+                CellValue::Number(1234.5678),
+            ]),
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+        ]);
+
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Comma,
+            &ValidationProfile::default(),
+        );
+
+        assert_eq!(result.status, ValidationStatus::Valid);
+        assert_eq!(result.parsed_cost, Some(1234.5678));
+    }
+
+    #[test]
+    fn test_parse_amount_separators() {
+        let european = |value| parse_amount(value, DecimalSeparator::Comma);
+        assert_eq!(parse_currency("$2,500.00"), Some(2500.0));
+        assert_eq!(parse_currency("TBD"), None);
+        assert_eq!(european("2 500,00"), Some(2500.0));
+        assert_eq!(european("€1.899,00"), Some(1899.0));
+        assert_eq!(european("1\u{a0}299,50 €"), Some(1299.5));
+    }
+
+    #[test]
+    fn test_parse_amount_rejects_non_finite() {
+        for value in ["NaN", "inf", "-infinity", "$1e999"] {
+            assert_eq!(parse_amount(value, DecimalSeparator::Point), None);
+        }
+        assert_eq!(parse_currency("nan"), None);
+    }
+
+    #[test]
+    fn test_parse_dimension_mm_units() {
+        let mm = |value| parse_dimension_mm(value, DecimalSeparator::Point).unwrap();
+        assert!((mm("19\"") - 482.6).abs() < 1e-9);
+        assert!((mm("19 in") - 482.6).abs() < 1e-9);
+        assert_eq!(mm("483mm"), 483.0);
+        assert_eq!(mm("4.5 cm"), 45.0);
+        assert_eq!(parse_dimension_mm("1U", DecimalSeparator::Point), None);
+        assert_eq!(
+            parse_dimension_mm("48,3 cm", DecimalSeparator::Comma),
+            Some(483.0)
+        );
+    }
+
+    #[test]
+    fn test_validate_row_returns_parsed_values() {
+        let row = ParsedRow {
+            row_number: 1,
+            cells: vec![
+                "Sennheiser".to_string(),
+                "EW-D".to_string(),
+                "508895".to_string(),
+                "€1.899,00".to_string(),
+                "2 499,00".to_string(),
+                "44mm".to_string(),
+                "19\"".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::Msrp,
+            EquipmentField::Height,
+            EquipmentField::Width,
+        ]);
+
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Comma,
+            &ValidationProfile::default(),
+        );
+
+        assert_eq!(result.status, ValidationStatus::Valid);
+        assert_eq!(result.parsed_cost, Some(1899.0));
+        assert_eq!(result.parsed_msrp, Some(2499.0));
+        assert_eq!(result.parsed_dimensions_mm.height, Some(44.0));
+        assert!((result.parsed_dimensions_mm.width.unwrap() - 482.6).abs() < 1e-9);
+        assert_eq!(result.parsed_dimensions_mm.depth, None);
+    }
+
+    #[test]
+    fn test_validate_rows_matches_existing_catalog() {
+        let existing = vec![ExistingEquipment {
+            id: "eq-1".to_string(),
+            values: HashMap::from([
+                (EquipmentField::Manufacturer, "Shure".to_string()),
+                (EquipmentField::Model, "MXA920-S".to_string()),
+                (EquipmentField::Sku, "MXA920W-S".to_string()),
+            ]),
+        }];
+        let row = |sku: &str, model: &str| ParsedRow {
+            row_number: 2,
+            cells: vec![
+                "Shure".to_string(),
+                model.to_string(),
+                sku.to_string(),
+                "4500".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+        ]);
+        let validate = |row: ParsedRow, threshold| {
+            validate_rows(
+                &[row],
+                &mappings,
+                DecimalSeparator::Point,
+                &ValidationProfile::default(),
+                &existing,
+                threshold,
+            )
+            .unwrap()
+            .remove(0)
+        };
+
+        let by_sku = validate(row("mxa920w-s", "MXA920"), 0.85);
+        assert_eq!(by_sku.match_type, Some(MatchType::UpdateSku));
+        assert_eq!(by_sku.existing_equipment_id.as_deref(), Some("eq-1"));
+        assert_eq!(by_sku.match_score, Some(1.0));
+
+        let fuzzy = validate(row("MXA920-NEW", "MXA920"), 0.85);
+        assert_eq!(fuzzy.match_type, Some(MatchType::UpdateFallback));
+        assert_eq!(fuzzy.existing_equipment_id.as_deref(), Some("eq-1"));
+        assert!(fuzzy.match_score.unwrap() >= 0.85);
+
+        let below_threshold = validate(row("MXA920-NEW", "MXA920"), 0.95);
+        assert_eq!(below_threshold.match_type, Some(MatchType::New));
+        assert_eq!(below_threshold.existing_equipment_id, None);
+        assert_eq!(below_threshold.match_score, fuzzy.match_score);
+    }
+
+    #[test]
+    fn test_validate_row_malformed_image_url_warns() {
+        let row = ParsedRow {
+            row_number: 1,
+            cells: vec![
+                "Poly".to_string(),
+                "Studio X50".to_string(),
+                "2200-86260-001".to_string(),
+                "2500.00".to_string(),
+                "htp:/poly com/x50.png".to_string(),
+            ],
+            typed_cells: None,
+        };
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::ImageUrl,
+        ]);
+
+        let result = validate_single_row(
+            &row,
+            &mappings,
+            DecimalSeparator::Point,
+            &ValidationProfile::default(),
+        );
+
+        assert_eq!(result.status, ValidationStatus::Valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("Invalid image URL"));
+    }
+
+    #[test]
+    fn test_validate_row_valid_or_empty_image_url_passes() {
+        let mappings = create_test_mappings(&[
+            EquipmentField::Manufacturer,
+            EquipmentField::Model,
+            EquipmentField::Sku,
+            EquipmentField::Cost,
+            EquipmentField::ImageUrl,
+        ]);
+
+        for url in ["https://images.poly.com/studio-x50.png?size=large", ""] {
+            let row = ParsedRow {
+                row_number: 1,
+                cells: vec![
+                    "Poly".to_string(),
+                    "Studio X50".to_string(),
+                    "2200-86260-001".to_string(),
+                    "2500.00".to_string(),
+                    url.to_string(),
+                ],
+                typed_cells: None,
+            };
+
+            let result = validate_single_row(
+                &row,
+                &mappings,
+                DecimalSeparator::Point,
+                &ValidationProfile::default(),
+            );
+            assert!(result.warnings.is_empty(), "{}", url);
+        }
+    }
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|c| c.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_header_row_first_row() {
+        let raw = rows(&[
+            &["Manufacturer", "Model", "Cost"],
+            &["Poly", "Studio X50", "2500.00"],
+        ]);
+        assert_eq!(detect_header_row(&raw), 0);
+    }
+
+    #[test]
+    fn test_detect_header_row_below_title() {
+        let raw = rows(&[
+            &["Poly Dealer Price List 2024", "", ""],
+            &["", "", ""],
+            &["Manufacturer", "Model", "Cost"],
+            &["Poly", "Studio X50", "2500.00"],
+            &["Poly", "Studio X30", "1500.00"],
+        ]);
+        assert_eq!(detect_header_row(&raw), 2);
+    }
+
+    #[test]
+    fn test_detect_header_row_ignores_numeric_rows() {
+        let raw = rows(&[
+            &["1", "2", "3"],
+            &["Model", "Qty", "Cost"],
+            &["X50", "1", "2500"],
+        ]);
+        assert_eq!(detect_header_row(&raw), 1);
+    }
+
+    #[test]
+    fn test_detect_header_row_keeps_header_with_blank_column() {
+        let raw = rows(&[
+            &["Manufacturer", "Model", "SKU", "Category", ""],
+            &["Shure", "MXA920", "MXA920W-S", "Audio", "Discontinued"],
+            &["Shure", "MXA710", "MXA710W-4FT", "Audio", "Active"],
+        ]);
+        assert_eq!(detect_header_row(&raw), 0);
+    }
+
+    #[test]
+    fn test_split_header_row_override() {
+        let raw = rows(&[
+            &["Manufacturer", "Model"],
+            &["Vendor", "Part"],
+            &["Poly", "Studio X50"],
+        ]);
+        let options = ParseOptions {
+            header_row_index: Some(1),
+            ..Default::default()
+        };
+
+        let split = split_header_row(raw.clone().into_iter(), &options, TrimMode::All).unwrap();
+        assert_eq!(split.header_index, 1);
+        assert_eq!(split.headers, vec!["Vendor", "Part"]);
+        assert_eq!(split.rows.len(), 1);
+        assert_eq!(split.rows[0].row_number, 3);
+
+        let options = ParseOptions {
+            header_row_index: Some(5),
+            ..Default::default()
+        };
+        assert!(matches!(
+            split_header_row(raw.into_iter(), &options, TrimMode::All),
+            Err(ImportError::ParseError(_))
+        ));
+    }
 }
@@ -0,0 +1,459 @@
+//! PDF Parser
+//!
+//! Extracts price-list tables from text-based PDFs using the lopdf crate.
+//! Text runs are grouped into lines by baseline and split into cells by
+//! position. Lines with two or more cells form the table, whose first line
+//! is taken as the header unless `ParseOptions::header_row_index` picks
+//! another one.
+
+use super::parser::{FileType, ImportError, ParseOptions, ParsedFile, ParsedRow, Parser, TrimMode};
+use lopdf::content::Content;
+use lopdf::{Document, Object};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Runs whose baselines differ by at most this many points share a line
+const LINE_TOLERANCE: f64 = 2.0;
+
+/// Cells may start this many points left of their header and still belong to it
+const COLUMN_TOLERANCE: f64 = 4.0;
+
+/// PDF file parser
+pub struct PdfParser;
+
+/// A piece of text drawn at a position on a page
+#[derive(Debug)]
+struct TextRun {
+    page: u32,
+    x: f64,
+    y: f64,
+    text: String,
+}
+
+/// A cell on a line, positioned when it starts a text run
+#[derive(Debug)]
+struct Cell {
+    x: Option<f64>,
+    text: String,
+}
+
+impl Parser for PdfParser {
+    fn parse_with_options(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown.pdf")
+            .to_string();
+
+        if !path.exists() {
+            return Err(ImportError::FileNotFound(path.display().to_string()));
+        }
+
+        let mut document =
+            Document::load(path).map_err(|e| ImportError::ReadError(e.to_string()))?;
+
+        // Files with only an owner password open with an empty user password
+        if document.is_encrypted() && document.decrypt("").is_err() {
+            return Err(ImportError::PasswordProtected);
+        }
+
+        // Title lines, page numbers and other single-cell lines are not table rows
+        let table: Vec<Vec<Cell>> = extract_lines(&document)?
+            .into_iter()
+            .filter(|line| line.len() >= 2)
+            .collect();
+
+        let header_index = options.header_row_index.unwrap_or(0);
+        if header_index > 0 && header_index >= table.len() {
+            return Err(ImportError::ParseError(format!(
+                "Header row {} is past the end of the table ({} rows)",
+                header_index + 1,
+                table.len()
+            )));
+        }
+        let mut table = table.into_iter().skip(header_index);
+
+        let trim_mode = options.trim_mode.unwrap_or(TrimMode::All);
+        let header_cells = table.next().ok_or(ImportError::EmptyFile)?;
+        let headers: Vec<String> = header_cells
+            .iter()
+            .map(|c| trim_value(&c.text, trim_mode.trims_headers()))
+            .collect();
+
+        let data_lines: Vec<Vec<Cell>> = table.collect();
+        let total_rows = data_lines.len() + 1;
+
+        let rows: Vec<ParsedRow> = data_lines
+            .iter()
+            .take(options.row_limit())
+            .enumerate()
+            .map(|(idx, line)| ParsedRow {
+                row_number: header_index + idx + 2, // 1-indexed, after header
+                cells: align_cells(&header_cells, line)
+                    .iter()
+                    .map(|c| trim_value(c, trim_mode.trims_cells()))
+                    .collect(),
+                typed_cells: None,
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return Err(ImportError::EmptyFile);
+        }
+
+        Ok(ParsedFile {
+            file_name,
+            file_type: FileType::Pdf,
+            headers,
+            rows,
+            total_rows,
+            truncated: data_lines.len() > options.row_limit(),
+            delimiter: None,
+            encoding: None,
+        })
+    }
+}
+
+/// Trim surrounding whitespace from a value when enabled
+fn trim_value(value: &str, trim: bool) -> String {
+    if trim {
+        value.trim().to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Collect the text shown by one text-showing operator
+fn collect_text(text: &mut String, encoding: Option<&str>, operands: &[Object]) {
+    for operand in operands {
+        match operand {
+            Object::String(bytes, _) => text.push_str(&Document::decode_text(encoding, bytes)),
+            Object::Array(items) => collect_text(text, encoding, items),
+            // Large negative kerning separates words
+            Object::Integer(i) if *i < -100 => text.push(' '),
+            Object::Real(r) if *r < -100.0 => text.push(' '),
+            _ => {}
+        }
+    }
+}
+
+/// Walk every page's content stream and record where text is drawn
+fn extract_runs(document: &Document) -> Result<Vec<TextRun>, ImportError> {
+    let mut runs: Vec<TextRun> = Vec::new();
+
+    for (page, page_id) in document.get_pages() {
+        let encodings: BTreeMap<Vec<u8>, &str> = document
+            .get_page_fonts(page_id)
+            .into_iter()
+            .map(|(name, font)| (name, font.get_font_encoding()))
+            .collect();
+        let content = document
+            .get_page_content(page_id)
+            .and_then(|data| Content::decode(&data))
+            .map_err(|e| ImportError::ParseError(e.to_string()))?;
+
+        let mut encoding: Option<&str> = None;
+        let (mut x, mut y, mut leading) = (0.0, 0.0, 0.0);
+
+        for operation in &content.operations {
+            let number = |i: usize| {
+                operation
+                    .operands
+                    .get(i)
+                    .and_then(|o| o.as_float().ok())
+                    .map(f64::from)
+            };
+
+            match operation.operator.as_str() {
+                "BT" => (x, y) = (0.0, 0.0),
+                "Tf" => {
+                    encoding = operation
+                        .operands
+                        .first()
+                        .and_then(|o| o.as_name().ok())
+                        .and_then(|name| encodings.get(name).copied());
+                }
+                "TL" => leading = number(0).unwrap_or(leading),
+                "Td" | "TD" => {
+                    let (tx, ty) = (number(0).unwrap_or(0.0), number(1).unwrap_or(0.0));
+                    x += tx;
+                    y += ty;
+                    if operation.operator == "TD" {
+                        leading = -ty;
+                    }
+                }
+                "Tm" => (x, y) = (number(4).unwrap_or(0.0), number(5).unwrap_or(0.0)),
+                "T*" => y -= leading,
+                "Tj" | "TJ" | "'" | "\"" => {
+                    if matches!(operation.operator.as_str(), "'" | "\"") {
+                        y -= leading;
+                    }
+                    // The " operator's first two operands are spacing values
+                    let operands = if operation.operator == "\"" {
+                        operation.operands.get(2..).unwrap_or_default()
+                    } else {
+                        &operation.operands[..]
+                    };
+
+                    let mut text = String::new();
+                    collect_text(&mut text, encoding, operands);
+
+                    // Consecutive shows without a move continue the same run
+                    match runs.last_mut() {
+                        Some(last) if last.page == page && last.x == x && last.y == y => {
+                            last.text.push_str(&text);
+                        }
+                        _ => runs.push(TextRun { page, x, y, text }),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(runs)
+}
+
+/// Group text runs into lines of cells, top to bottom in page order
+fn extract_lines(document: &Document) -> Result<Vec<Vec<Cell>>, ImportError> {
+    let mut runs = extract_runs(document)?;
+    runs.retain(|r| !r.text.trim().is_empty());
+    runs.sort_by(|a, b| {
+        a.page
+            .cmp(&b.page)
+            .then_with(|| b.y.total_cmp(&a.y))
+            .then_with(|| a.x.total_cmp(&b.x))
+    });
+
+    let mut lines: Vec<(u32, f64, Vec<&TextRun>)> = Vec::new();
+    for run in &runs {
+        match lines.last_mut() {
+            Some((page, y, line)) if *page == run.page && (*y - run.y).abs() <= LINE_TOLERANCE => {
+                line.push(run);
+            }
+            _ => lines.push((run.page, run.y, vec![run])),
+        }
+    }
+
+    Ok(lines
+        .into_iter()
+        .map(|(_, _, mut line)| {
+            line.sort_by(|a, b| a.x.total_cmp(&b.x));
+            line.into_iter().flat_map(split_run).collect()
+        })
+        .collect())
+}
+
+/// Split a run on wide gaps, since some generators draw a whole row as one string
+fn split_run(run: &TextRun) -> Vec<Cell> {
+    let mut cells: Vec<Cell> = Vec::new();
+    let mut current = String::new();
+    let mut spaces = 0;
+
+    for c in run.text.chars() {
+        if c == '\t' || (c == ' ' && spaces >= 1) {
+            if !current.trim().is_empty() {
+                let x = if cells.is_empty() { Some(run.x) } else { None };
+                cells.push(Cell {
+                    x,
+                    text: current.trim_end().to_string(),
+                });
+            }
+            current.clear();
+            spaces = 0;
+        } else {
+            spaces = if c == ' ' { spaces + 1 } else { 0 };
+            current.push(c);
+        }
+    }
+    if !current.trim().is_empty() {
+        let x = if cells.is_empty() { Some(run.x) } else { None };
+        cells.push(Cell { x, text: current });
+    }
+
+    cells
+}
+
+/// Place a line's cells under the header columns
+///
+/// Lines with one cell per header are taken in order. Otherwise each
+/// positioned cell goes under the rightmost header starting at or before
+/// it, and unpositioned cells follow the previous cell's column.
+fn align_cells(header: &[Cell], line: &[Cell]) -> Vec<String> {
+    if line.len() == header.len() {
+        return line.iter().map(|c| c.text.clone()).collect();
+    }
+
+    let mut cells = vec![String::new(); header.len()];
+    let mut column: Option<usize> = None;
+
+    for cell in line {
+        let next = column.map_or(0, |c| c + 1);
+        let index = match cell.x {
+            Some(x) => header
+                .iter()
+                .rposition(|h| h.x.is_some_and(|hx| hx <= x + COLUMN_TOLERANCE))
+                .unwrap_or(next),
+            None => next,
+        }
+        .min(header.len() - 1);
+
+        if !cells[index].is_empty() {
+            cells[index].push(' ');
+        }
+        cells[index].push_str(&cell.text);
+        column = Some(index);
+    }
+
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::parser::PREVIEW_ROWS;
+    use printpdf::{BuiltinFont, Mm, PdfDocument};
+    use std::fs::File;
+    use std::io::BufWriter;
+    use tempfile::TempDir;
+
+    /// Write a one-page PDF with each (text, x mm, y mm) drawn separately
+    fn create_test_pdf(dir: &TempDir, texts: &[(&str, f32, f32)]) -> std::path::PathBuf {
+        let path = dir.path().join("price-list.pdf");
+        let (doc, page, layer) = PdfDocument::new("Price List", Mm(210.0), Mm(297.0), "Layer 1");
+        let font = doc.add_builtin_font(BuiltinFont::Helvetica).unwrap();
+        let layer = doc.get_page(page).get_layer(layer);
+        for (text, x, y) in texts {
+            layer.use_text(*text, 10.0, Mm(*x), Mm(*y), &font);
+        }
+        doc.save(&mut BufWriter::new(File::create(&path).unwrap()))
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_price_list_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = create_test_pdf(
+            &dir,
+            &[
+                ("Acme AV Price List 2026", 10.0, 280.0),
+                ("Manufacturer", 10.0, 260.0),
+                ("Model", 60.0, 260.0),
+                ("Cost", 120.0, 260.0),
+                ("Poly", 10.0, 250.0),
+                ("Studio X50", 60.0, 250.0),
+                ("2500.00", 120.0, 250.0),
+                ("Shure", 10.0, 240.0),
+                ("1200.00", 120.0, 240.0),
+                ("Page 1", 100.0, 10.0),
+            ],
+        );
+
+        let parsed = PdfParser::parse(&path).unwrap();
+
+        assert_eq!(parsed.file_type, FileType::Pdf);
+        assert_eq!(parsed.headers, vec!["Manufacturer", "Model", "Cost"]);
+        assert_eq!(parsed.rows.len(), 2);
+        assert_eq!(parsed.rows[0].cells, vec!["Poly", "Studio X50", "2500.00"]);
+        // Missing model leaves its column blank
+        assert_eq!(parsed.rows[1].cells, vec!["Shure", "", "1200.00"]);
+        assert_eq!(parsed.rows[1].row_number, 3);
+    }
+
+    #[test]
+    fn test_wide_gaps_split_cells() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = create_test_pdf(
+            &dir,
+            &[
+                ("SKU    Description    MSRP", 10.0, 260.0),
+                ("ABC123    Ceiling mic    899.00", 10.0, 250.0),
+            ],
+        );
+
+        let parsed = PdfParser::parse(&path).unwrap();
+
+        assert_eq!(parsed.headers, vec!["SKU", "Description", "MSRP"]);
+        assert_eq!(
+            parsed.rows[0].cells,
+            vec!["ABC123", "Ceiling mic", "899.00"]
+        );
+    }
+
+    #[test]
+    fn test_parse_pdf_preview_keeps_first_rows_and_counts_all() {
+        let dir = tempfile::tempdir().unwrap();
+        let models: Vec<String> = (0..PREVIEW_ROWS + 10)
+            .map(|i| format!("Model {}", i))
+            .collect();
+        let mut texts = vec![("Manufacturer", 10.0, 290.0), ("Model", 60.0, 290.0)];
+        for (i, model) in models.iter().enumerate() {
+            let y = 287.0 - i as f32 * 2.5;
+            texts.push(("Poly", 10.0, y));
+            texts.push((model.as_str(), 60.0, y));
+        }
+        let path = create_test_pdf(&dir, &texts);
+        let options = ParseOptions {
+            preview: true,
+            ..Default::default()
+        };
+
+        let preview = PdfParser::parse_with_options(&path, &options).unwrap();
+        assert_eq!(preview.rows.len(), PREVIEW_ROWS);
+        assert_eq!(preview.total_rows, PREVIEW_ROWS + 11);
+        assert!(preview.truncated);
+
+        let full = PdfParser::parse(&path).unwrap();
+        assert_eq!(full.rows.len(), PREVIEW_ROWS + 10);
+        assert!(!full.truncated);
+    }
+
+    #[test]
+    fn test_parse_pdf_header_row_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = create_test_pdf(
+            &dir,
+            &[
+                ("Vendor", 10.0, 270.0),
+                ("Part", 60.0, 270.0),
+                ("Manufacturer", 10.0, 260.0),
+                ("Model", 60.0, 260.0),
+                ("Poly", 10.0, 250.0),
+                ("Studio X50", 60.0, 250.0),
+            ],
+        );
+        let options = ParseOptions {
+            header_row_index: Some(1),
+            ..Default::default()
+        };
+
+        let parsed = PdfParser::parse_with_options(&path, &options).unwrap();
+        assert_eq!(parsed.headers, vec!["Manufacturer", "Model"]);
+        assert_eq!(parsed.rows.len(), 1);
+        assert_eq!(parsed.rows[0].row_number, 3);
+
+        let options = ParseOptions {
+            header_row_index: Some(5),
+            ..Default::default()
+        };
+        let result = PdfParser::parse_with_options(&path, &options);
+        assert!(matches!(result, Err(ImportError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_pdf_without_table_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = create_test_pdf(&dir, &[("Just a cover page", 10.0, 260.0)]);
+
+        let result = PdfParser::parse(&path);
+
+        assert!(matches!(result, Err(ImportError::EmptyFile)));
+    }
+
+    #[test]
+    fn test_parse_nonexistent_pdf() {
+        let result = PdfParser::parse(Path::new("/nonexistent/prices.pdf"));
+        assert!(matches!(result, Err(ImportError::FileNotFound(_))));
+    }
+}
@@ -0,0 +1,175 @@
+//! Parser Registry
+//!
+//! Maps file extensions to the parser that reads them, so new import formats
+//! are added by registering a parser rather than editing the file dispatch.
+
+use super::csv_parser::CsvParser;
+use super::excel::ExcelParser;
+use super::parser::{ImportError, ParseOptions, ParsedFile, Parser};
+use super::pdf_parser::PdfParser;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{OnceLock, RwLock};
+
+/// A parser that can be stored in the registry behind a `Box`
+///
+/// Implemented for every `Parser`, so registering a new format only needs a
+/// `Parser` implementation.
+pub trait FileParser: Send + Sync {
+    /// Parse a file with the given options
+    fn parse_file(&self, path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError>;
+}
+
+impl<P: Parser + Send + Sync> FileParser for P {
+    fn parse_file(&self, path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
+        P::parse_with_options(path, options)
+    }
+}
+
+/// File extensions and the parsers registered for them
+pub struct ParserRegistry {
+    parsers: HashMap<String, Box<dyn FileParser>>,
+}
+
+impl ParserRegistry {
+    /// A registry with no parsers
+    pub fn empty() -> Self {
+        Self {
+            parsers: HashMap::new(),
+        }
+    }
+
+    /// Register a parser for an extension, replacing any existing one
+    ///
+    /// Extensions match case-insensitively and may include the leading dot.
+    pub fn register(&mut self, extension: &str, parser: Box<dyn FileParser>) {
+        self.parsers.insert(normalize_extension(extension), parser);
+    }
+
+    /// Whether a parser is registered for the extension
+    pub fn supports(&self, extension: &str) -> bool {
+        self.parsers.contains_key(&normalize_extension(extension))
+    }
+
+    /// Parse a file with the parser registered for its extension
+    pub fn parse(&self, path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(normalize_extension)
+            .unwrap_or_default();
+
+        match self.parsers.get(&extension) {
+            Some(parser) => parser.parse_file(path, options),
+            None => Err(ImportError::UnsupportedFormat(format!(
+                "Unsupported file format: .{}",
+                extension
+            ))),
+        }
+    }
+}
+
+impl Default for ParserRegistry {
+    /// Excel, OpenDocument, CSV and PDF parsers
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register("xlsx", Box::new(ExcelParser));
+        registry.register("xls", Box::new(ExcelParser));
+        registry.register("ods", Box::new(ExcelParser));
+        registry.register("csv", Box::new(CsvParser));
+        registry.register("pdf", Box::new(PdfParser));
+        registry
+    }
+}
+
+fn normalize_extension(extension: &str) -> String {
+    extension.trim_start_matches('.').to_lowercase()
+}
+
+// ============================================================================
+// App-wide Registry
+// ============================================================================
+
+/// Registry used by the import commands, created with the default parsers
+fn registry() -> &'static RwLock<ParserRegistry> {
+    static REGISTRY: OnceLock<RwLock<ParserRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(ParserRegistry::default()))
+}
+
+/// Register a parser for an extension with the import commands
+pub fn register_parser(extension: &str, parser: Box<dyn FileParser>) {
+    if let Ok(mut registry) = registry().write() {
+        registry.register(extension, parser);
+    }
+}
+
+/// Parse a file with the parser registered for its extension
+pub fn parse_file(path: &Path, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
+    registry()
+        .read()
+        .map_err(|_| ImportError::ReadError("Parser registry is unavailable".to_string()))?
+        .parse(path, options)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::parser::FileType;
+
+    /// Parser that reports a fixed file name instead of reading the file
+    struct StubParser;
+
+    impl Parser for StubParser {
+        fn parse_with_options(
+            _path: &Path,
+            _options: &ParseOptions,
+        ) -> Result<ParsedFile, ImportError> {
+            Ok(ParsedFile {
+                file_name: "stub".to_string(),
+                file_type: FileType::Csv,
+                headers: Vec::new(),
+                rows: Vec::new(),
+                total_rows: 0,
+                truncated: false,
+                delimiter: None,
+                encoding: None,
+            })
+        }
+    }
+
+    #[test]
+    fn test_default_registry_extensions() {
+        let registry = ParserRegistry::default();
+
+        for extension in ["xlsx", "xls", "ods", "csv", "pdf", ".CSV"] {
+            assert!(registry.supports(extension), "{}", extension);
+        }
+        assert!(!registry.supports("numbers"));
+    }
+
+    #[test]
+    fn test_unknown_extension_unsupported() {
+        let result = ParserRegistry::default()
+            .parse(Path::new("/test/file.numbers"), &ParseOptions::default());
+
+        assert!(
+            matches!(result, Err(ImportError::UnsupportedFormat(msg)) if msg.contains("numbers"))
+        );
+    }
+
+    #[test]
+    fn test_registered_parser_used() {
+        let mut registry = ParserRegistry::empty();
+        registry.register(".NUMBERS", Box::new(StubParser));
+
+        let parsed = registry
+            .parse(Path::new("/test/file.numbers"), &ParseOptions::default())
+            .unwrap();
+
+        assert_eq!(parsed.file_name, "stub");
+    }
+}
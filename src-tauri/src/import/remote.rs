@@ -0,0 +1,348 @@
+//! Remote File Import
+//!
+//! Downloads a pricing sheet from an http(s) URL, such as a Google Sheet
+//! published as CSV, and parses it with the same parsers as local files.
+
+use super::parser::{FileType, ImportError, ParseOptions, ParsedFile};
+use super::registry;
+use std::io::Read;
+use std::time::Duration;
+
+/// Largest file accepted from a URL, so a huge download cannot exhaust memory
+pub const MAX_DOWNLOAD_BYTES: u64 = 20 * 1024 * 1024;
+
+/// How long to wait for the whole download before giving up
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A downloaded file and the content type the server reported for it
+struct Download {
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+}
+
+/// First bytes of a legacy .xls workbook (an OLE compound file)
+const OLE_SIGNATURE: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// First bytes of a ZIP archive, the container of .xlsx and .ods workbooks
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// Media type stored uncompressed as the first entry of an .ods archive
+const ODS_MIME: &[u8] = b"application/vnd.oasis.opendocument.spreadsheet";
+
+/// Infers the file extension from the response content type, falling back
+/// to the URL's file extension or Google Sheets' `output=` export parameter
+fn detect_extension(content_type: Option<&str>, url: &str) -> Option<&'static str> {
+    let mime = content_type
+        .and_then(|c| c.split(';').next())
+        .map(|c| c.trim().to_lowercase());
+    match mime.as_deref() {
+        Some("text/csv") => return Some("csv"),
+        Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet") => {
+            return Some("xlsx")
+        }
+        Some("application/vnd.ms-excel") => return Some("xls"),
+        Some("application/vnd.oasis.opendocument.spreadsheet") => return Some("ods"),
+        _ => {}
+    }
+
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let path = path.split('#').next().unwrap_or(path);
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase());
+    let output = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("output="))
+        .map(str::to_lowercase);
+
+    match extension.or(output).as_deref() {
+        Some("csv") => Some("csv"),
+        Some("xlsx") => Some("xlsx"),
+        Some("xls") => Some("xls"),
+        Some("ods") => Some("ods"),
+        _ => None,
+    }
+}
+
+/// Corrects a workbook extension from the file's signature
+///
+/// Servers often label both Excel formats `application/vnd.ms-excel`, and
+/// the workbook reader is chosen by extension, so the container decides:
+/// an OLE file is .xls, a ZIP whose first entry declares the OpenDocument
+/// spreadsheet type is .ods, and any other ZIP is .xlsx. CSV files and
+/// unrecognized bytes keep the detected extension.
+fn workbook_extension(bytes: &[u8], detected: &'static str) -> &'static str {
+    if detected == "csv" {
+        return detected;
+    }
+    if bytes.starts_with(OLE_SIGNATURE) {
+        return "xls";
+    }
+    if bytes.starts_with(ZIP_SIGNATURE) {
+        // The first entry's name starts at byte 30 of the archive
+        let head = &bytes[..bytes.len().min(128)];
+        let is_ods = head.get(30..38) == Some(b"mimetype".as_slice())
+            && head.windows(ODS_MIME.len()).any(|w| w == ODS_MIME);
+        return if is_ods { "ods" } else { "xlsx" };
+    }
+    detected
+}
+
+/// File name shown for a URL import: the last path segment, or the host
+fn url_file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let path = path.split_once("://").map_or(path, |(_, rest)| rest);
+    path.rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// Fetches a URL, rejecting non-200 responses and bodies over `max_bytes`
+fn download(url: &str, max_bytes: u64) -> Result<Download, ImportError> {
+    let agent = ureq::AgentBuilder::new().timeout(DOWNLOAD_TIMEOUT).build();
+    let response = agent.get(url).call().map_err(|e| match e {
+        ureq::Error::Status(status, _) => {
+            ImportError::ReadError(format!("{} returned HTTP {}", url, status))
+        }
+        ureq::Error::Transport(transport) => ImportError::ReadError(transport.to_string()),
+    })?;
+
+    if response.status() != 200 {
+        return Err(ImportError::ReadError(format!(
+            "{} returned HTTP {}",
+            url,
+            response.status()
+        )));
+    }
+
+    let too_large =
+        || ImportError::ReadError(format!("Download exceeds the {} byte limit", max_bytes));
+    let declared_length = response
+        .header("Content-Length")
+        .and_then(|l| l.parse::<u64>().ok());
+    if declared_length.is_some_and(|length| length > max_bytes) {
+        return Err(too_large());
+    }
+
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(max_bytes + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| ImportError::ReadError(e.to_string()))?;
+    if bytes.len() as u64 > max_bytes {
+        return Err(too_large());
+    }
+
+    Ok(Download {
+        bytes,
+        content_type,
+    })
+}
+
+/// Parses downloaded bytes by writing them to a temporary file with the
+/// detected extension for the registered parser
+fn parse_download(
+    download: &Download,
+    extension: &str,
+    options: &ParseOptions,
+) -> Result<ParsedFile, ImportError> {
+    let unique = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    let path = std::env::temp_dir().join(format!(
+        "av-designer-import-{}-{}.{}",
+        std::process::id(),
+        unique,
+        extension
+    ));
+
+    std::fs::write(&path, &download.bytes).map_err(|e| ImportError::WriteError(e.to_string()))?;
+    let parsed = registry::parse_file(&path, options);
+    let _ = std::fs::remove_file(&path);
+    parsed
+}
+
+/// Downloads a CSV, Excel or OpenDocument file from an http(s) URL and parses it
+///
+/// The file type comes from the response's content type, or from the URL
+/// when the server sends a generic one. The parsed file is named after the
+/// last segment of the URL path.
+pub fn parse_url(url: &str, options: &ParseOptions) -> Result<ParsedFile, ImportError> {
+    parse_url_with_limit(url, options, MAX_DOWNLOAD_BYTES)
+}
+
+fn parse_url_with_limit(
+    url: &str,
+    options: &ParseOptions,
+    max_bytes: u64,
+) -> Result<ParsedFile, ImportError> {
+    let scheme = url.split_once("://").map(|(s, _)| s.to_lowercase());
+    if !matches!(scheme.as_deref(), Some("http") | Some("https")) {
+        return Err(ImportError::UnsupportedFormat(format!(
+            "Only http and https URLs can be imported: {}",
+            url
+        )));
+    }
+
+    let download = download(url, max_bytes)?;
+    let extension = detect_extension(download.content_type.as_deref(), url).ok_or_else(|| {
+        ImportError::UnsupportedFormat(format!(
+            "Could not tell whether {} is a CSV or spreadsheet file",
+            url
+        ))
+    })?;
+    let extension = workbook_extension(&download.bytes, extension);
+
+    let mut parsed = parse_download(&download, extension, options)?;
+    parsed.file_name = url_file_name(url);
+    Ok(parsed)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::net::TcpListener;
+
+    /// Serves one canned HTTP response on a local port, returning its URL
+    fn serve_once(status: &str, content_type: &str, body: impl AsRef<[u8]>, path: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}{}", listener.local_addr().unwrap(), path);
+        let body = body.as_ref();
+        let mut response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            content_type,
+            body.len()
+        )
+        .into_bytes();
+        response.extend_from_slice(body);
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            stream.write_all(&response).unwrap();
+        });
+
+        url
+    }
+
+    fn fixture_path(name: &str) -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_detect_extension() {
+        let url = "https://example.com/catalog";
+        assert_eq!(
+            detect_extension(Some("text/csv; charset=utf-8"), url),
+            Some("csv")
+        );
+        assert_eq!(
+            detect_extension(
+                Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+                url
+            ),
+            Some("xlsx")
+        );
+        assert_eq!(
+            detect_extension(Some("application/vnd.ms-excel"), url),
+            Some("xls")
+        );
+        assert_eq!(
+            detect_extension(
+                Some("application/octet-stream"),
+                "https://example.com/a.XLSX?v=2"
+            ),
+            Some("xlsx")
+        );
+        assert_eq!(
+            detect_extension(None, "https://example.com/legacy.xls"),
+            Some("xls")
+        );
+        assert_eq!(
+            detect_extension(
+                None,
+                "https://docs.google.com/spreadsheets/d/e/abc/pub?gid=0&output=csv"
+            ),
+            Some("csv")
+        );
+        assert_eq!(detect_extension(Some("text/html"), url), None);
+    }
+
+    #[test]
+    fn test_workbook_extension_from_signature() {
+        let mut ole = OLE_SIGNATURE.to_vec();
+        ole.extend_from_slice(&[0; 8]);
+        assert_eq!(workbook_extension(&ole, "xlsx"), "xls");
+        assert_eq!(workbook_extension(b"PK\x03\x04rest", "xls"), "xlsx");
+        assert_eq!(workbook_extension(b"a,b\n1,2\n", "csv"), "csv");
+        assert_eq!(workbook_extension(b"unknown", "xls"), "xls");
+
+        let ods = std::fs::read(fixture_path("price_list.ods")).unwrap();
+        assert_eq!(workbook_extension(&ods, "xls"), "ods");
+    }
+
+    #[test]
+    fn test_parse_url_workbook_labeled_as_xls() {
+        let ods = std::fs::read(fixture_path("price_list.ods")).unwrap();
+        let url = serve_once("200 OK", "application/vnd.ms-excel", ods, "/price_list.xls");
+
+        let parsed = parse_url(&url, &ParseOptions::default()).unwrap();
+
+        assert_eq!(parsed.file_type, FileType::Ods);
+        assert_eq!(parsed.file_name, "price_list.xls");
+        assert!(!parsed.rows.is_empty());
+    }
+
+    #[test]
+    fn test_parse_url_csv() {
+        let url = serve_once(
+            "200 OK",
+            "text/csv",
+            "Manufacturer,Model,Cost\nPoly,Studio X50,2499\n",
+            "/pub?output=csv",
+        );
+
+        let parsed = parse_url(&url, &ParseOptions::default()).unwrap();
+
+        assert_eq!(parsed.file_type, FileType::Csv);
+        assert_eq!(parsed.file_name, "pub");
+        assert_eq!(parsed.headers, vec!["Manufacturer", "Model", "Cost"]);
+        assert_eq!(parsed.rows[0].cells, vec!["Poly", "Studio X50", "2499"]);
+    }
+
+    #[test]
+    fn test_parse_url_not_found_is_read_error() {
+        let url = serve_once("404 Not Found", "text/html", "missing", "/catalog.csv");
+
+        let result = parse_url(&url, &ParseOptions::default());
+
+        assert!(matches!(result, Err(ImportError::ReadError(msg)) if msg.contains("404")));
+    }
+
+    #[test]
+    fn test_parse_url_over_size_limit_rejected() {
+        let url = serve_once("200 OK", "text/csv", "a,b\n1,2\n", "/catalog.csv");
+
+        let result = parse_url_with_limit(&url, &ParseOptions::default(), 4);
+
+        assert!(matches!(result, Err(ImportError::ReadError(msg)) if msg.contains("limit")));
+    }
+
+    #[test]
+    fn test_parse_url_rejects_other_schemes() {
+        let result = parse_url("file:///etc/passwd", &ParseOptions::default());
+        assert!(matches!(result, Err(ImportError::UnsupportedFormat(_))));
+    }
+}
@@ -0,0 +1,130 @@
+//! Column Statistics
+//!
+//! Reports how well each column of a parsed file is filled in, so users
+//! can spot mostly-empty columns that are not worth mapping.
+
+use super::parser::ParsedFile;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of distinct sample values reported per column
+const MAX_SAMPLE_VALUES: usize = 5;
+
+/// Fill-rate statistics for one source column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnStat {
+    /// Index of the source column
+    pub column_index: usize,
+    /// Header name, empty when the file has no header for this column
+    pub header: String,
+    /// Number of rows with a non-blank value in this column
+    pub non_empty_count: usize,
+    /// Share of rows with a value, from 0 to 100
+    pub fill_percentage: f64,
+    /// First few distinct values, in the order they appear
+    pub sample_values: Vec<String>,
+}
+
+/// Compute fill-rate statistics for every column of a parsed file
+///
+/// Columns span the header row and the widest data row. Cells containing
+/// only whitespace count as empty.
+pub fn column_stats(parsed: &ParsedFile) -> Vec<ColumnStat> {
+    let column_count = parsed
+        .rows
+        .iter()
+        .map(|row| row.cells.len())
+        .chain(std::iter::once(parsed.headers.len()))
+        .max()
+        .unwrap_or_default();
+
+    (0..column_count)
+        .map(|column_index| {
+            let mut non_empty_count = 0;
+            let mut sample_values: Vec<String> = Vec::new();
+
+            for value in parsed
+                .rows
+                .iter()
+                .filter_map(|row| row.cells.get(column_index))
+                .map(|cell| cell.trim())
+                .filter(|cell| !cell.is_empty())
+            {
+                non_empty_count += 1;
+                if sample_values.len() < MAX_SAMPLE_VALUES
+                    && !sample_values.iter().any(|s| s == value)
+                {
+                    sample_values.push(value.to_string());
+                }
+            }
+
+            let fill_percentage = if parsed.rows.is_empty() {
+                0.0
+            } else {
+                non_empty_count as f64 / parsed.rows.len() as f64 * 100.0
+            };
+
+            ColumnStat {
+                column_index,
+                header: parsed
+                    .headers
+                    .get(column_index)
+                    .cloned()
+                    .unwrap_or_default(),
+                non_empty_count,
+                fill_percentage,
+                sample_values,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::csv_parser::CsvParser;
+    use crate::import::parser::Parser;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn parse_test_csv(content: &str) -> ParsedFile {
+        let mut file = NamedTempFile::with_suffix(".csv").unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file.flush().unwrap();
+        CsvParser::parse(file.path()).unwrap()
+    }
+
+    #[test]
+    fn test_full_and_sparse_columns() {
+        let parsed = parse_test_csv(
+            "Manufacturer,Model,Notes\n\
+             Poly,Studio X50,Wall mount\n\
+             Poly,Studio X70,\n\
+             Shure,MXA920,\n\
+             Crestron,DMPS3,\n",
+        );
+
+        let stats = column_stats(&parsed);
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0].header, "Manufacturer");
+        assert_eq!(stats[0].non_empty_count, 4);
+        assert_eq!(stats[0].fill_percentage, 100.0);
+        assert_eq!(stats[0].sample_values, vec!["Poly", "Shure", "Crestron"]);
+
+        assert_eq!(stats[2].header, "Notes");
+        assert_eq!(stats[2].non_empty_count, 1);
+        assert_eq!(stats[2].fill_percentage, 25.0);
+        assert_eq!(stats[2].sample_values, vec!["Wall mount"]);
+    }
+
+    #[test]
+    fn test_sample_values_capped() {
+        let parsed = parse_test_csv("SKU\nA\nB\nC\nD\nE\nF\nG\n");
+
+        let stats = column_stats(&parsed);
+
+        assert_eq!(stats[0].sample_values.len(), MAX_SAMPLE_VALUES);
+        assert_eq!(stats[0].non_empty_count, 7);
+    }
+}
@@ -0,0 +1,133 @@
+//! Import Template
+//!
+//! Writes a blank pricing sheet in our canonical column layout so vendors
+//! can send data that maps cleanly in the import wizard.
+
+use super::parser::{EquipmentField, ImportError};
+use csv::Writer;
+use rust_xlsxwriter::Workbook;
+use std::path::Path;
+
+/// Example values written beneath the header row, one per field
+fn example_value(field: EquipmentField) -> &'static str {
+    match field {
+        EquipmentField::Manufacturer => "Poly",
+        EquipmentField::Model => "Studio X50",
+        EquipmentField::Sku => "2200-86260-001",
+        EquipmentField::Category => "video",
+        EquipmentField::Subcategory => "codecs",
+        EquipmentField::Description => "All-in-one video bar",
+        EquipmentField::Cost => "2500.00",
+        EquipmentField::Msrp => "3499.00",
+        EquipmentField::Height => "3.5",
+        EquipmentField::Width => "32.5",
+        EquipmentField::Depth => "4.2",
+        EquipmentField::Weight => "10.1",
+        EquipmentField::Voltage => "120",
+        EquipmentField::Wattage => "60",
+        EquipmentField::Certifications => "Zoom Rooms; Microsoft Teams",
+        EquipmentField::ImageUrl => "https://example.com/images/studio-x50.png",
+    }
+}
+
+/// Write an import template to `path` in the given format, `csv` or `xlsx`
+///
+/// The template has a single header row naming every mappable
+/// [`EquipmentField`] followed by one example row.
+pub fn write_template(path: &Path, format: &str) -> Result<(), ImportError> {
+    match format.to_lowercase().as_str() {
+        "csv" => write_csv_template(path),
+        "xlsx" => write_xlsx_template(path),
+        other => Err(ImportError::UnsupportedFormat(format!(
+            "Unsupported template format: {}",
+            other
+        ))),
+    }
+}
+
+/// Write the template as a CSV file
+fn write_csv_template(path: &Path) -> Result<(), ImportError> {
+    let mut writer = Writer::from_path(path).map_err(|e| ImportError::WriteError(e.to_string()))?;
+
+    writer
+        .write_record(EquipmentField::ALL.iter().map(|f| f.canonical_name()))
+        .map_err(|e| ImportError::WriteError(e.to_string()))?;
+    writer
+        .write_record(EquipmentField::ALL.iter().map(|f| example_value(*f)))
+        .map_err(|e| ImportError::WriteError(e.to_string()))?;
+
+    writer
+        .flush()
+        .map_err(|e| ImportError::WriteError(e.to_string()))
+}
+
+/// Write the template as a single-sheet Excel workbook
+///
+/// Numeric examples are written as numbers so the sheet reads like a
+/// vendor's own price list.
+fn write_xlsx_template(path: &Path) -> Result<(), ImportError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    for (col, field) in EquipmentField::ALL.iter().enumerate() {
+        let col = col as u16;
+        let example = example_value(*field);
+        worksheet
+            .write_string(0, col, field.canonical_name())
+            .map_err(|e| ImportError::WriteError(e.to_string()))?;
+        match example.parse::<f64>() {
+            Ok(number) => worksheet.write_number(1, col, number),
+            Err(_) => worksheet.write_string(1, col, example),
+        }
+        .map_err(|e| ImportError::WriteError(e.to_string()))?;
+    }
+
+    workbook
+        .save(path)
+        .map_err(|e| ImportError::WriteError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::csv_parser::CsvParser;
+    use crate::import::excel::ExcelParser;
+    use crate::import::parser::{detect_header_mappings, ParsedFile, Parser};
+    use tempfile::NamedTempFile;
+
+    fn assert_maps_every_field(parsed: &ParsedFile) {
+        assert_eq!(parsed.headers.len(), EquipmentField::ALL.len());
+        assert_eq!(parsed.rows.len(), 1);
+
+        let suggestions = detect_header_mappings(parsed).unwrap();
+        for (suggestion, field) in suggestions.iter().zip(EquipmentField::ALL.iter()) {
+            assert_eq!(suggestion.suggested_field, Some(*field));
+            assert!(suggestion.confidence > 0.9, "{}", suggestion.header);
+        }
+    }
+
+    #[test]
+    fn test_template_round_trips_through_detect_headers() {
+        let file = NamedTempFile::with_suffix(".csv").unwrap();
+        write_template(file.path(), "csv").unwrap();
+
+        assert_maps_every_field(&CsvParser::parse(file.path()).unwrap());
+    }
+
+    #[test]
+    fn test_xlsx_template_round_trips_through_detect_headers() {
+        let file = NamedTempFile::with_suffix(".xlsx").unwrap();
+        write_template(file.path(), "xlsx").unwrap();
+
+        let parsed = ExcelParser::parse(file.path()).unwrap();
+        assert_maps_every_field(&parsed);
+        assert_eq!(parsed.rows[0].cells[0], "Poly");
+    }
+
+    #[test]
+    fn test_template_unsupported_format() {
+        let file = NamedTempFile::with_suffix(".pdf").unwrap();
+        let result = write_template(file.path(), "pdf");
+        assert!(matches!(result, Err(ImportError::UnsupportedFormat(_))));
+    }
+}
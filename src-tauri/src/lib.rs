@@ -7,14 +7,141 @@ pub mod database;
 pub mod drawings;
 pub mod export;
 pub mod import;
+pub mod standards;
 
-use commands::{get_app_info, greet};
-use drawings::generate_electrical;
-use export::export_to_pdf;
-use import::{detect_headers, parse_import_file, validate_import_rows};
+#[cfg(debug_assertions)]
+use commands::greet;
+use commands::{
+    app_health, create_equipment, delete_equipment, get_app_info, get_equipment, list_equipment,
+    update_equipment, validate_standards,
+};
+use database::{db_connect, export_db_snapshot, import_db_snapshot, DatabaseConfig, DatabaseState};
+use drawings::{
+    calculate_heat, calculate_power, check_ada_mounting, check_mount_loads,
+    combine_rooms_for_diagram, compute_amplifier_sizing, compute_audio_zones, compute_conduit_fill,
+    compute_diagram_diff, compute_install_order, compute_project_cable_totals,
+    compute_rack_packing, compute_rack_utilization, compute_reachable_from, compute_signal_cycles,
+    compute_source_fanout, compute_standards_gap, compute_tray_route,
+    find_equipment_by_certification, find_longest_cable_run, find_orphan_equipment,
+    find_overlength_cable_runs, generate_cable_schedule, generate_connection_labels,
+    generate_electrical, generate_floor_plan_drawing, generate_rack, generate_rcp,
+    prune_connections, suggest_equipment_alternatives, suggest_equipment_placement,
+};
+use export::{
+    check_printer_compatibility, compute_connection_matrix, compute_layer_bounds,
+    diagram_to_svg_string, estimate_pdf_render_time, export_bom_estimate_csv,
+    export_connection_matrix_csv, export_drawings_to_pdf, export_dxf, export_equipment_datasheet,
+    export_graphml, export_room_summary, export_signal_legend_png, export_to_json, export_to_pdf,
+    export_to_png, export_to_svg, generate_bom, import_drawing_json, normalize_title_block_contact,
+    preview_pdf_pagination, rekey_drawing, validate_drawing_element_ids, validate_drawing_layers,
+    validate_export_config, validate_sheet_numbering,
+};
+use import::{
+    assert_import_columns, column_stats, commit_import, dedupe_import_headers, detect_headers,
+    export_import_template, import_impact, list_excel_sheets, map_import_category,
+    parse_import_file, parse_import_url, revalidate_import_rows, summarize_validation_results,
+    validate_equipment_image, validate_import_rows,
+};
+use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Commands for every build; debug builds also register the sample greet
+    macro_rules! app_handler {
+        ($($debug_only:ident),*) => {
+            tauri::generate_handler![
+                $($debug_only,)*
+                app_health,
+                get_app_info,
+                create_equipment,
+                get_equipment,
+                list_equipment,
+                update_equipment,
+                delete_equipment,
+                validate_standards,
+                db_connect,
+                export_db_snapshot,
+                import_db_snapshot,
+                generate_electrical,
+                generate_floor_plan_drawing,
+                generate_rcp,
+                generate_cable_schedule,
+                compute_install_order,
+                compute_rack_packing,
+                compute_rack_utilization,
+                generate_rack,
+                compute_tray_route,
+                compute_conduit_fill,
+                combine_rooms_for_diagram,
+                compute_audio_zones,
+                compute_amplifier_sizing,
+                calculate_power,
+                calculate_heat,
+                generate_connection_labels,
+                check_mount_loads,
+                check_ada_mounting,
+                compute_standards_gap,
+                find_orphan_equipment,
+                suggest_equipment_alternatives,
+                suggest_equipment_placement,
+                find_equipment_by_certification,
+                compute_source_fanout,
+                compute_reachable_from,
+                compute_signal_cycles,
+                compute_diagram_diff,
+                prune_connections,
+                find_longest_cable_run,
+                find_overlength_cable_runs,
+                compute_project_cable_totals,
+                export_to_pdf,
+                preview_pdf_pagination,
+                export_drawings_to_pdf,
+                normalize_title_block_contact,
+                export_room_summary,
+                export_equipment_datasheet,
+                validate_drawing_element_ids,
+                validate_drawing_layers,
+                validate_export_config,
+                validate_sheet_numbering,
+                compute_layer_bounds,
+                estimate_pdf_render_time,
+                check_printer_compatibility,
+                export_to_png,
+                export_to_svg,
+                diagram_to_svg_string,
+                export_dxf,
+                export_graphml,
+                export_to_json,
+                import_drawing_json,
+                export_signal_legend_png,
+                compute_connection_matrix,
+                export_connection_matrix_csv,
+                export_bom_estimate_csv,
+                generate_bom,
+                rekey_drawing,
+                parse_import_file,
+                parse_import_url,
+                list_excel_sheets,
+                detect_headers,
+                column_stats,
+                dedupe_import_headers,
+                import_impact,
+                validate_import_rows,
+                commit_import,
+                revalidate_import_rows,
+                summarize_validation_results,
+                export_import_template,
+                map_import_category,
+                validate_equipment_image,
+                assert_import_columns
+            ]
+        };
+    }
+    #[cfg(debug_assertions)]
+    let handler = app_handler!(greet);
+    #[cfg(not(debug_assertions))]
+    let handler = app_handler!();
+
     tauri::Builder::default()
         .setup(|app| {
             if cfg!(debug_assertions) {
@@ -24,17 +151,14 @@ pub fn run() {
                         .build(),
                 )?;
             }
+            // Keep the local database with the app's data, not in the
+            // working directory the app happened to start from
+            let data_dir = app.path().app_data_dir()?;
+            std::fs::create_dir_all(&data_dir)?;
+            app.manage(DatabaseState::new(DatabaseConfig::in_dir(&data_dir)));
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
-            greet,
-            get_app_info,
-            generate_electrical,
-            export_to_pdf,
-            parse_import_file,
-            detect_headers,
-            validate_import_rows
-        ])
+        .invoke_handler(handler)
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,193 @@
+//! Standards Module
+//!
+//! Checks a room design against AV design rules, such as every room with
+//! displays having a way to play program audio, and reports each rule the
+//! room breaks.
+
+use crate::drawings::{EquipmentCategory, EquipmentInput, RoomInput};
+use serde::{Deserialize, Serialize};
+
+/// Rule: a room with displays needs at least one audio output
+pub const RULE_DISPLAY_AUDIO_OUTPUT: &str = "display-audio-output";
+
+/// Rule: multi-display rooms should have one camera per display
+pub const RULE_CAMERA_PER_DISPLAY: &str = "camera-per-display";
+
+// ============================================================================
+// Standard Violation - a design rule the room breaks
+// ============================================================================
+
+/// How serious a violation is
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The design does not work as drawn
+    Error,
+    /// The design works but departs from recommended practice
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandardViolation {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl StandardViolation {
+    fn new(rule_id: &str, severity: Severity, message: String) -> Self {
+        Self {
+            rule_id: rule_id.to_string(),
+            severity,
+            message,
+        }
+    }
+}
+
+// ============================================================================
+// Individual Rules
+// ============================================================================
+
+/// Number of placed devices of a category and subcategory
+fn count_placed(
+    placed: &[&EquipmentInput],
+    category: EquipmentCategory,
+    subcategory: &str,
+) -> usize {
+    placed
+        .iter()
+        .filter(|e| e.category == category && e.subcategory == subcategory)
+        .count()
+}
+
+/// Flags rooms with displays but no speakers to play program audio
+fn check_display_audio_output(placed: &[&EquipmentInput], violations: &mut Vec<StandardViolation>) {
+    let displays = count_placed(placed, EquipmentCategory::Video, "displays");
+    let speakers = count_placed(placed, EquipmentCategory::Audio, "speakers");
+
+    if displays > 0 && speakers == 0 {
+        violations.push(StandardViolation::new(
+            RULE_DISPLAY_AUDIO_OUTPUT,
+            Severity::Error,
+            format!(
+                "Room has {} display(s) but no speakers for program audio",
+                displays
+            ),
+        ));
+    }
+}
+
+/// Flags dual-screen and larger rooms whose camera count differs from the
+/// display count, so each far-end view has a matching near-end camera
+fn check_camera_per_display(placed: &[&EquipmentInput], violations: &mut Vec<StandardViolation>) {
+    let displays = count_placed(placed, EquipmentCategory::Video, "displays");
+    let cameras = count_placed(placed, EquipmentCategory::Video, "cameras");
+
+    if displays >= 2 && cameras != displays {
+        violations.push(StandardViolation::new(
+            RULE_CAMERA_PER_DISPLAY,
+            Severity::Warning,
+            format!(
+                "Room has {} displays but {} camera(s); expected one camera per display",
+                displays, cameras
+            ),
+        ));
+    }
+}
+
+// ============================================================================
+// Standards Validation
+// ============================================================================
+
+/// Checks a room against every design rule, returning the rules it breaks
+///
+/// Placed equipment missing from the catalog is ignored.
+pub fn validate_room(
+    room: &RoomInput,
+    equipment_catalog: &[EquipmentInput],
+) -> Vec<StandardViolation> {
+    let placed: Vec<&EquipmentInput> = room
+        .placed_equipment
+        .iter()
+        .filter_map(|p| equipment_catalog.iter().find(|e| e.id == p.equipment_id))
+        .collect();
+
+    let mut violations = Vec::new();
+    check_display_audio_output(&placed, &mut violations);
+    check_camera_per_display(&placed, &mut violations);
+    violations
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drawings::test_support::{
+        self, create_test_equipment, create_test_placed_equipment,
+    };
+
+    fn create_test_catalog() -> Vec<EquipmentInput> {
+        [
+            ("display", EquipmentCategory::Video, "displays"),
+            ("camera", EquipmentCategory::Video, "cameras"),
+            ("speaker", EquipmentCategory::Audio, "speakers"),
+        ]
+        .into_iter()
+        .map(|(id, category, subcategory)| create_test_equipment(id, category, subcategory))
+        .collect()
+    }
+
+    fn create_test_room(equipment_ids: &[&str]) -> RoomInput {
+        test_support::create_test_room(
+            equipment_ids
+                .iter()
+                .enumerate()
+                .map(|(i, equipment_id)| {
+                    create_test_placed_equipment(&format!("p{}", i), equipment_id)
+                })
+                .collect(),
+        )
+    }
+
+    fn rule_ids(violations: &[StandardViolation]) -> Vec<&str> {
+        violations.iter().map(|v| v.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_compliant_room_has_no_violations() {
+        let room = create_test_room(&["display", "display", "camera", "camera", "speaker"]);
+
+        assert!(validate_room(&room, &create_test_catalog()).is_empty());
+    }
+
+    #[test]
+    fn test_display_without_speakers_is_error() {
+        let room = create_test_room(&["display", "camera"]);
+
+        let violations = validate_room(&room, &create_test_catalog());
+
+        assert_eq!(rule_ids(&violations), vec![RULE_DISPLAY_AUDIO_OUTPUT]);
+        assert_eq!(violations[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_dual_screen_camera_mismatch_is_warning() {
+        let room = create_test_room(&["display", "display", "camera", "speaker"]);
+
+        let violations = validate_room(&room, &create_test_catalog());
+
+        assert_eq!(rule_ids(&violations), vec![RULE_CAMERA_PER_DISPLAY]);
+        assert_eq!(violations[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_single_display_needs_no_camera_match() {
+        let room = create_test_room(&["display", "speaker"]);
+
+        assert!(validate_room(&room, &create_test_catalog()).is_empty());
+    }
+}
@@ -2,7 +2,7 @@
  * File Upload Step
  *
  * Drag-and-drop file selection for the import wizard.
- * Accepts Excel (.xlsx), CSV, and PDF files.
+ * Accepts Excel (.xlsx), OpenDocument (.ods), CSV, and PDF files.
  */
 
 import { useCallback, useState, useRef } from 'react';
@@ -31,6 +31,7 @@ interface SelectedFile {
 const ACCEPTED_EXTENSIONS: Record<string, FileType> = {
   xlsx: 'xlsx',
   xls: 'xlsx',
+  ods: 'ods',
   csv: 'csv',
   pdf: 'pdf',
 };
@@ -38,6 +39,7 @@ const ACCEPTED_EXTENSIONS: Record<string, FileType> = {
 const ACCEPTED_MIME_TYPES: Record<string, FileType> = {
   'application/vnd.openxmlformats-officedocument.spreadsheetml.sheet': 'xlsx',
   'application/vnd.ms-excel': 'xlsx',
+  'application/vnd.oasis.opendocument.spreadsheet': 'ods',
   'text/csv': 'csv',
   'application/csv': 'csv',
   'application/pdf': 'pdf',
@@ -72,6 +74,8 @@ function getFileTypeLabel(fileType: FileType): string {
   switch (fileType) {
     case 'xlsx':
       return 'Excel Spreadsheet';
+    case 'ods':
+      return 'OpenDocument Spreadsheet';
     case 'csv':
       return 'CSV File';
     case 'pdf':
@@ -96,7 +100,7 @@ export function FileUploadStep({ onFileSelect, isLoading, error }: FileUploadSte
       const fileType = getFileType(file);
       if (!fileType) {
         setValidationError(
-          'Unsupported file type. Please select an Excel (.xlsx), CSV, or PDF file.'
+          'Unsupported file type. Please select an Excel (.xlsx), OpenDocument (.ods), CSV, or PDF file.'
         );
         return;
       }
@@ -213,6 +217,7 @@ export function FileUploadStep({ onFileSelect, isLoading, error }: FileUploadSte
           </div>
           <div className="file-upload-step__formats">
             <span className="file-upload-step__format">.xlsx</span>
+            <span className="file-upload-step__format">.ods</span>
             <span className="file-upload-step__format">.csv</span>
             <span className="file-upload-step__format">.pdf</span>
           </div>
@@ -222,7 +227,7 @@ export function FileUploadStep({ onFileSelect, isLoading, error }: FileUploadSte
       <input
         ref={inputRef}
         type="file"
-        accept=".xlsx,.xls,.csv,.pdf"
+        accept=".xlsx,.xls,.ods,.csv,.pdf"
         onChange={handleInputChange}
         style={{ display: 'none' }}
         aria-label="Select file"
@@ -266,7 +271,7 @@ function UploadIcon() {
 
 function FileIcon({ fileType }: { fileType: FileType }) {
   const color =
-    fileType === 'xlsx'
+    fileType === 'xlsx' || fileType === 'ods'
       ? 'var(--color-status-success)'
       : fileType === 'csv'
         ? 'var(--color-accent-blue)'
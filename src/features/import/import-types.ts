@@ -9,7 +9,7 @@
 // File Types
 // =============================================================================
 
-export type FileType = 'xlsx' | 'csv' | 'pdf';
+export type FileType = 'xlsx' | 'ods' | 'csv' | 'pdf';
 
 // =============================================================================
 // Parsed Data (from Rust backend)
@@ -31,8 +31,23 @@ export interface ParsedFile {
   totalRows: number;
   /** Whether the file was truncated due to size limits */
   truncated: boolean;
+  /** Field delimiter detected in text files */
+  delimiter?: string | null;
+  /** Character encoding detected in text files, transcoded to UTF-8 */
+  encoding?: string | null;
 }
 
+/**
+ * A cell value with the type it had in the source workbook
+ */
+export type CellValue =
+  | { type: 'empty' }
+  | { type: 'number'; value: number }
+  | { type: 'bool'; value: boolean }
+  /** ISO 8601 date, or date and time when the cell has a time of day */
+  | { type: 'date'; value: string }
+  | { type: 'string'; value: string };
+
 /**
  * A single parsed row of data
  */
@@ -41,6 +56,8 @@ export interface ParsedRow {
   rowNumber: number;
   /** Cell values as strings */
   cells: string[];
+  /** Typed cell values, parallel to `cells`, for formats that store types */
+  typedCells?: CellValue[] | null;
 }
 
 // =============================================================================
@@ -78,6 +95,8 @@ export interface ColumnMapping {
   sourceHeader: string;
   /** Target equipment field (null if unmapped) */
   targetField: EquipmentField | null;
+  /** Value this yes/no column contributes when its cell is truthy */
+  flagLabel?: string | null;
 }
 
 /**
@@ -92,6 +111,8 @@ export interface HeaderSuggestion {
   suggestedField: EquipmentField | null;
   /** Confidence score (0.0 - 1.0) */
   confidence: number;
+  /** Platform named by a yes/no column such as "Teams Certified" */
+  flagLabel?: string | null;
 }
 
 // =============================================================================
@@ -10,6 +10,7 @@ export type {
   FileType,
   ParsedFile,
   ParsedRow,
+  CellValue,
   EquipmentField,
   ColumnMapping,
   HeaderSuggestion,